@@ -12,6 +12,7 @@ pub const BPS_DIVISOR: u64 = 10_000; // 100%
 pub const DEFAULT_TREASURY_CUT_BPS: u64 = 1_000; // 10%
 pub const DEFAULT_FUNDING_CUT_BPS: u64 = 1_000; // 10%
 pub const MAX_TREASURY_CUT_BPS: u64 = 5_000; // 50% maximum treasury cut
+pub const MAX_INSURANCE_CUT_BPS: u64 = 5_000; // 50% maximum insurance fund cut
 
 // Funding rate constants
 pub const MAX_FUNDING_RATE_BPS: u64 = 1000; // 10% hourly = 87,600% APR
@@ -37,6 +38,48 @@ pub const COLLATERAL_MINT: Pubkey = pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGG
 // Baskt constants
 pub const BASE_NAV: u64 = 100; // Base NAV value for new baskts ($100)
 pub const PRICE_PRECISION: u64 = 10u64.pow(6); // 6 decimal places to match USDC
+pub const PRICE_EXPONENT: i32 = -6; // Exponent matching PRICE_PRECISION
+pub const MAX_MARK_PREMIUM_BPS: u64 = 50; // Mark premium at fully one-sided open interest (0.5%)
+
+// Oracle constants
+pub const DEFAULT_ORACLE_MAX_AGE_SECONDS: u64 = 60; // Reject Pyth updates older than 1 minute
+pub const MAX_ORACLE_MAX_AGE_SECONDS: u64 = 3600; // Upper bound for the per-asset staleness limit
+pub const DEFAULT_ORACLE_MAX_CONF_BPS: u64 = 200; // Reject prices whose confidence exceeds 2% of price
+pub const MAX_ORACLE_MAX_CONF_BPS: u64 = 1000; // Upper bound for the per-asset confidence limit
+pub const DEFAULT_ORACLE_MAX_SPREAD_BPS: u64 = 100; // Flag sources more than 1% apart
+pub const MAX_ORACLE_MAX_SPREAD_BPS: u64 = 2000; // Upper bound for the per-asset spread limit
+pub const MAX_ORACLE_SOURCES: usize = 4; // Max price sources aggregated per asset
+pub const DEFAULT_PRICE_DEVIATION_BPS: u64 = 100; // 1% default max deviation between submitted and oracle NAV
+pub const MAX_CONFIGURABLE_PRICE_DEVIATION_BPS: u64 = 1000; // 10% upper bound for the configurable deviation
+
+// Published price account layout version
+pub const BASKT_PRICE_ACCOUNT_VERSION: u8 = 1;
+
+// NAV history constants
+pub const NAV_HISTORY_CAPACITY: usize = 64; // Observations kept per baskt
+pub const NAV_HISTORY_MIN_INTERVAL_SECONDS: i64 = 15; // Minimum spacing between recorded observations
+pub const MAX_TWAP_WINDOW_SECONDS: u64 = 86400; // 1 day
+pub const MAX_CIRCUIT_BREAKER_WINDOW_SECONDS: u64 = 86400; // 1 day
+
+// TWAP order constants
+pub const MIN_TWAP_TRANCHES: u16 = 2;
+pub const MAX_TWAP_TRANCHES: u16 = 100;
+pub const MAX_TWAP_TRANCHE_INTERVAL_SECONDS: u32 = 86400; // 1 day
+
+// Order group constants
+pub const MIN_ORDER_GROUP_SIZE: usize = 2;
+pub const MAX_ORDER_GROUP_SIZE: usize = 4; // e.g. a TP and an SL plus room for laddered exits
+
+// Cross-margin constants
+pub const MAX_MARGIN_POSITIONS: usize = 8; // Member positions per margin account
+pub const MAX_MARGIN_PRICE_AGE_SECONDS: i64 = 60; // Oldest baskt mark price accepted for margin valuation
+pub const MARGIN_VALUATION_ACCOUNTS_PER_MEMBER: usize = 2; // remaining_accounts per member: Position, Baskt
+pub const MARGIN_LIQUIDATION_ACCOUNTS_PER_MEMBER: usize = 4; // remaining_accounts per member: Position, Baskt, position escrow, ADL ranking
+
+// Auto-deleveraging constants
+pub const MAX_ADL_RANKING_ENTRIES: usize = 16; // Ranked positions kept per baskt
+pub const MAX_ADL_PRICE_AGE_SECONDS: i64 = 60; // Oldest baskt mark price accepted for ranking
+pub const MAX_ADL_RANKING_AGE_SECONDS: i64 = 300; // Oldest ranking an auto-deleverage may act on
 
 pub const MIN_GRACE_PERIOD: i64 = 1; // 1 second
 pub const MAX_GRACE_PERIOD: i64 = 604800; // 7 days
@@ -54,3 +97,16 @@ pub const LIQUIDITY_POOL_SEED: &[u8] = b"liquidity_pool";
 pub const TOKEN_VAULT_SEED: &[u8] = b"token_vault"; // DO NOT CHANGE THIS for now
 pub const POOL_AUTHORITY_SEED: &[u8] = b"pool_authority";
 pub const LP_ESCROW_SEED: &[u8] = b"lp_escrow";
+pub const PRICE_FEED_SEED: &[u8] = b"price_feed";
+pub const NAV_HISTORY_SEED: &[u8] = b"nav_history";
+pub const BASKT_PRICE_SEED: &[u8] = b"baskt_price";
+pub const ORDER_GROUP_SEED: &[u8] = b"order_group";
+pub const TRADING_DELEGATE_SEED: &[u8] = b"trading_delegate";
+pub const MARGIN_ACCOUNT_SEED: &[u8] = b"margin_account";
+pub const MARGIN_ESCROW_SEED: &[u8] = b"margin_escrow";
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+pub const INSURANCE_VAULT_SEED: &[u8] = b"insurance_vault";
+pub const ADL_RANKING_SEED: &[u8] = b"adl_ranking";
+
+// Domain separator for off-chain signed price messages
+pub const PRICE_MESSAGE_PREFIX: &[u8] = b"baskt:price_feed:v1";
@@ -124,4 +124,74 @@ pub enum PerpetualsError {
     BorrowRateExceedsMaximum,
     #[msg("Invalid borrow state - borrow accumulated should be negative")]
     InvalidBorrowState,
+    #[msg("Invalid oracle account")]
+    InvalidOracleAccount,
+    #[msg("Oracle price feed does not match asset")]
+    OracleFeedMismatch,
+    #[msg("Oracle price is stale")]
+    StaleOraclePrice,
+    #[msg("Oracle price confidence interval is too wide")]
+    OracleConfidenceTooLow,
+    #[msg("Invalid oracle config")]
+    InvalidOracleConfig,
+    #[msg("Price update is not newer than the stored price")]
+    PriceUpdateOutOfOrder,
+    #[msg("Price message signature is missing or invalid")]
+    InvalidPriceSignature,
+    #[msg("Not enough usable oracle sources")]
+    InsufficientOracleSources,
+    #[msg("Oracle sources disagree beyond the configured spread")]
+    OracleSourcesDisagree,
+    #[msg("NAV observation recorded too soon after the previous one")]
+    NavRecordedTooSoon,
+    #[msg("NAV history does not cover the requested window")]
+    InsufficientNavHistory,
+    #[msg("NAV history account is required for this baskt")]
+    NavHistoryRequired,
+    #[msg("Unsupported price account version")]
+    UnsupportedPriceAccountVersion,
+    #[msg("Execution price has not crossed the order trigger price")]
+    TriggerNotReached,
+    #[msg("Order has expired")]
+    OrderExpired,
+    #[msg("Order has not expired")]
+    OrderNotExpired,
+    #[msg("Close order size exceeds the target position size")]
+    OrderSizeExceedsPosition,
+    #[msg("Invalid order group or group members")]
+    InvalidOrderGroup,
+    #[msg("Order group account is required for grouped orders")]
+    OrderGroupRequired,
+    #[msg("TWAP tranche interval has not elapsed")]
+    TrancheIntervalNotElapsed,
+    #[msg("Trading delegate has expired")]
+    TradingDelegateExpired,
+    #[msg("Trading delegate is not authorized for this baskt")]
+    TradingDelegateScopeViolation,
+    #[msg("Order notional exceeds the trading delegate cap")]
+    TradingDelegateNotionalExceeded,
+    #[msg("Position collateral is held by a margin account")]
+    PositionIsCrossMargined,
+    #[msg("Margin account position set is invalid or incomplete")]
+    InvalidMarginPositions,
+    #[msg("Margin account has reached its position limit")]
+    MarginAccountFull,
+    #[msg("Margin account equity is below the required margin")]
+    InsufficientMarginEquity,
+    #[msg("Margin account is not liquidatable")]
+    MarginAccountNotLiquidatable,
+    #[msg("Baskt mark price is too old for margin valuation")]
+    StaleBasktPrice,
+    #[msg("Liquidation size exceeds what is needed to restore the target margin")]
+    PartialLiquidationTooLarge,
+    #[msg("Insurance vault does not match the insurance fund")]
+    InvalidInsuranceVault,
+    #[msg("Auto-deleveraging is not triggered for the current pool state")]
+    AdlNotTriggered,
+    #[msg("Position is not the top-ranked auto-deleveraging candidate")]
+    AdlNotTopRanked,
+    #[msg("Auto-deleveraging ranking is too old")]
+    StaleAdlRanking,
+    #[msg("NAV moved beyond the baskt circuit breaker threshold")]
+    CircuitBreakerTripped,
 }
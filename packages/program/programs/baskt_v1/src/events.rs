@@ -1,4 +1,4 @@
-use crate::state::order::{OrderAction, OrderType};
+use crate::state::order::{OrderAction, OrderType, TriggerDirection};
 use anchor_lang::prelude::*;
 
 //----------------------------------------------------------------------------
@@ -12,6 +12,8 @@ use anchor_lang::prelude::*;
 // 4. FUNDING EVENTS - Funding rate and index updates
 // 5. LIQUIDITY POOL EVENTS - Liquidity pool operations and withdrawals
 // 6. PROTOCOL EVENTS - Protocol-level state changes
+// 7. ORACLE EVENTS - Protocol-owned price feed updates
+// 8. DELEGATE EVENTS - Trading delegate authorization
 //
 // Each section contains related events with consistent naming conventions
 // and field structures. Events are ordered by their logical flow in the
@@ -37,6 +39,9 @@ pub struct OrderCreatedEvent {
     pub limit_price: u64,
     pub max_slippage_bps: u64,
     pub order_type: OrderType,
+    pub trigger_price: u64,
+    pub trigger_direction: TriggerDirection,
+    pub expires_at: Option<i64>,
     pub leverage_bps: u64,
     pub timestamp: i64,
 }
@@ -51,6 +56,68 @@ pub struct OrderCancelledEvent {
     pub timestamp: i64,
 }
 
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderAmendedEvent {
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub baskt_id: Pubkey,
+    pub limit_price: Option<u64>,
+    pub max_slippage_bps: Option<u64>,
+    pub notional_value: Option<u64>,
+    pub collateral: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderGroupCreatedEvent {
+    pub owner: Pubkey,
+    pub order_group: Pubkey,
+    pub group_id: u32,
+    pub member_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrailingStopUpdatedEvent {
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub baskt_id: Pubkey,
+    pub watermark: u64,
+    pub stop_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TwapTrancheFilledEvent {
+    pub order_id: u64,
+    pub owner: Pubkey,
+    pub position_id: u64,
+    pub tranches_filled: u16,
+    pub tranche_count: u16,
+    pub notional_filled: u64,
+    pub collateral_used: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderExpiredEvent {
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub baskt_id: Pubkey,
+    pub expired_by: Pubkey,
+    pub timestamp: i64,
+}
+
 //----------------------------------------------------------------------------
 // POSITION EVENTS
 //----------------------------------------------------------------------------
@@ -72,6 +139,26 @@ pub struct PositionOpenedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionIncreasedEvent {
+    pub order_id: u64,
+    pub owner: Pubkey,
+    pub position_id: u64,
+    pub baskt_id: Pubkey,
+    pub size_added: u64,
+    pub new_size: u64,
+    pub collateral_added: u64,
+    pub new_collateral: u64,
+    pub increase_price: u64,
+    pub new_entry_price: u64,
+    pub rebalance_fee: u64,
+    pub fee_to_treasury: u64,
+    pub fee_to_blp: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -99,6 +186,8 @@ pub struct PositionClosedEvent {
     pub base_fee: u64,
     pub rebalance_fee: u64,
     pub bad_debt_amount: u64,
+    pub fee_to_insurance: u64,
+    pub bad_debt_covered: u64,
     pub collateral_released: u64,
 }
 
@@ -128,6 +217,8 @@ pub struct PositionLiquidatedEvent {
     pub base_fee: u64,
     pub rebalance_fee: u64,
     pub bad_debt_amount: u64,
+    pub fee_to_insurance: u64,
+    pub bad_debt_covered: u64,
     pub collateral_released: u64,
 }
 
@@ -157,6 +248,8 @@ pub struct PositionForceClosed {
     pub base_fee: u64,
     pub rebalance_fee: u64,
     pub bad_debt_amount: u64,
+    pub fee_to_insurance: u64,
+    pub bad_debt_covered: u64,
     pub collateral_released: u64,
 }
 
@@ -172,6 +265,57 @@ pub struct CollateralAddedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollateralRemovedEvent {
+    pub owner: Pubkey,
+    pub position_id: u64,
+    pub baskt_id: Pubkey,
+    pub removed_collateral: u64,
+    pub new_total_collateral: u64,
+    pub current_price: u64,
+    pub rebalance_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdlRankingUpdatedEvent {
+    pub baskt: Pubkey,
+    pub keeper: Pubkey,
+    pub positions_scored: u16,
+    pub ranked_positions: u16,
+    pub ranked_unrealized_pnl: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionAutoDeleveragedEvent {
+    pub baskt: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub keeper: Pubkey,
+    pub exit_price: u64,
+    pub size_reduced: u64,
+    pub size_remaining: u64,
+    pub adl_score: u128,
+    pub ranked_unrealized_pnl: u64,
+    pub effective_liquidity: u64,
+    pub pnl: i128,
+    pub escrow_to_user: u64,
+    pub pool_to_user: u64,
+    pub user_total_payout: u64,
+    /// Profit forfeited because the pool could not pay it out
+    pub pnl_haircut: u64,
+    pub rebalance_fee: u64,
+    pub collateral_remaining: u64,
+    pub timestamp: i64,
+}
+
 //----------------------------------------------------------------------------
 // BASKT EVENTS
 //----------------------------------------------------------------------------
@@ -243,6 +387,35 @@ pub struct RebalanceRequestEvent {
     pub timestamp: i64,
 }
 
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BasktHaltedEvent {
+    pub baskt_id: Pubkey,
+    pub nav: u64,
+    pub move_bps: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BasktResumedEvent {
+    pub baskt_id: Pubkey,
+    pub resumed_by: Pubkey,
+    pub nav: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NavRecordedEvent {
+    pub baskt_id: Pubkey,
+    pub nav: u64,
+    pub timestamp: i64,
+}
+
 //----------------------------------------------------------------------------
 // FUNDING EVENTS
 //----------------------------------------------------------------------------
@@ -286,6 +459,16 @@ pub struct LiquidityPoolInitializedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFundInitializedEvent {
+    pub insurance_fund: Pubkey,
+    pub vault: Pubkey,
+    pub initializer: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 #[derive(Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -333,3 +516,114 @@ pub struct ProtocolStateUpdatedEvent {
     pub updated_by: Pubkey,
     pub timestamp: i64,
 }
+
+//----------------------------------------------------------------------------
+// ORACLE EVENTS
+//----------------------------------------------------------------------------
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PriceFeedUpdatedEvent {
+    pub price_feed: Pubkey,
+    pub asset: Pubkey,
+    pub price: u64,
+    pub conf: u64,
+    pub publish_time: i64,
+    pub signer: Pubkey,
+}
+
+//----------------------------------------------------------------------------
+// DELEGATE EVENTS
+//----------------------------------------------------------------------------
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TradingDelegateSetEvent {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub expires_at: i64,
+    pub baskt: Option<Pubkey>,
+    pub max_notional: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TradingDelegateRevokedEvent {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+//----------------------------------------------------------------------------
+// MARGIN EVENTS
+//----------------------------------------------------------------------------
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarginCollateralDepositedEvent {
+    pub owner: Pubkey,
+    pub margin_account: Pubkey,
+    pub amount: u64,
+    pub new_total_collateral: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarginCollateralWithdrawnEvent {
+    pub owner: Pubkey,
+    pub margin_account: Pubkey,
+    pub amount: u64,
+    pub new_total_collateral: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarginPositionAttachedEvent {
+    pub owner: Pubkey,
+    pub margin_account: Pubkey,
+    pub position: Pubkey,
+    pub collateral_moved: u64,
+    pub new_total_collateral: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarginPositionDetachedEvent {
+    pub owner: Pubkey,
+    pub margin_account: Pubkey,
+    pub position: Pubkey,
+    pub collateral_moved: u64,
+    pub new_total_collateral: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarginAccountLiquidatedEvent {
+    pub owner: Pubkey,
+    pub margin_account: Pubkey,
+    pub liquidator: Pubkey,
+    pub total_equity: i128,
+    pub required_margin: u64,
+    pub positions_closed: u8,
+    pub collateral_seized: u64,
+    pub fee_to_treasury: u64,
+    pub fee_to_insurance: u64,
+    pub fee_to_blp: u64,
+    pub escrow_to_pool: u64,
+    pub pool_shortfall: u64,
+    pub bad_debt_covered: u64,
+    pub timestamp: i64,
+}
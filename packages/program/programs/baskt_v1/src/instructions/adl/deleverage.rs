@@ -0,0 +1,359 @@
+use crate::constants::{
+    ADL_RANKING_SEED, AUTHORITY_SEED, BASKT_SEED, BPS_DIVISOR, ESCROW_SEED, LIQUIDITY_POOL_SEED,
+    MAX_ADL_RANKING_AGE_SECONDS, NAV_HISTORY_SEED, POOL_AUTHORITY_SEED, POSITION_SEED, PROTOCOL_SEED,
+};
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::math::mul_div_u64;
+use crate::state::{
+    adl_ranking::{AdlEntry, AdlRanking},
+    baskt::Baskt,
+    liquidity::LiquidityPool,
+    margin_account::MarginAccount,
+    nav_history::NavHistory,
+    position::{Position, PositionStatus, ProgramAuthority},
+    protocol::{Protocol, Role},
+};
+use crate::utils::{
+    calculate_position_settlement, cap_pool_payout, check_circuit_breaker, close_account, close_escrow_account, effective_u64,
+    execute_settlement_transfers, update_pool_state, update_position_after_settlement,
+    verify_baskt_price, ClosingType, MarginCollateral, TransferParams,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+/// Parameters for auto-deleveraging a position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct AutoDeleverageParams {
+    pub exit_price: u64,
+    pub size_to_reduce: Option<u64>, // None = full close, Some(size) = partial reduction
+}
+
+/// Reduce the top-ranked position of a baskt at the current price, fee-free, while the
+/// ranked unrealized profit is too large for the pool to cover
+#[derive(Accounts)]
+pub struct AutoDeleveragePosition<'info> {
+    #[account(
+        mut,
+        constraint = protocol.has_permission(keeper.key(), Role::Keeper) @ PerpetualsError::Unauthorized
+    )]
+    pub keeper: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, position.owner.as_ref(), &position.position_id.to_le_bytes()],
+        bump = position.bump,
+        constraint = position.status == PositionStatus::Open @ PerpetualsError::PositionAlreadyClosed,
+        constraint = position.baskt_id == baskt.key() @ PerpetualsError::InvalidBaskt,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        mut,
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump,
+        constraint = baskt.is_trading() @ PerpetualsError::BasktNotActive
+    )]
+    pub baskt: Box<Account<'info, Baskt>>,
+
+    #[account(
+        mut,
+        seeds = [ADL_RANKING_SEED, baskt.key().as_ref()],
+        bump = adl_ranking.bump,
+    )]
+    pub adl_ranking: Box<Account<'info, AdlRanking>>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+
+    /// Position escrow token account
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, position.key().as_ref()],
+        bump,
+        constraint = owner_collateral_escrow_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = owner_collateral_escrow_account.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+    )]
+    pub owner_collateral_escrow_account: Account<'info, TokenAccount>,
+
+    /// User's collateral token account to receive the payout
+    #[account(
+        mut,
+        constraint = owner_collateral_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = owner_collateral_account.owner == position.owner @ PerpetualsError::Unauthorized
+    )]
+    pub owner_collateral_account: Account<'info, TokenAccount>,
+
+    /// BLP token vault for liquidity pool
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == liquidity_pool.usdc_vault @ PerpetualsError::InvalidUsdcVault,
+        constraint = usdc_vault.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = owner_collateral_account.key() != usdc_vault.key() @ PerpetualsError::InvalidInput
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// Protocol treasury token account for the settled rebalance fee
+    #[account(
+        mut,
+        constraint = treasury_token.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = treasury_token.owner == protocol.treasury @ PerpetualsError::InvalidTreasuryAccount,
+        constraint = treasury_token.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
+        constraint = treasury_token.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority,
+        constraint = treasury_token.key() != usdc_vault.key() @ PerpetualsError::InvalidInput,
+        constraint = treasury_token.key() != owner_collateral_account.key() @ PerpetualsError::InvalidInput
+    )]
+    pub treasury_token: Account<'info, TokenAccount>,
+
+    /// Program authority PDA
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Account<'info, ProgramAuthority>,
+
+    /// CHECK: Pool authority PDA for transfers from pool vault
+    #[account(
+        seeds = [POOL_AUTHORITY_SEED, liquidity_pool.key().as_ref(), protocol.key().as_ref()],
+        bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// Margin account of a cross-margined position, whose shared collateral backs the close
+    #[account(mut)]
+    pub margin_account: Option<Box<Account<'info, MarginAccount>>>,
+
+    /// Token account holding the margin account's shared collateral
+    #[account(mut)]
+    pub margin_escrow: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// NAV history, required when the baskt has a circuit breaker configured
+    #[account(
+        seeds = [NAV_HISTORY_SEED, baskt.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Account<'info, NavHistory>>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: the baskt oracle accounts (see load_baskt_oracle_prices)
+}
+
+pub fn auto_deleverage_position<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AutoDeleveragePosition<'info>>,
+    params: AutoDeleverageParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let position_key = ctx.accounts.position.key();
+    require!(params.exit_price > 0, PerpetualsError::InvalidOraclePrice);
+
+    // 1. Only the top entry of a recent ranking may be deleveraged
+    let ranking = &ctx.accounts.adl_ranking;
+    require!(
+        clock.unix_timestamp.saturating_sub(ranking.last_updated) <= MAX_ADL_RANKING_AGE_SECONDS,
+        PerpetualsError::StaleAdlRanking
+    );
+    let adl_score = match ranking.top() {
+        Some(top) if top.position == position_key => top.score,
+        _ => return err!(PerpetualsError::AdlNotTopRanked),
+    };
+
+    // 2. ADL is triggered once ranked profit outgrows its allowed share of the pool
+    let adl_pnl_to_pool_bps = ctx.accounts.protocol.config.adl_pnl_to_pool_bps;
+    let ranked_unrealized_pnl = ranking.ranked_unrealized_pnl()?;
+    let effective_liquidity = ctx.accounts.liquidity_pool.effective_liquidity();
+    require!(
+        adl_pnl_to_pool_bps > 0
+            && ranked_unrealized_pnl
+                > mul_div_u64(effective_liquidity, adl_pnl_to_pool_bps, BPS_DIVISOR)?,
+        PerpetualsError::AdlNotTriggered
+    );
+
+    // 3. Verify the exit price against the NAV implied by the aggregated oracle prices
+    let baskt = &mut ctx.accounts.baskt;
+    let max_price_deviation_bps = effective_u64(
+        baskt.risk_config.get_max_price_deviation_bps(),
+        ctx.accounts.protocol.config.max_price_deviation_bps,
+    );
+    let oracle_nav = verify_baskt_price(
+        baskt,
+        ctx.remaining_accounts,
+        params.exit_price,
+        max_price_deviation_bps,
+    )?;
+    // Never force an exit on prices the oracle sources disagree about
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+    check_circuit_breaker(
+        baskt,
+        ctx.accounts.nav_history.as_deref(),
+        clock.unix_timestamp,
+        oracle_nav.nav,
+    )?;
+
+    // 4. Settle indices; the position must still be in profit at the exit price
+    let position = &mut ctx.accounts.position;
+    position.update_market_indices(
+        baskt.market_indices.cumulative_funding_index,
+        baskt.market_indices.cumulative_borrow_index,
+        params.exit_price,
+    )?;
+    let (current_score, _) =
+        position.adl_score(params.exit_price, baskt.rebalance_fee_index.cumulative_index)?;
+    require!(current_score > 0, PerpetualsError::AdlNotTriggered);
+    // Rescored on-chain, the candidate must still rank at least as high as every other entry
+    require!(
+        ctx.accounts.adl_ranking.outranks_others(&position_key, current_score),
+        PerpetualsError::AdlNotTopRanked
+    );
+    let rebalance_fee_owed =
+        position.apply_rebalance_fee(baskt.rebalance_fee_index.cumulative_index, params.exit_price)?;
+
+    let size_to_reduce = params.size_to_reduce.unwrap_or(position.size);
+    require!(size_to_reduce > 0, PerpetualsError::ZeroSizedPosition);
+    require!(
+        size_to_reduce <= position.size,
+        PerpetualsError::InvalidPositionSize
+    );
+    let is_full_close = size_to_reduce == position.size;
+
+    // A cross-margined position settles against the shared collateral of its account,
+    // and its payout returns there
+    let mut margin = MarginCollateral::for_position(
+        position,
+        ctx.accounts.margin_account.as_deref_mut(),
+        ctx.accounts.margin_escrow.as_deref(),
+    )?;
+    if let Some(margin) = margin.as_mut() {
+        margin.stage(
+            &ctx.accounts.token_program,
+            position,
+            &ctx.accounts.owner_collateral_escrow_account,
+            &ctx.accounts.program_authority.to_account_info(),
+            ctx.bumps.program_authority,
+        )?;
+        ctx.accounts.owner_collateral_escrow_account.reload()?;
+    }
+    let payout_account = match &margin {
+        Some(margin) => margin.margin_escrow.to_account_info(),
+        None => ctx.accounts.owner_collateral_account.to_account_info(),
+    };
+
+    // 5. Settle without a closing fee; a profitable position carries no bad debt, so the
+    // insurance fund is not involved
+    let mut settlement_details = calculate_position_settlement(
+        position,
+        size_to_reduce,
+        params.exit_price,
+        ClosingType::ForceClose { closing_fee_bps: 0 },
+        ctx.accounts.protocol.config.treasury_cut_bps,
+        0,
+        rebalance_fee_owed,
+    )?;
+    // The pool pays out what it can; profit beyond its liquidity is forfeited
+    let pnl_haircut = cap_pool_payout(
+        &mut settlement_details,
+        ctx.accounts.liquidity_pool.effective_liquidity(),
+    );
+
+    execute_settlement_transfers(
+        &ctx.accounts.token_program,
+        &ctx.accounts.owner_collateral_escrow_account,
+        &payout_account,
+        Some(&ctx.accounts.treasury_token.to_account_info()),
+        &ctx.accounts.usdc_vault.to_account_info(),
+        &ctx.accounts.program_authority.to_account_info(),
+        &ctx.accounts.pool_authority.to_account_info(),
+        ctx.accounts.liquidity_pool.key(),
+        ctx.accounts.protocol.key(),
+        &TransferParams {
+            escrow_balance: ctx.accounts.owner_collateral_escrow_account.amount,
+            authority_bump: ctx.bumps.program_authority,
+            pool_authority_bump: ctx.bumps.pool_authority,
+        },
+        &settlement_details,
+    )?;
+
+    update_pool_state(&mut ctx.accounts.liquidity_pool, &settlement_details)?;
+
+    update_position_after_settlement(
+        position,
+        size_to_reduce,
+        settlement_details.collateral_to_release,
+    )?;
+    if let Some(margin) = margin.as_mut() {
+        margin.unstage(
+            &ctx.accounts.token_program,
+            position,
+            &ctx.accounts.owner_collateral_escrow_account,
+            &ctx.accounts.program_authority.to_account_info(),
+            ctx.bumps.program_authority,
+            settlement_details.user_payout_u64,
+        )?;
+    }
+
+    // 6. Drop or rescore the position so the next candidate moves up
+    let ranking = &mut ctx.accounts.adl_ranking;
+    if is_full_close {
+        ranking.remove(&position_key);
+    } else {
+        let (score, unrealized_pnl) =
+            position.adl_score(params.exit_price, baskt.rebalance_fee_index.cumulative_index)?;
+        ranking.upsert(AdlEntry {
+            position: position_key,
+            score,
+            unrealized_pnl,
+        });
+    }
+
+    emit!(PositionAutoDeleveragedEvent {
+        baskt: baskt.key(),
+        position: position_key,
+        owner: position.owner,
+        keeper: ctx.accounts.keeper.key(),
+        exit_price: params.exit_price,
+        size_reduced: size_to_reduce,
+        size_remaining: position.size,
+        adl_score,
+        ranked_unrealized_pnl,
+        effective_liquidity,
+        pnl: settlement_details.pnl,
+        escrow_to_user: settlement_details.escrow_to_user,
+        pool_to_user: settlement_details.pool_to_user,
+        user_total_payout: settlement_details.user_payout_u64,
+        pnl_haircut,
+        rebalance_fee: settlement_details.rebalance_fee,
+        collateral_remaining: position.collateral,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Track open interest and refresh the mark price for the new skew
+    baskt.remove_open_interest(position.is_long, size_to_reduce);
+    baskt.update_prices(oracle_nav.nav, clock.unix_timestamp)?;
+
+    if is_full_close {
+        baskt.open_positions = baskt
+            .open_positions
+            .checked_sub(1)
+            .ok_or(PerpetualsError::MathOverflow)?;
+
+        // Close escrow token account to reclaim rent
+        close_escrow_account(
+            &ctx.accounts.token_program,
+            &ctx.accounts.owner_collateral_escrow_account,
+            &ctx.accounts.keeper.to_account_info(),
+            &ctx.accounts.program_authority.to_account_info(),
+            ctx.bumps.program_authority,
+        )?;
+
+        // Close position account manually since there is no close attribute
+        close_account(&position.to_account_info(), &ctx.accounts.keeper.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod deleverage;
+pub mod ranking;
+
+pub use deleverage::*;
+pub use ranking::*;
@@ -0,0 +1,99 @@
+use crate::constants::{ADL_RANKING_SEED, BASKT_SEED, MAX_ADL_PRICE_AGE_SECONDS, PROTOCOL_SEED};
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::{
+    adl_ranking::{AdlEntry, AdlRanking},
+    baskt::Baskt,
+    position::{Position, PositionStatus},
+    protocol::{Protocol, Role},
+};
+use anchor_lang::prelude::*;
+
+/// Score positions of a baskt at its mark price and merge them into the baskt's
+/// auto-deleveraging ranking
+#[derive(Accounts)]
+pub struct UpdateAdlRanking<'info> {
+    #[account(
+        mut,
+        constraint = protocol.has_permission(keeper.key(), Role::Keeper) @ PerpetualsError::Unauthorized
+    )]
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump,
+    )]
+    pub baskt: Box<Account<'info, Baskt>>,
+
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = AdlRanking::DISCRIMINATOR.len() + AdlRanking::INIT_SPACE,
+        seeds = [ADL_RANKING_SEED, baskt.key().as_ref()],
+        bump
+    )]
+    pub adl_ranking: Box<Account<'info, AdlRanking>>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// `remaining_accounts`: open positions of the baskt to (re)score. Entries only leave the
+/// ranking when rescored without profit, pushed out by higher scores, or when their
+/// position is closed, so a keeper cannot drop a higher-ranked position.
+pub fn update_adl_ranking<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateAdlRanking<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let baskt = &ctx.accounts.baskt;
+    let baskt_key = baskt.key();
+    require!(
+        baskt.mark_price > 0
+            && clock.unix_timestamp.saturating_sub(baskt.price_updated_at) <= MAX_ADL_PRICE_AGE_SECONDS,
+        PerpetualsError::StaleBasktPrice
+    );
+
+    let ranking = &mut ctx.accounts.adl_ranking;
+    if ranking.baskt == Pubkey::default() {
+        ranking.initialize(baskt_key, ctx.bumps.adl_ranking);
+    }
+
+    for account in ctx.remaining_accounts {
+        let position = Account::<Position>::try_from(account)?;
+        require_keys_eq!(position.baskt_id, baskt_key, PerpetualsError::InvalidBaskt);
+        require!(
+            position.status == PositionStatus::Open,
+            PerpetualsError::PositionAlreadyClosed
+        );
+
+        // Accrue unsettled funding and borrow on a copy before scoring
+        let mut settled = position.clone();
+        settled.update_market_indices(
+            baskt.market_indices.cumulative_funding_index,
+            baskt.market_indices.cumulative_borrow_index,
+            baskt.mark_price,
+        )?;
+        let (score, unrealized_pnl) =
+            settled.adl_score(baskt.mark_price, baskt.rebalance_fee_index.cumulative_index)?;
+
+        ranking.upsert(AdlEntry {
+            position: position.key(),
+            score,
+            unrealized_pnl,
+        });
+    }
+    ranking.last_updated = clock.unix_timestamp;
+
+    emit!(AdlRankingUpdatedEvent {
+        baskt: baskt_key,
+        keeper: ctx.accounts.keeper.key(),
+        positions_scored: ctx.remaining_accounts.len() as u16,
+        ranked_positions: ranking.entries.len() as u16,
+        ranked_unrealized_pnl: ranking.ranked_unrealized_pnl()?,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
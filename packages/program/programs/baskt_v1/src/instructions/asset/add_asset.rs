@@ -1,15 +1,17 @@
 use crate::constants::*;
 use crate::error::PerpetualsError;
 use crate::state::{
-    asset::{AssetPermissions, SyntheticAsset},
+    asset::{AssetPermissions, OracleSource, SyntheticAsset},
     protocol::{Protocol, Role},
 };
+use crate::utils::validate_oracle_config;
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct AddAssetParams {
     pub permissions: AssetPermissions,
     pub ticker: String,
+    pub oracle_sources: Vec<OracleSource>,
 }
 
 #[derive(Accounts)]
@@ -42,6 +44,8 @@ pub fn add_asset(ctx: Context<AddAsset>, params: AddAssetParams) -> Result<()> {
         params.ticker,
         params.permissions,
         clock.unix_timestamp as u32, // Convert to u32 for optimized timestamp
+        params.oracle_sources,
     )?;
+    validate_oracle_config(&asset.oracle_config)?;
     Ok(())
 }
@@ -0,0 +1,75 @@
+use crate::constants::*;
+use crate::error::PerpetualsError;
+use crate::state::{
+    asset::{DeployedSyntheticAsset, OracleConfig, SyntheticAsset},
+    protocol::{Protocol, Role},
+};
+use crate::utils::{transfer_sol, validate_oracle_config};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateAssetParams {
+    pub ticker: String,
+    pub oracle_config: OracleConfig,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateAssetParams)]
+pub struct MigrateAsset<'info> {
+    #[account(mut,
+        constraint = protocol.has_permission(authority.key(), Role::AssetManager) @ PerpetualsError::UnauthorizedRole
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Asset still in its deployed layout, which `Account<SyntheticAsset>` cannot
+    /// decode; owner, discriminator and size are checked in the handler
+    #[account(
+        mut,
+        seeds = [ASSET_SEED, params.ticker.as_bytes()],
+        bump
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow an asset created before oracle configs existed to the current layout
+pub fn migrate_asset(ctx: Context<MigrateAsset>, params: MigrateAssetParams) -> Result<()> {
+    let asset_info = ctx.accounts.asset.to_account_info();
+    let space = SyntheticAsset::DISCRIMINATOR.len() + SyntheticAsset::INIT_SPACE;
+
+    require_keys_eq!(*asset_info.owner, crate::ID, PerpetualsError::InvalidAssetAccount);
+    let deployed = {
+        let data = asset_info.try_borrow_data()?;
+        // Migrated and newly added assets already have the full size
+        require!(
+            data.len() < space && data.starts_with(SyntheticAsset::DISCRIMINATOR),
+            PerpetualsError::InvalidAssetAccount
+        );
+        DeployedSyntheticAsset::deserialize(&mut &data[SyntheticAsset::DISCRIMINATOR.len()..])
+            .map_err(|_| PerpetualsError::InvalidAssetAccount)?
+    };
+    validate_oracle_config(&params.oracle_config)?;
+
+    // Top up rent for the larger account before growing it
+    let rent_due = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(asset_info.lamports());
+    if rent_due > 0 {
+        transfer_sol(
+            &ctx.accounts.authority.to_account_info(),
+            &asset_info,
+            &ctx.accounts.system_program.to_account_info(),
+            rent_due,
+        )?;
+    }
+    asset_info.realloc(space, true)?;
+
+    let asset = SyntheticAsset::from_deployed(deployed, params.oracle_config);
+    let mut data = asset_info.try_borrow_mut_data()?;
+    asset.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
@@ -1,2 +1,8 @@
 pub mod add_asset;
+pub mod update_oracle_config;
+pub mod set_manual_price;
+pub mod migrate_asset;
 pub use add_asset::*;
+pub use update_oracle_config::*;
+pub use set_manual_price::*;
+pub use migrate_asset::*;
@@ -0,0 +1,37 @@
+use crate::constants::*;
+use crate::error::PerpetualsError;
+use crate::state::{
+    asset::{ManualPrice, SyntheticAsset},
+    protocol::{Protocol, Role},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetManualPrice<'info> {
+    #[account(
+        constraint = protocol.has_permission(authority.key(), Role::AssetManager) @ PerpetualsError::UnauthorizedRole
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ASSET_SEED, asset.ticker.as_bytes()],
+        bump
+    )]
+    pub asset: Account<'info, SyntheticAsset>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
+
+/// Set the admin price consumed by `OracleSource::Manual` (PRICE_PRECISION)
+pub fn set_manual_price(ctx: Context<SetManualPrice>, price: u64) -> Result<()> {
+    require!(price > 0, PerpetualsError::InvalidOraclePrice);
+
+    let clock = Clock::get()?;
+    ctx.accounts.asset.manual_price = ManualPrice {
+        price,
+        publish_time: clock.unix_timestamp,
+    };
+    Ok(())
+}
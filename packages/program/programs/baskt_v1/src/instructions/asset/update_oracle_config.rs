@@ -0,0 +1,36 @@
+use crate::constants::*;
+use crate::error::PerpetualsError;
+use crate::state::{
+    asset::{OracleConfig, SyntheticAsset},
+    protocol::{Protocol, Role},
+};
+use crate::utils::validate_oracle_config;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateAssetOracleConfig<'info> {
+    #[account(
+        constraint = protocol.has_permission(authority.key(), Role::AssetManager) @ PerpetualsError::UnauthorizedRole
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ASSET_SEED, asset.ticker.as_bytes()],
+        bump
+    )]
+    pub asset: Account<'info, SyntheticAsset>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
+
+pub fn update_asset_oracle_config(
+    ctx: Context<UpdateAssetOracleConfig>,
+    oracle_config: OracleConfig,
+) -> Result<()> {
+    let asset = &mut ctx.accounts.asset;
+    validate_oracle_config(&oracle_config)?;
+    asset.oracle_config = oracle_config;
+    Ok(())
+}
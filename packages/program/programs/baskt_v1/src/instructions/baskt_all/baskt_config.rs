@@ -3,11 +3,13 @@ use anchor_lang::prelude::*;
 use crate::constants::{BASKT_SEED, PROTOCOL_SEED};
 use crate::error::PerpetualsError;
 use crate::events::*;
-use crate::state::baskt::{Baskt, BasktConfig};
+use crate::state::baskt::{Baskt, BasktConfig, BasktRiskConfig, CircuitBreakerConfig};
 use crate::state::protocol::{Protocol, Role};
 use crate::utils::{
-     validate_baskt_config, validate_baskt_fee_bps,
+     validate_baskt_config, validate_baskt_fee_bps, validate_baskt_risk_config,
     validate_baskt_liquidation_threshold_bps, validate_baskt_min_collateral_ratio_bps,
+    validate_baskt_partial_liquidation_target,
+    validate_baskt_circuit_breaker, validate_baskt_price_deviation_bps, validate_baskt_twap_window,
 };
 
 // Helper function to check if authority can modify baskt config
@@ -175,7 +177,10 @@ pub fn set_baskt_min_collateral_ratio_bps(
         return Ok(());
     }
 
-    baskt.config.set_min_collateral_ratio_bps(new_min_collateral_ratio_bps);
+    let mut new_config = baskt.config;
+    new_config.set_min_collateral_ratio_bps(new_min_collateral_ratio_bps);
+    validate_baskt_partial_liquidation_target(&new_config, &ctx.accounts.protocol.config)?;
+    baskt.config = new_config;
 
     let clock = Clock::get()?;
     emit!(BasktConfigUpdatedEvent {
@@ -231,7 +236,148 @@ pub fn set_baskt_liquidation_threshold_bps(
         return Ok(());
     }
 
-    baskt.config.set_liquidation_threshold_bps(new_liquidation_threshold_bps);
+    let mut new_config = baskt.config;
+    new_config.set_liquidation_threshold_bps(new_liquidation_threshold_bps);
+    validate_baskt_partial_liquidation_target(&new_config, &ctx.accounts.protocol.config)?;
+    baskt.config = new_config;
+
+    let clock = Clock::get()?;
+    emit!(BasktConfigUpdatedEvent {
+        baskt: baskt.key(),
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// Set Baskt Max Price Deviation Bps Instruction
+// ----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct SetBasktMaxPriceDeviationBps<'info> {
+    /// Authority that can modify baskt config
+    #[account(
+        mut,
+        constraint = can_modify_baskt_config(&baskt, authority.key(), &protocol) @ PerpetualsError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    /// Baskt account to update
+    #[account(
+        mut,
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump
+    )]
+    pub baskt: Account<'info, Baskt>,
+
+    /// Protocol account for role checking
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
+
+// Generate baskt max price deviation setter using the macro
+crate::impl_baskt_bps_setter!(
+    set_baskt_max_price_deviation_bps,
+    SetBasktMaxPriceDeviationBps<'info>,
+    max_price_deviation_bps,
+    validate_baskt_price_deviation_bps,
+    old_max_price_deviation_bps,
+    new_max_price_deviation_bps
+);
+
+// ----------------------------------------------------------------------------
+// Set Baskt Liquidation TWAP Window Instruction
+// ----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct SetBasktLiquidationTwapWindow<'info> {
+    /// Authority that can modify baskt config
+    #[account(
+        mut,
+        constraint = can_modify_baskt_config(&baskt, authority.key(), &protocol) @ PerpetualsError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    /// Baskt account to update
+    #[account(
+        mut,
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump
+    )]
+    pub baskt: Account<'info, Baskt>,
+
+    /// Protocol account for role checking
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
+
+pub fn set_baskt_liquidation_twap_window(
+    ctx: Context<SetBasktLiquidationTwapWindow>,
+    new_window_seconds: Option<u64>,
+) -> Result<()> {
+    validate_baskt_twap_window(new_window_seconds)?;
+    let baskt = &mut ctx.accounts.baskt;
+    let old_window_seconds = baskt.risk_config.get_liquidation_twap_window_seconds();
+
+    // Early exit if nothing changed
+    if old_window_seconds == new_window_seconds {
+        return Ok(());
+    }
+
+    baskt.risk_config.set_liquidation_twap_window_seconds(new_window_seconds);
+
+    let clock = Clock::get()?;
+    emit!(BasktConfigUpdatedEvent {
+        baskt: baskt.key(),
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// Set Baskt Circuit Breaker Instruction
+// ----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct SetBasktCircuitBreaker<'info> {
+    /// Authority that can modify baskt config
+    #[account(
+        mut,
+        constraint = can_modify_baskt_config(&baskt, authority.key(), &protocol) @ PerpetualsError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    /// Baskt account to update
+    #[account(
+        mut,
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump
+    )]
+    pub baskt: Account<'info, Baskt>,
+
+    /// Protocol account for role checking
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
+
+pub fn set_baskt_circuit_breaker(
+    ctx: Context<SetBasktCircuitBreaker>,
+    new_circuit_breaker: Option<CircuitBreakerConfig>,
+) -> Result<()> {
+    validate_baskt_circuit_breaker(new_circuit_breaker)?;
+    let baskt = &mut ctx.accounts.baskt;
+    let old_circuit_breaker = baskt.risk_config.get_circuit_breaker();
+
+    // Early exit if nothing changed
+    if old_circuit_breaker == new_circuit_breaker {
+        return Ok(());
+    }
+
+    baskt.risk_config.set_circuit_breaker(new_circuit_breaker);
 
     let clock = Clock::get()?;
     emit!(BasktConfigUpdatedEvent {
@@ -254,6 +400,9 @@ pub struct UpdateBasktConfigParams {
     pub liquidation_fee_bps: Option<u64>,
     pub min_collateral_ratio_bps: Option<u64>,
     pub liquidation_threshold_bps: Option<u64>,
+    pub max_price_deviation_bps: Option<u64>,
+    pub liquidation_twap_window_seconds: Option<u64>,
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
 #[derive(Accounts)]
@@ -287,6 +436,7 @@ pub fn update_baskt_config(
 
     let baskt = &mut ctx.accounts.baskt;
     let old_config = baskt.config;
+    let old_risk_config = baskt.risk_config;
 
     // Create new config with updated values
     let mut new_config = BasktConfig::default();
@@ -296,16 +446,24 @@ pub fn update_baskt_config(
     new_config.set_min_collateral_ratio_bps(params.min_collateral_ratio_bps);
     new_config.set_liquidation_threshold_bps(params.liquidation_threshold_bps);
 
+    let mut new_risk_config = BasktRiskConfig::default();
+    new_risk_config.set_max_price_deviation_bps(params.max_price_deviation_bps);
+    new_risk_config.set_liquidation_twap_window_seconds(params.liquidation_twap_window_seconds);
+    new_risk_config.set_circuit_breaker(params.circuit_breaker);
+
     // Validate the new config
     validate_baskt_config(&new_config)?;
+    validate_baskt_partial_liquidation_target(&new_config, &ctx.accounts.protocol.config)?;
+    validate_baskt_risk_config(&new_risk_config)?;
 
     // Check if anything changed
-    if old_config == new_config {
+    if old_config == new_config && old_risk_config == new_risk_config {
         return Ok(()); // No changes
     }
 
     // Update config
     baskt.config = new_config;
+    baskt.risk_config = new_risk_config;
 
     // Emit event using the clock declared above
     emit!(BasktConfigUpdatedEvent {
@@ -356,6 +514,7 @@ macro_rules! impl_baskt_bps_setter {
                 "opening_fee_bps" => baskt.config.get_opening_fee_bps(),
                 "closing_fee_bps" => baskt.config.get_closing_fee_bps(),
                 "liquidation_fee_bps" => baskt.config.get_liquidation_fee_bps(),
+                "max_price_deviation_bps" => baskt.risk_config.get_max_price_deviation_bps(),
                 _ => panic!("Unknown field"),
             };
 
@@ -368,6 +527,7 @@ macro_rules! impl_baskt_bps_setter {
                 "opening_fee_bps" => baskt.config.set_opening_fee_bps($new_ident),
                 "closing_fee_bps" => baskt.config.set_closing_fee_bps($new_ident),
                 "liquidation_fee_bps" => baskt.config.set_liquidation_fee_bps($new_ident),
+                "max_price_deviation_bps" => baskt.risk_config.set_max_price_deviation_bps($new_ident),
                 _ => panic!("Unknown field"),
             };
 
@@ -0,0 +1,37 @@
+use crate::constants::{BASKT_PRICE_SEED, BASKT_SEED};
+use crate::state::baskt::Baskt;
+use crate::state::baskt_price::BasktPriceAccount;
+use anchor_lang::prelude::*;
+
+/// Create the published price account for a baskt. Permissionless; the payer funds rent.
+/// The account is kept up to date by `record_nav` and `rebalance`.
+#[derive(Accounts)]
+pub struct InitializeBasktPrice<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump
+    )]
+    pub baskt: Account<'info, Baskt>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = BasktPriceAccount::DISCRIMINATOR.len() + BasktPriceAccount::INIT_SPACE,
+        seeds = [BASKT_PRICE_SEED, baskt.key().as_ref()],
+        bump
+    )]
+    pub baskt_price: Account<'info, BasktPriceAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_baskt_price(ctx: Context<InitializeBasktPrice>) -> Result<()> {
+    let baskt_key = ctx.accounts.baskt.key();
+    ctx.accounts
+        .baskt_price
+        .initialize(baskt_key, ctx.bumps.baskt_price);
+    Ok(())
+}
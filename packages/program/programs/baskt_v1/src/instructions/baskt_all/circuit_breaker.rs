@@ -0,0 +1,63 @@
+use crate::constants::{BASKT_SEED, NAV_HISTORY_SEED, PROTOCOL_SEED};
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::baskt::{Baskt, BasktStatus};
+use crate::state::nav_history::NavHistory;
+use crate::state::protocol::{Protocol, Role};
+use crate::utils::load_baskt_oracle_nav;
+use anchor_lang::prelude::*;
+
+/// Resume trading on a baskt halted by its circuit breaker
+#[derive(Accounts)]
+pub struct ResumeBaskt<'info> {
+    #[account(
+        mut,
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump,
+        constraint = baskt.is_halted() @ PerpetualsError::InvalidBasktState
+    )]
+    pub baskt: Account<'info, Baskt>,
+
+    /// NAV history, re-seeded with the current NAV
+    #[account(
+        mut,
+        seeds = [NAV_HISTORY_SEED, baskt.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Account<'info, NavHistory>,
+
+    /// @dev Requires BasktManager role to resume trading
+    #[account(
+        constraint = protocol.has_permission(authority.key(), Role::BasktManager) @ PerpetualsError::UnauthorizedRole
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+    // remaining_accounts: for each baskt asset, in order,
+    // the SyntheticAsset followed by its oracle source accounts
+}
+
+pub fn resume_baskt<'info>(ctx: Context<'_, '_, 'info, 'info, ResumeBaskt<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let oracle_nav = load_baskt_oracle_nav(&ctx.accounts.baskt, ctx.remaining_accounts, &clock)?;
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+
+    // Moves from before the halt would otherwise trip the breaker again right away
+    let nav_history = &mut ctx.accounts.nav_history;
+    nav_history.clear();
+    nav_history.record(clock.unix_timestamp, oracle_nav.nav, 0)?;
+
+    let baskt = &mut ctx.accounts.baskt;
+    baskt.update_prices(oracle_nav.nav, clock.unix_timestamp)?;
+    baskt.status = BasktStatus::Active;
+
+    emit!(BasktResumedEvent {
+        baskt_id: baskt.key(),
+        resumed_by: ctx.accounts.authority.key(),
+        nav: oracle_nav.nav,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -31,7 +31,7 @@ pub fn decommission_baskt(ctx: Context<DecommissionBaskt>) -> Result<()> {
     let clock = Clock::get()?;
 
     require!(
-        matches!(baskt.status, BasktStatus::Active),
+        matches!(baskt.status, BasktStatus::Active | BasktStatus::Halted),
         PerpetualsError::InvalidBasktState
     );
 
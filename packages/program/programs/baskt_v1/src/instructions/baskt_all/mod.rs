@@ -6,6 +6,10 @@ pub mod create;
 pub mod activate;
 pub mod decomission;
 pub mod close;
+pub mod quote_nav;
+pub mod nav_history;
+pub mod baskt_price;
+pub mod circuit_breaker;
 
 pub use baskt_config::*;
 pub use market_indices::*;
@@ -13,6 +17,10 @@ pub use create::*;
 pub use activate::*;
 pub use decomission::*;
 pub use close::*;
+pub use quote_nav::*;
+pub use nav_history::*;
+pub use baskt_price::*;
+pub use circuit_breaker::*;
 
 pub use rebalance::*;
 pub use rebalance_request::*;
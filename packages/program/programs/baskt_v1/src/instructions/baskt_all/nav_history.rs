@@ -0,0 +1,132 @@
+use crate::constants::{
+    BASKT_PRICE_SEED, BASKT_SEED, NAV_HISTORY_MIN_INTERVAL_SECONDS, NAV_HISTORY_SEED,
+};
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::baskt::{Baskt, BasktStatus};
+use crate::state::baskt_price::BasktPriceAccount;
+use crate::state::nav_history::NavHistory;
+use crate::utils::load_baskt_oracle_nav;
+use anchor_lang::prelude::*;
+
+//----------------------------------------------------------------------------
+// Initialize NAV History Instruction
+//----------------------------------------------------------------------------
+
+/// Create the NAV history ring buffer for a baskt. Permissionless; the payer funds rent.
+#[derive(Accounts)]
+pub struct InitializeNavHistory<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump
+    )]
+    pub baskt: Account<'info, Baskt>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = NavHistory::DISCRIMINATOR.len() + NavHistory::INIT_SPACE,
+        seeds = [NAV_HISTORY_SEED, baskt.key().as_ref()],
+        bump
+    )]
+    pub nav_history: Account<'info, NavHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_nav_history(ctx: Context<InitializeNavHistory>) -> Result<()> {
+    let nav_history = &mut ctx.accounts.nav_history;
+    nav_history.baskt = ctx.accounts.baskt.key();
+    nav_history.head = 0;
+    nav_history.observations = Vec::new();
+    nav_history.bump = ctx.bumps.nav_history;
+    Ok(())
+}
+
+//----------------------------------------------------------------------------
+// Record NAV Instruction
+//----------------------------------------------------------------------------
+
+/// Permissionless crank appending the current oracle NAV to the baskt's history and
+/// refreshing the baskt's index and mark prices and its published price account
+#[derive(Accounts)]
+pub struct RecordNav<'info> {
+    #[account(
+        mut,
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump,
+        constraint = baskt.is_trading() @ PerpetualsError::BasktNotActive
+    )]
+    pub baskt: Account<'info, Baskt>,
+
+    #[account(
+        mut,
+        seeds = [NAV_HISTORY_SEED, baskt.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Account<'info, NavHistory>,
+
+    /// Published price account, refreshed when provided
+    #[account(
+        mut,
+        seeds = [BASKT_PRICE_SEED, baskt.key().as_ref()],
+        bump = baskt_price.bump
+    )]
+    pub baskt_price: Option<Account<'info, BasktPriceAccount>>,
+    // remaining_accounts: for each baskt asset, in order,
+    // the SyntheticAsset followed by its oracle source accounts
+}
+
+pub fn record_nav<'info>(ctx: Context<'_, '_, 'info, 'info, RecordNav<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let oracle_nav = load_baskt_oracle_nav(&ctx.accounts.baskt, ctx.remaining_accounts, &clock)?;
+    // Glitched prices must not end up in the TWAP
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+
+    // Trip the circuit breaker instead of recording an extreme move
+    if let Some(breaker) = ctx.accounts.baskt.risk_config.get_circuit_breaker() {
+        let move_bps = ctx.accounts.nav_history.max_move_bps(
+            clock.unix_timestamp,
+            breaker.window_seconds as i64,
+            oracle_nav.nav,
+        )?;
+        if move_bps > breaker.move_bps {
+            ctx.accounts.baskt.status = BasktStatus::Halted;
+            if let Some(baskt_price) = ctx.accounts.baskt_price.as_mut() {
+                baskt_price.publish(&ctx.accounts.baskt, oracle_nav.nav, oracle_nav.conf, &clock);
+            }
+
+            emit!(BasktHaltedEvent {
+                baskt_id: ctx.accounts.baskt.key(),
+                nav: oracle_nav.nav,
+                move_bps,
+                timestamp: clock.unix_timestamp,
+            });
+            return Ok(());
+        }
+    }
+
+    ctx.accounts
+        .baskt
+        .update_prices(oracle_nav.nav, clock.unix_timestamp)?;
+    ctx.accounts.nav_history.record(
+        clock.unix_timestamp,
+        oracle_nav.nav,
+        NAV_HISTORY_MIN_INTERVAL_SECONDS,
+    )?;
+
+    if let Some(baskt_price) = ctx.accounts.baskt_price.as_mut() {
+        baskt_price.publish(&ctx.accounts.baskt, oracle_nav.nav, oracle_nav.conf, &clock);
+    }
+
+    emit!(NavRecordedEvent {
+        baskt_id: ctx.accounts.baskt.key(),
+        nav: oracle_nav.nav,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
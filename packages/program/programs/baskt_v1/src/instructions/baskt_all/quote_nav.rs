@@ -0,0 +1,31 @@
+use crate::constants::BASKT_SEED;
+use crate::error::PerpetualsError;
+use crate::state::baskt::Baskt;
+use crate::utils::load_baskt_oracle_nav;
+use anchor_lang::prelude::*;
+
+/// Read-only NAV quote computed from on-chain oracle prices
+#[derive(Accounts)]
+pub struct QuoteNav<'info> {
+    #[account(
+        seeds = [BASKT_SEED, &baskt.uid.to_le_bytes()],
+        bump = baskt.bump
+    )]
+    pub baskt: Account<'info, Baskt>,
+    // remaining_accounts: for each baskt asset, in order,
+    // the SyntheticAsset followed by its oracle source accounts
+}
+
+/// Compute the baskt NAV from oracle prices without mutating any state.
+/// The NAV (PRICE_PRECISION, u64) is returned to the caller via `set_return_data`,
+/// so it can be read by simulation or by a CPI caller.
+pub fn quote_nav<'info>(ctx: Context<'_, '_, 'info, 'info, QuoteNav<'info>>) -> Result<u64> {
+    let baskt = &ctx.accounts.baskt;
+    require!(
+        baskt.allows_exits(),
+        PerpetualsError::InvalidBasktState
+    );
+
+    let clock = Clock::get()?;
+    Ok(load_baskt_oracle_nav(baskt, ctx.remaining_accounts, &clock)?.nav)
+}
@@ -1,6 +1,7 @@
-use crate::constants::{BPS_DIVISOR, PROTOCOL_SEED};
+use crate::constants::{BASKT_PRICE_SEED, BPS_DIVISOR, PROTOCOL_SEED};
 use crate::error::PerpetualsError;
 use crate::state::baskt::{AssetConfig, Baskt};
+use crate::state::baskt_price::BasktPriceAccount;
 use crate::state::protocol::{Protocol, Role};
 use crate::events::BasktRebalancedEvent;
 use anchor_lang::prelude::*;
@@ -20,6 +21,14 @@ pub struct Rebalance<'info> {
     #[account(seeds = [PROTOCOL_SEED], bump)]
     pub protocol: Account<'info, Protocol>,
 
+    /// Published price account, refreshed with the new NAV when provided
+    #[account(
+        mut,
+        seeds = [BASKT_PRICE_SEED, baskt.key().as_ref()],
+        bump = baskt_price.bump
+    )]
+    pub baskt_price: Option<Account<'info, BasktPriceAccount>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -46,7 +55,8 @@ pub fn rebalance(
         PerpetualsError::InvalidBasktConfig
     );
 
-    let current_timestamp = Clock::get()?.unix_timestamp;
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
 
     // Single pass: validate assets, weights, calculate total, and update baseline prices
     let mut total_weight: u64 = 0;
@@ -90,6 +100,11 @@ pub fn rebalance(
     baskt.last_rebalance_time = current_timestamp as u32;
     baskt.baseline_nav = new_nav;
 
+    // Rebalances reset baseline prices, so the NAV is exactly `new_nav`
+    if let Some(baskt_price) = ctx.accounts.baskt_price.as_mut() {
+        baskt_price.publish(baskt, new_nav, 0, &clock);
+    }
+
     emit!(BasktRebalancedEvent {
         baskt_id: baskt.key(),
         rebalance_index: baskt.rebalance_fee_index.cumulative_index,
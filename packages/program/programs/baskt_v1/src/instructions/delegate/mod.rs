@@ -0,0 +1,3 @@
+pub mod trading_delegate;
+
+pub use trading_delegate::*;
@@ -0,0 +1,84 @@
+use crate::constants::TRADING_DELEGATE_SEED;
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::trading_delegate::{TradingDelegate, TradingDelegateConfig};
+use anchor_lang::prelude::*;
+
+//----------------------------------------------------------------------------
+// Set Trading Delegate Instruction
+//----------------------------------------------------------------------------
+
+/// Create or update the delegation from `owner` to `delegate`
+#[derive(Accounts)]
+pub struct SetTradingDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Key being authorized to trade for the owner
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = TradingDelegate::DISCRIMINATOR.len() + TradingDelegate::INIT_SPACE,
+        seeds = [TRADING_DELEGATE_SEED, owner.key().as_ref(), delegate.key().as_ref()],
+        bump
+    )]
+    pub trading_delegate: Account<'info, TradingDelegate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_trading_delegate(
+    ctx: Context<SetTradingDelegate>,
+    config: TradingDelegateConfig,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.trading_delegate.configure(
+        ctx.accounts.owner.key(),
+        ctx.accounts.delegate.key(),
+        config,
+        ctx.bumps.trading_delegate,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(TradingDelegateSetEvent {
+        owner: ctx.accounts.owner.key(),
+        delegate: ctx.accounts.delegate.key(),
+        expires_at: config.expires_at,
+        baskt: config.baskt,
+        max_notional: config.max_notional,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+//----------------------------------------------------------------------------
+// Revoke Trading Delegate Instruction
+//----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct RevokeTradingDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TRADING_DELEGATE_SEED, owner.key().as_ref(), trading_delegate.delegate.as_ref()],
+        bump = trading_delegate.bump,
+        constraint = trading_delegate.owner == owner.key() @ PerpetualsError::Unauthorized,
+        close = owner
+    )]
+    pub trading_delegate: Account<'info, TradingDelegate>,
+}
+
+pub fn revoke_trading_delegate(ctx: Context<RevokeTradingDelegate>) -> Result<()> {
+    emit!(TradingDelegateRevokedEvent {
+        owner: ctx.accounts.owner.key(),
+        delegate: ctx.accounts.trading_delegate.delegate,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use {
+    crate::constants::{AUTHORITY_SEED, INSURANCE_FUND_SEED, INSURANCE_VAULT_SEED, PROTOCOL_SEED},
+    crate::error::PerpetualsError,
+    crate::events::*,
+    crate::state::{
+        insurance_fund::InsuranceFund,
+        position::ProgramAuthority,
+        protocol::{Protocol, Role},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, Token, TokenAccount},
+};
+
+/// Initializes the protocol insurance fund and its collateral vault
+#[derive(Accounts)]
+pub struct InitializeInsuranceFund<'info> {
+    /// Admin with Owner role who can initialize the fund
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump,
+        constraint = protocol.has_permission(admin.key(), Role::Owner) @ PerpetualsError::UnauthorizedRole
+    )]
+    pub protocol: Account<'info, Protocol>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = InsuranceFund::DISCRIMINATOR.len() + InsuranceFund::INIT_SPACE,
+        seeds = [INSURANCE_FUND_SEED],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    /// Vault owned by the program authority so settlements can move funds in and out
+    #[account(
+        init,
+        payer = admin,
+        token::mint = collateral_mint,
+        token::authority = program_authority,
+        seeds = [INSURANCE_VAULT_SEED],
+        bump
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = collateral_mint.key() == protocol.collateral_mint @ PerpetualsError::InvalidMint
+    )]
+    pub collateral_mint: Account<'info, Mint>,
+
+    /// PDA used for token authority over escrow and the insurance vault
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Account<'info, ProgramAuthority>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Initialize the insurance fund for the first time
+pub fn initialize_insurance_fund(ctx: Context<InitializeInsuranceFund>) -> Result<()> {
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.initialize(ctx.accounts.insurance_vault.key(), ctx.bumps.insurance_fund);
+
+    emit!(InsuranceFundInitializedEvent {
+        insurance_fund: insurance_fund.key(),
+        vault: ctx.accounts.insurance_vault.key(),
+        initializer: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
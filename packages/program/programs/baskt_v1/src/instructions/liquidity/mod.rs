@@ -11,3 +11,6 @@ pub use init::*;
 
 pub mod process_withdraw_queue;
 pub use process_withdraw_queue::*;
+
+pub mod insurance_fund;
+pub use insurance_fund::*;
@@ -0,0 +1,291 @@
+use crate::constants::{
+    ADL_RANKING_SEED, AUTHORITY_SEED, ESCROW_SEED, INSURANCE_FUND_SEED, LIQUIDITY_POOL_SEED,
+    MARGIN_ACCOUNT_SEED, MARGIN_ESCROW_SEED, MARGIN_LIQUIDATION_ACCOUNTS_PER_MEMBER,
+    PRICE_PRECISION, PROTOCOL_SEED,
+};
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::math::mul_div_u64;
+use crate::state::{
+    adl_ranking::AdlRanking,
+    baskt::Baskt,
+    insurance_fund::InsuranceFund,
+    liquidity::LiquidityPool,
+    margin_account::MarginAccount,
+    position::{Position, ProgramAuthority},
+    protocol::{Protocol, Role},
+};
+use crate::utils::{
+    calc_fee, calculate_margin_liquidation_settlement, calculate_pnl, close_account,
+    close_escrow_account, effective_u64, execute_insurance_transfers, value_margin_positions,
+    MarginLiquidationLeg, MarginRequirement,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Liquidate a margin account whose total equity fell below maintenance margin.
+///
+/// Every member position is closed at its baskt index price and the legs settle together
+/// against the shared collateral: collectible liquidation and rebalance fees are split as
+/// in an isolated liquidation, the rest of the collateral goes to the pool, and any loss
+/// beyond the collateral is absorbed by the insurance fund ahead of the pool.
+#[derive(Accounts)]
+pub struct LiquidateMarginAccount<'info> {
+    #[account(mut)]
+    pub liquidator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ACCOUNT_SEED, margin_account.owner.as_ref()],
+        bump = margin_account.bump,
+    )]
+    pub margin_account: Account<'info, MarginAccount>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ESCROW_SEED, margin_account.key().as_ref()],
+        bump,
+        constraint = margin_escrow.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = margin_escrow.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+    )]
+    pub margin_escrow: Account<'info, TokenAccount>,
+
+    /// Liquidity pool
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    /// BLP token vault receiving the seized collateral
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == liquidity_pool.usdc_vault @ PerpetualsError::InvalidUsdcVault,
+        constraint = usdc_vault.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = treasury_token.key() != usdc_vault.key() @ PerpetualsError::InvalidInput
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// Protocol treasury token account for fee collection
+    #[account(
+        mut,
+        constraint = treasury_token.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = treasury_token.owner == protocol.treasury @ PerpetualsError::InvalidTreasuryAccount,
+        constraint = treasury_token.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
+        constraint = treasury_token.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority
+    )]
+    pub treasury_token: Account<'info, TokenAccount>,
+
+    /// Insurance fund absorbing bad debt ahead of the liquidity pool
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    /// Token vault holding the insurance fund balance
+    #[account(
+        mut,
+        constraint = insurance_vault.key() == insurance_fund.vault @ PerpetualsError::InvalidInsuranceVault,
+        constraint = insurance_vault.key() != usdc_vault.key() @ PerpetualsError::InvalidInput
+    )]
+    pub insurance_vault: Box<Account<'info, TokenAccount>>,
+
+    /// PDA used for token authority over escrow
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Account<'info, ProgramAuthority>,
+
+    #[account(
+        constraint = protocol.feature_flags.allow_liquidations @ PerpetualsError::PositionOperationsDisabled,
+        constraint = protocol.has_permission(liquidator.key(), Role::Liquidator) @ PerpetualsError::Unauthorized,
+        seeds = [PROTOCOL_SEED],
+        bump
+    )]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// `remaining_accounts`: (Position, Baskt, position escrow, ADL ranking) for every member
+/// position, in account order; all writable.
+pub fn liquidate_margin_account<'info>(
+    ctx: Context<'_, '_, 'info, 'info, LiquidateMarginAccount<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let margin_key = ctx.accounts.margin_account.key();
+
+    let values = value_margin_positions(
+        &ctx.accounts.margin_account,
+        ctx.remaining_accounts,
+        MARGIN_LIQUIDATION_ACCOUNTS_PER_MEMBER,
+        &ctx.accounts.protocol,
+        MarginRequirement::Maintenance,
+        clock.unix_timestamp,
+    )?;
+    let health = ctx.accounts.margin_account.health(&values)?;
+    require!(!health.is_healthy(), PerpetualsError::MarginAccountNotLiquidatable);
+
+    let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED, &[ctx.bumps.program_authority]];
+    let mut collateral = ctx.accounts.margin_account.collateral;
+    let mut legs = Vec::with_capacity(values.len());
+
+    for accounts in ctx
+        .remaining_accounts
+        .chunks(MARGIN_LIQUIDATION_ACCOUNTS_PER_MEMBER)
+        .take(values.len())
+    {
+        // Members and their baskts were checked against the margin account while valuing it
+        let mut position = Account::<Position>::try_from(&accounts[0])?;
+        let mut baskt = Account::<Baskt>::try_from(&accounts[1])?;
+        require!(baskt.allows_exits(), PerpetualsError::InvalidBasktState);
+        let exit_price = baskt.index_price;
+
+        position.update_market_indices(
+            baskt.market_indices.cumulative_funding_index,
+            baskt.market_indices.cumulative_borrow_index,
+            exit_price,
+        )?;
+        let rebalance_fee_owed =
+            position.apply_rebalance_fee(baskt.rebalance_fee_index.cumulative_index, exit_price)?;
+
+        let liquidation_fee_bps = effective_u64(
+            baskt.config.get_liquidation_fee_bps(),
+            ctx.accounts.protocol.config.liquidation_fee_bps,
+        );
+        let exit_notional = mul_div_u64(position.size, exit_price, PRICE_PRECISION)?;
+        let pnl = calculate_pnl(position.is_long, position.entry_price, position.size, exit_price)?;
+        legs.push(MarginLiquidationLeg {
+            equity: (pnl as i128)
+                .checked_add(position.funding_accumulated)
+                .ok_or(PerpetualsError::MathOverflow)?
+                .checked_add(position.borrow_accumulated)
+                .ok_or(PerpetualsError::MathOverflow)?,
+            fees: calc_fee(exit_notional, liquidation_fee_bps)?
+                .checked_add(rebalance_fee_owed)
+                .ok_or(PerpetualsError::MathOverflow)?,
+        });
+
+        // Anything left in the position escrow backs the account like shared collateral
+        let (escrow_key, _) =
+            Pubkey::find_program_address(&[ESCROW_SEED, position.key().as_ref()], &crate::ID);
+        require_keys_eq!(accounts[2].key(), escrow_key, PerpetualsError::InvalidMarginPositions);
+        let escrow = Account::<TokenAccount>::try_from(&accounts[2])?;
+        if escrow.amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: escrow.to_account_info(),
+                        to: ctx.accounts.margin_escrow.to_account_info(),
+                        authority: ctx.accounts.program_authority.to_account_info(),
+                    },
+                    &[authority_seeds],
+                ),
+                escrow.amount,
+            )?;
+            collateral = collateral
+                .checked_add(escrow.amount)
+                .ok_or(PerpetualsError::MathOverflow)?;
+        }
+        close_escrow_account(
+            &ctx.accounts.token_program,
+            &escrow,
+            &ctx.accounts.liquidator.to_account_info(),
+            &ctx.accounts.program_authority.to_account_info(),
+            ctx.bumps.program_authority,
+        )?;
+
+        // Track open interest; a closed position is no longer an auto-deleveraging candidate
+        baskt.remove_open_interest(position.is_long, position.size);
+        baskt.open_positions = baskt
+            .open_positions
+            .checked_sub(1)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        baskt.exit(&crate::ID)?;
+
+        let (ranking_key, _) =
+            Pubkey::find_program_address(&[ADL_RANKING_SEED, baskt.key().as_ref()], &crate::ID);
+        require_keys_eq!(accounts[3].key(), ranking_key, PerpetualsError::InvalidMarginPositions);
+        AdlRanking::remove_from_account(&accounts[3], &position.key())?;
+
+        close_account(&accounts[0], &ctx.accounts.liquidator.to_account_info())?;
+    }
+
+    let settlement = calculate_margin_liquidation_settlement(
+        collateral,
+        &legs,
+        ctx.accounts.protocol.config.treasury_cut_bps,
+        ctx.accounts.protocol.config.insurance_cut_bps,
+    )?;
+
+    for (to, amount) in [
+        (ctx.accounts.treasury_token.to_account_info(), settlement.escrow_to_treasury),
+        (ctx.accounts.usdc_vault.to_account_info(), settlement.escrow_to_pool),
+    ] {
+        if amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.margin_escrow.to_account_info(),
+                        to,
+                        authority: ctx.accounts.program_authority.to_account_info(),
+                    },
+                    &[authority_seeds],
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    // Route the insurance cut into the fund and let it absorb bad debt before the pool does
+    let insurance_settlement = ctx.accounts.insurance_fund.settle(
+        settlement.escrow_to_insurance,
+        settlement.pool_shortfall,
+        ctx.accounts.insurance_vault.amount,
+    )?;
+    execute_insurance_transfers(
+        &ctx.accounts.token_program,
+        &ctx.accounts.margin_escrow,
+        &ctx.accounts.insurance_vault,
+        &ctx.accounts.usdc_vault.to_account_info(),
+        &ctx.accounts.program_authority.to_account_info(),
+        ctx.bumps.program_authority,
+        &insurance_settlement,
+    )?;
+    ctx.accounts.liquidity_pool.increase_liquidity(
+        settlement
+            .escrow_to_pool
+            .checked_add(insurance_settlement.bad_debt_covered)
+            .ok_or(PerpetualsError::MathOverflow)?,
+    )?;
+
+    let margin_account = &mut ctx.accounts.margin_account;
+    let positions_closed = margin_account.positions.len() as u8;
+    margin_account.collateral = 0;
+    margin_account.positions.clear();
+
+    emit!(MarginAccountLiquidatedEvent {
+        owner: margin_account.owner,
+        margin_account: margin_key,
+        liquidator: ctx.accounts.liquidator.key(),
+        total_equity: settlement.total_equity,
+        required_margin: health.required_margin,
+        positions_closed,
+        collateral_seized: collateral,
+        fee_to_treasury: settlement.escrow_to_treasury,
+        fee_to_insurance: insurance_settlement.fee_received,
+        fee_to_blp: settlement.fee_to_blp,
+        escrow_to_pool: settlement.escrow_to_pool,
+        pool_shortfall: settlement.pool_shortfall,
+        bad_debt_covered: insurance_settlement.bad_debt_covered,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+use crate::constants::{
+    AUTHORITY_SEED, MARGIN_ACCOUNT_SEED, MARGIN_ESCROW_SEED, MARGIN_VALUATION_ACCOUNTS_PER_MEMBER,
+    PROTOCOL_SEED,
+};
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::{
+    margin_account::MarginAccount,
+    position::ProgramAuthority,
+    protocol::Protocol,
+};
+use crate::utils::{value_margin_positions, MarginRequirement};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+//----------------------------------------------------------------------------
+// Create Margin Account Instruction
+//----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct CreateMarginAccount<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = MarginAccount::DISCRIMINATOR.len() + MarginAccount::INIT_SPACE,
+        seeds = [MARGIN_ACCOUNT_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub margin_account: Account<'info, MarginAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [MARGIN_ESCROW_SEED, margin_account.key().as_ref()],
+        bump,
+        token::mint = collateral_mint,
+        token::authority = program_authority,
+    )]
+    pub margin_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = collateral_mint.key() == protocol.collateral_mint @ PerpetualsError::InvalidMint
+    )]
+    pub collateral_mint: Account<'info, Mint>,
+
+    /// PDA used for token authority over escrow
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Account<'info, ProgramAuthority>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump
+    )]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_margin_account(ctx: Context<CreateMarginAccount>) -> Result<()> {
+    ctx.accounts
+        .margin_account
+        .initialize(ctx.accounts.owner.key(), ctx.bumps.margin_account);
+    Ok(())
+}
+
+//----------------------------------------------------------------------------
+// Deposit Margin Collateral Instruction
+//----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct DepositMarginCollateral<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = margin_account.bump,
+        constraint = margin_account.owner == owner.key() @ PerpetualsError::Unauthorized,
+    )]
+    pub margin_account: Account<'info, MarginAccount>,
+
+    #[account(
+        mut,
+        constraint = owner_collateral_account.owner == owner.key() @ PerpetualsError::Unauthorized,
+        constraint = owner_collateral_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = owner_collateral_account.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
+        constraint = owner_collateral_account.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority
+    )]
+    pub owner_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ESCROW_SEED, margin_account.key().as_ref()],
+        bump,
+        constraint = margin_escrow.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = margin_escrow.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+    )]
+    pub margin_escrow: Account<'info, TokenAccount>,
+
+    /// PDA used for token authority over escrow
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Account<'info, ProgramAuthority>,
+
+    #[account(
+        constraint = protocol.feature_flags.allow_add_collateral @ PerpetualsError::PositionOperationsDisabled,
+        seeds = [PROTOCOL_SEED],
+        bump
+    )]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn deposit_margin_collateral(ctx: Context<DepositMarginCollateral>, amount: u64) -> Result<()> {
+    ctx.accounts.margin_account.deposit(amount)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_collateral_account.to_account_info(),
+                to: ctx.accounts.margin_escrow.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(MarginCollateralDepositedEvent {
+        owner: ctx.accounts.owner.key(),
+        margin_account: ctx.accounts.margin_account.key(),
+        amount,
+        new_total_collateral: ctx.accounts.margin_account.collateral,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+//----------------------------------------------------------------------------
+// Withdraw Margin Collateral Instruction
+//----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct WithdrawMarginCollateral<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = margin_account.bump,
+        constraint = margin_account.owner == owner.key() @ PerpetualsError::Unauthorized,
+    )]
+    pub margin_account: Account<'info, MarginAccount>,
+
+    #[account(
+        mut,
+        constraint = owner_collateral_account.owner == owner.key() @ PerpetualsError::Unauthorized,
+        constraint = owner_collateral_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+    )]
+    pub owner_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ESCROW_SEED, margin_account.key().as_ref()],
+        bump,
+        constraint = margin_escrow.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = margin_escrow.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+    )]
+    pub margin_escrow: Account<'info, TokenAccount>,
+
+    /// PDA used for token authority over escrow
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Account<'info, ProgramAuthority>,
+
+    #[account(
+        constraint = protocol.feature_flags.allow_collateral_withdrawal @ PerpetualsError::PositionOperationsDisabled,
+        seeds = [PROTOCOL_SEED],
+        bump
+    )]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw shared collateral while the account stays above the initial margin.
+/// `remaining_accounts`: (Position, Baskt) for every member position, in account order.
+pub fn withdraw_margin_collateral<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawMarginCollateral<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.margin_account.withdraw(amount)?;
+
+    let values = value_margin_positions(
+        &ctx.accounts.margin_account,
+        ctx.remaining_accounts,
+        MARGIN_VALUATION_ACCOUNTS_PER_MEMBER,
+        &ctx.accounts.protocol,
+        MarginRequirement::Initial,
+        clock.unix_timestamp,
+    )?;
+    require!(
+        ctx.accounts.margin_account.health(&values)?.is_healthy(),
+        PerpetualsError::InsufficientMarginEquity
+    );
+
+    let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED, &[ctx.bumps.program_authority]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.margin_escrow.to_account_info(),
+                to: ctx.accounts.owner_collateral_account.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            &[authority_seeds],
+        ),
+        amount,
+    )?;
+
+    emit!(MarginCollateralWithdrawnEvent {
+        owner: ctx.accounts.owner.key(),
+        margin_account: ctx.accounts.margin_account.key(),
+        amount,
+        new_total_collateral: ctx.accounts.margin_account.collateral,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use crate::constants::{
+    AUTHORITY_SEED, ESCROW_SEED, MARGIN_ACCOUNT_SEED, MARGIN_ESCROW_SEED,
+    MARGIN_VALUATION_ACCOUNTS_PER_MEMBER, POSITION_SEED, PROTOCOL_SEED,
+};
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::{
+    baskt::Baskt,
+    margin_account::MarginAccount,
+    position::{Position, PositionStatus, ProgramAuthority},
+    protocol::Protocol,
+};
+use crate::utils::{value_margin_position, value_margin_positions, MarginRequirement};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+//----------------------------------------------------------------------------
+// Attach Margin Position Instruction
+//----------------------------------------------------------------------------
+
+/// Move an isolated position into the owner's margin account. Its collateral joins the
+/// shared pool and the position is then margined by total account equity.
+#[derive(Accounts)]
+pub struct AttachMarginPosition<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = margin_account.bump,
+        constraint = margin_account.owner == owner.key() @ PerpetualsError::Unauthorized,
+    )]
+    pub margin_account: Account<'info, MarginAccount>,
+
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, owner.key().as_ref(), &position.position_id.to_le_bytes()],
+        bump = position.bump,
+        constraint = position.owner == owner.key() @ PerpetualsError::Unauthorized,
+        constraint = position.status as u8 == PositionStatus::Open as u8 @ PerpetualsError::PositionAlreadyClosed,
+        constraint = !position.is_cross_margined() @ PerpetualsError::PositionIsCrossMargined,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, position.key().as_ref()],
+        bump,
+        constraint = position_escrow.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = position_escrow.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+    )]
+    pub position_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ESCROW_SEED, margin_account.key().as_ref()],
+        bump,
+        constraint = margin_escrow.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = margin_escrow.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+    )]
+    pub margin_escrow: Account<'info, TokenAccount>,
+
+    /// PDA used for token authority over escrow
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Account<'info, ProgramAuthority>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump
+    )]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn attach_margin_position(ctx: Context<AttachMarginPosition>) -> Result<()> {
+    let margin_account = &mut ctx.accounts.margin_account;
+    let position = &mut ctx.accounts.position;
+    let collateral = position.collateral;
+
+    margin_account.add_position(position.key())?;
+    margin_account.deposit(collateral)?;
+    position.collateral = 0;
+    position.margin_account = Some(margin_account.key());
+
+    let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED, &[ctx.bumps.program_authority]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.position_escrow.to_account_info(),
+                to: ctx.accounts.margin_escrow.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            &[authority_seeds],
+        ),
+        collateral,
+    )?;
+
+    emit!(MarginPositionAttachedEvent {
+        owner: position.owner,
+        margin_account: margin_account.key(),
+        position: position.key(),
+        collateral_moved: collateral,
+        new_total_collateral: margin_account.collateral,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+//----------------------------------------------------------------------------
+// Detach Margin Position Instruction
+//----------------------------------------------------------------------------
+
+/// Return a member position to isolated margin with `collateral` taken from the shared
+/// pool. Both the detached position and the remaining account must meet initial margin.
+#[derive(Accounts)]
+pub struct DetachMarginPosition<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = margin_account.bump,
+        constraint = margin_account.owner == owner.key() @ PerpetualsError::Unauthorized,
+    )]
+    pub margin_account: Account<'info, MarginAccount>,
+
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, owner.key().as_ref(), &position.position_id.to_le_bytes()],
+        bump = position.bump,
+        constraint = position.owner == owner.key() @ PerpetualsError::Unauthorized,
+        constraint = position.margin_account == Some(margin_account.key()) @ PerpetualsError::InvalidMarginPositions,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(
+        constraint = baskt.key() == position.baskt_id @ PerpetualsError::InvalidBaskt,
+    )]
+    pub baskt: Box<Account<'info, Baskt>>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, position.key().as_ref()],
+        bump,
+        constraint = position_escrow.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = position_escrow.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+    )]
+    pub position_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [MARGIN_ESCROW_SEED, margin_account.key().as_ref()],
+        bump,
+        constraint = margin_escrow.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = margin_escrow.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+    )]
+    pub margin_escrow: Account<'info, TokenAccount>,
+
+    /// PDA used for token authority over escrow
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Account<'info, ProgramAuthority>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump
+    )]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// `remaining_accounts`: (Position, Baskt) for every position left in the account.
+pub fn detach_margin_position<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DetachMarginPosition<'info>>,
+    collateral: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let margin_account = &mut ctx.accounts.margin_account;
+    let position = &mut ctx.accounts.position;
+    let baskt = &ctx.accounts.baskt;
+
+    margin_account.remove_position(position.key())?;
+    margin_account.withdraw(collateral)?;
+    position.collateral = collateral;
+    position.margin_account = None;
+
+    // The detached position must stand on its own collateral
+    let isolated = value_margin_position(
+        position,
+        baskt,
+        &ctx.accounts.protocol,
+        MarginRequirement::Initial,
+        clock.unix_timestamp,
+    )?;
+    require!(
+        isolated.equity >= isolated.required_margin as i128,
+        PerpetualsError::InsufficientCollateral
+    );
+
+    // And so must what remains in the margin account
+    let values = value_margin_positions(
+        margin_account,
+        ctx.remaining_accounts,
+        MARGIN_VALUATION_ACCOUNTS_PER_MEMBER,
+        &ctx.accounts.protocol,
+        MarginRequirement::Initial,
+        clock.unix_timestamp,
+    )?;
+    require!(
+        margin_account.health(&values)?.is_healthy(),
+        PerpetualsError::InsufficientMarginEquity
+    );
+
+    let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED, &[ctx.bumps.program_authority]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.margin_escrow.to_account_info(),
+                to: ctx.accounts.position_escrow.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            &[authority_seeds],
+        ),
+        collateral,
+    )?;
+
+    emit!(MarginPositionDetachedEvent {
+        owner: position.owner,
+        margin_account: margin_account.key(),
+        position: position.key(),
+        collateral_moved: collateral,
+        new_total_collateral: margin_account.collateral,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod liquidate;
+pub mod margin_account;
+pub mod membership;
+
+pub use liquidate::*;
+pub use margin_account::*;
+pub use membership::*;
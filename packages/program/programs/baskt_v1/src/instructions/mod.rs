@@ -1,9 +1,15 @@
+pub mod adl;
 pub mod asset;
 pub mod baskt_all;
+pub mod delegate;
 pub mod liquidity;
+pub mod margin;
+pub mod oracle;
 pub mod order;
 pub mod position;
 pub mod protocol;
 
 // bring everything in scope
-pub use {asset::*, baskt_all::*, liquidity::*, order::*, position::*, protocol::*};
+pub use {adl::*, asset::*, baskt_all::*, delegate::*, liquidity::*, margin::*, oracle::*, order::*, position::*, protocol::*};
+// `close` and `liquidate` exist in more than one module; the position ones win
+pub use position::{close, liquidate};
@@ -0,0 +1,47 @@
+use crate::constants::*;
+use crate::error::PerpetualsError;
+use crate::state::{
+    asset::SyntheticAsset,
+    price_feed::PriceFeed,
+    protocol::{Protocol, Role},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializePriceFeed<'info> {
+    #[account(mut,
+        constraint = protocol.has_permission(authority.key(), Role::AssetManager) @ PerpetualsError::UnauthorizedRole
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ASSET_SEED, asset.ticker.as_bytes()],
+        bump
+    )]
+    pub asset: Account<'info, SyntheticAsset>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PriceFeed::DISCRIMINATOR.len() + PriceFeed::INIT_SPACE,
+        seeds = [PRICE_FEED_SEED, asset.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_price_feed(ctx: Context<InitializePriceFeed>) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.asset = ctx.accounts.asset.key();
+    price_feed.price = 0;
+    price_feed.conf = 0;
+    price_feed.publish_time = 0;
+    price_feed.last_signer = Pubkey::default();
+    price_feed.bump = ctx.bumps.price_feed;
+    Ok(())
+}
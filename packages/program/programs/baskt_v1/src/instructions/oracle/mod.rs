@@ -0,0 +1,7 @@
+pub mod initialize_price_feed;
+pub mod update_price_feed;
+pub mod post_signed_price;
+
+pub use initialize_price_feed::*;
+pub use update_price_feed::*;
+pub use post_signed_price::*;
@@ -0,0 +1,79 @@
+use crate::constants::*;
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::instructions::oracle::update_price_feed::PriceFeedUpdateParams;
+use crate::state::{
+    price_feed::PriceFeed,
+    protocol::{Protocol, Role},
+};
+use crate::utils::parse_ed25519_instruction;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+/// Publish a price signed off-chain by an Oracle role holder. Anyone can submit it.
+///
+/// The instruction immediately before this one must be an ed25519 precompile
+/// instruction verifying the oracle's signature over `PriceFeed::signed_message`.
+#[derive(Accounts)]
+pub struct PostSignedPrice<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PRICE_FEED_SEED, price_feed.asset.as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+
+    /// CHECK: Instructions sysvar, used to read the ed25519 verification instruction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+pub fn post_signed_price(ctx: Context<PostSignedPrice>, params: PriceFeedUpdateParams) -> Result<()> {
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.to_account_info();
+    let current_index = load_current_index_checked(&instructions_sysvar)?;
+    require!(current_index > 0, PerpetualsError::InvalidPriceSignature);
+    let ed25519_ix =
+        load_instruction_at_checked((current_index - 1) as usize, &instructions_sysvar)?;
+    let (signer, message) = parse_ed25519_instruction(&ed25519_ix)?;
+
+    require!(
+        ctx.accounts.protocol.has_permission(signer, Role::Oracle),
+        PerpetualsError::UnauthorizedRole
+    );
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    let expected = PriceFeed::signed_message(
+        &price_feed.key(),
+        params.price,
+        params.conf,
+        params.publish_time,
+    );
+    require!(message == expected, PerpetualsError::InvalidPriceSignature);
+
+    let clock = Clock::get()?;
+    price_feed.update(
+        params.price,
+        params.conf,
+        params.publish_time,
+        signer,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(PriceFeedUpdatedEvent {
+        price_feed: price_feed.key(),
+        asset: price_feed.asset,
+        price: params.price,
+        conf: params.conf,
+        publish_time: params.publish_time,
+        signer,
+    });
+
+    Ok(())
+}
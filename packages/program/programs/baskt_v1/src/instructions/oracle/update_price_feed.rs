@@ -0,0 +1,59 @@
+use crate::constants::*;
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::{
+    price_feed::PriceFeed,
+    protocol::{Protocol, Role},
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PriceFeedUpdateParams {
+    pub price: u64,
+    pub conf: u64,
+    pub publish_time: i64,
+}
+
+/// Publish a price directly as an Oracle role holder
+#[derive(Accounts)]
+pub struct UpdatePriceFeed<'info> {
+    #[account(
+        constraint = protocol.has_permission(authority.key(), Role::Oracle) @ PerpetualsError::UnauthorizedRole
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PRICE_FEED_SEED, price_feed.asset.as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
+
+pub fn update_price_feed(ctx: Context<UpdatePriceFeed>, params: PriceFeedUpdateParams) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+    let signer = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    price_feed.update(
+        params.price,
+        params.conf,
+        params.publish_time,
+        signer,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(PriceFeedUpdatedEvent {
+        price_feed: price_feed.key(),
+        asset: price_feed.asset,
+        price: params.price,
+        conf: params.conf,
+        publish_time: params.publish_time,
+        signer,
+    });
+
+    Ok(())
+}
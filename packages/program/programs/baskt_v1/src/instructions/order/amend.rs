@@ -0,0 +1,163 @@
+use crate::constants::{AUTHORITY_SEED, ORDER_SEED, PROTOCOL_SEED, USER_ESCROW_SEED};
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::instructions::order::create_order::validate_order_collateral;
+use crate::state::{
+    baskt::Baskt,
+    order::{Order, OrderStatus},
+    protocol::Protocol,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+//----------------------------------------------------------------------------
+// PARAMS STRUCTURES
+//----------------------------------------------------------------------------
+
+/// Fields left as None keep their current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AmendOrderParams {
+    /// Limit price for limit orders; slippage guard reference price for market orders
+    pub limit_price: Option<u64>,
+    /// Market order slippage guard
+    pub max_slippage_bps: Option<u64>,
+    /// Open and increase orders only
+    pub notional_value: Option<u64>,
+    /// Open and increase orders only; the difference moves between owner and escrow
+    pub collateral: Option<u64>,
+}
+
+//----------------------------------------------------------------------------
+// INSTRUCTION HANDLERS: ORDER
+//----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct AmendOrder<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, owner.key().as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+        constraint = order.owner == owner.key() @ PerpetualsError::Unauthorized,
+        constraint = order.status as u8 == OrderStatus::Pending as u8 @ PerpetualsError::OrderAlreadyProcessed,
+    )]
+    pub order: Account<'info, Order>,
+
+    /// Baskt the order trades, for its collateral and fee configuration
+    #[account(
+        constraint = baskt.key() == order.baskt_id @ PerpetualsError::InvalidBaskt,
+    )]
+    pub baskt: Account<'info, Baskt>,
+
+    #[account(
+        mut,
+        constraint = owner_collateral_account.owner == owner.key() @ PerpetualsError::UnauthorizedTokenOwner,
+        constraint = owner_collateral_account.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
+        constraint = owner_collateral_account.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority,
+        constraint = owner_collateral_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint
+    )]
+    pub owner_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [USER_ESCROW_SEED, owner.key().as_ref()],
+        bump,
+        constraint = owner_collateral_escrow_account.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = owner_collateral_escrow_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = owner_collateral_escrow_account.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
+        constraint = owner_collateral_escrow_account.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority
+    )]
+    pub owner_collateral_escrow_account: Account<'info, TokenAccount>,
+
+    ///CHECK: PDA used for token authority. Needed to sign the transfer from escrow.
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: AccountInfo<'info>,
+
+    /// Protocol account to verify feature flags
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump,
+        constraint = protocol.feature_flags.allow_trading @ PerpetualsError::TradingDisabled
+    )]
+    pub protocol: Account<'info, Protocol>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn amend_order(ctx: Context<AmendOrder>, params: AmendOrderParams) -> Result<()> {
+    let order = &mut ctx.accounts.order;
+    let clock = Clock::get()?;
+
+    require!(!order.is_expired(clock.unix_timestamp), PerpetualsError::OrderExpired);
+
+    let old_collateral = order.escrowed_collateral();
+
+    order.amend_price(params.limit_price, params.max_slippage_bps)?;
+    let (notional_value, collateral, leverage_bps) =
+        order.amend_size(params.notional_value, params.collateral)?;
+
+    // Re-validate collateralized orders against the current baskt and protocol config
+    if params.notional_value.is_some() || params.collateral.is_some() {
+        validate_order_collateral(
+            &ctx.accounts.baskt,
+            &ctx.accounts.protocol,
+            notional_value,
+            collateral,
+            leverage_bps,
+        )?;
+    }
+
+    // Move the collateral difference between the owner and the order escrow
+    if collateral > old_collateral {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner_collateral_account.to_account_info(),
+                    to: ctx
+                        .accounts
+                        .owner_collateral_escrow_account
+                        .to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            collateral - old_collateral,
+        )?;
+    } else if collateral < old_collateral {
+        let signer_seeds = [AUTHORITY_SEED, &[ctx.bumps.program_authority]];
+        let signer = &[&signer_seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx
+                        .accounts
+                        .owner_collateral_escrow_account
+                        .to_account_info(),
+                    to: ctx.accounts.owner_collateral_account.to_account_info(),
+                    authority: ctx.accounts.program_authority.to_account_info(),
+                },
+                signer,
+            ),
+            old_collateral - collateral,
+        )?;
+    }
+
+    emit!(OrderAmendedEvent {
+        owner: order.owner,
+        order_id: order.order_id as u64,
+        baskt_id: order.baskt_id,
+        limit_price: params.limit_price,
+        max_slippage_bps: params.max_slippage_bps,
+        notional_value: params.notional_value,
+        collateral: params.collateral,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,12 +1,13 @@
 use crate::constants::{
-    AUTHORITY_SEED, ORDER_SEED, PROTOCOL_SEED,
+    AUTHORITY_SEED, ORDER_SEED, PROTOCOL_SEED, TRADING_DELEGATE_SEED,
     USER_ESCROW_SEED,
 };
 use crate::error::PerpetualsError;
 use crate::events::*;
 use crate::state::{
-    order::{Order, OrderAction, OrderStatus},
+    order::{Order, OrderStatus},
     protocol::Protocol,
+    trading_delegate::TradingDelegate,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
@@ -22,8 +23,19 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 #[derive(Accounts)]
 pub struct CancelOrder<'info> {
+    /// Owner, or a trading delegate of the owner
+    pub authority: Signer<'info>,
+
+    /// CHECK: Order owner; receives the rent and the refund
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub owner: UncheckedAccount<'info>,
+
+    /// Required when the authority is not the owner
+    #[account(
+        seeds = [TRADING_DELEGATE_SEED, owner.key().as_ref(), authority.key().as_ref()],
+        bump = trading_delegate.bump,
+    )]
+    pub trading_delegate: Option<Account<'info, TradingDelegate>>,
 
     #[account(
         mut,
@@ -77,9 +89,18 @@ pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
     let order = &ctx.accounts.order; // Borrow immutably as state changes are handled by close = owner
     let clock = Clock::get()?;
 
-    // Only open orders have collateral to return from escrow
-    if order.action == OrderAction::Open {
-        let open_params = order.get_open_params()?;
+    TradingDelegate::check_authority(
+        ctx.accounts.trading_delegate.as_deref(),
+        order.owner,
+        ctx.accounts.authority.key(),
+        order.baskt_id,
+        0,
+        clock.unix_timestamp,
+    )?;
+
+    // Only open and increase orders have collateral to return from escrow
+    let escrowed_collateral = order.escrowed_collateral();
+    if escrowed_collateral > 0 {
         // Use signer seeds with canonical bump derived by Anchor
         let signer_seeds = [AUTHORITY_SEED, &[ctx.bumps.program_authority]];
         let signer = &[&signer_seeds[..]];
@@ -97,7 +118,7 @@ pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
                 },
                 signer,
             ),
-            open_params.collateral,
+            escrowed_collateral,
         )?;
     }
 
@@ -1,6 +1,6 @@
 use crate::constants::BPS_DIVISOR;
 use crate::constants::{
-    AUTHORITY_SEED, ORDER_SEED, PROTOCOL_SEED,
+    AUTHORITY_SEED, ORDER_SEED, PROTOCOL_SEED, TRADING_DELEGATE_SEED,
     USER_ESCROW_SEED,
 };
 use crate::error::PerpetualsError;
@@ -8,8 +8,10 @@ use crate::events::*;
 use crate::math::mul_div_u64;
 use crate::state::{
     baskt::{Baskt, BasktStatus},
-    order::{Order, OrderAction, OrderStatus, OrderType, OpenOrderParams, CloseOrderParams, MarketOrderParams, LimitOrderParams},
+    position::{Position, PositionStatus},
+    order::{Order, OrderAction, OrderStatus, OrderType, OpenOrderParams, CloseOrderParams, IncreaseOrderParams, LimitOrderParams, SlippageGuard, TriggerDirection, TriggerOrderParams},
     protocol::Protocol,
+    trading_delegate::TradingDelegate,
 };
 use crate::utils::{
     calc_min_collateral_from_notional, calc_opening_fee_with_effective_rate, effective_u64,
@@ -30,10 +32,24 @@ pub struct CreateOrderParams {
     pub action: OrderAction,
     pub target_position: Option<Pubkey>,
     pub size_as_contracts: Option<u64>,
+    /// Limit price for limit orders; optional reference price for market orders (0 = none)
     pub limit_price: u64,
+    /// Market order slippage guard around `limit_price`; ignored by other order types
     pub max_slippage_bps: u64,
     pub leverage_bps: u64,
     pub order_type: OrderType,
+    pub trigger_price: u64,
+    pub trigger_direction: TriggerDirection,
+    /// Unix timestamp after which the order can no longer be filled (None = good-til-cancelled)
+    pub expires_at: Option<i64>,
+    /// Close orders only: clamp fills to the remaining position size
+    pub reduce_only: bool,
+    /// Trailing stops only: retracement from the watermark, which starts at `trigger_price`
+    pub trail_bps: u64,
+    /// TWAP orders only: number of tranches the notional and collateral are split into
+    pub tranche_count: u16,
+    /// TWAP orders only: minimum seconds between tranche fills
+    pub tranche_interval_seconds: u32,
 }
 
 //----------------------------------------------------------------------------
@@ -43,12 +59,23 @@ pub struct CreateOrderParams {
 #[derive(Accounts)]
 #[instruction(params: CreateOrderParams)]
 pub struct CreateOrder<'info> {
+    /// Owner, or a trading delegate of the owner; pays rent and funds the collateral
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    /// CHECK: Order owner; must be the authority unless trading_delegate authorizes it
+    pub owner: UncheckedAccount<'info>,
+
+    /// Required when the authority is not the owner
+    #[account(
+        seeds = [TRADING_DELEGATE_SEED, owner.key().as_ref(), authority.key().as_ref()],
+        bump = trading_delegate.bump,
+    )]
+    pub trading_delegate: Option<Account<'info, TradingDelegate>>,
 
     #[account(
         init,
-        payer = owner,
+        payer = authority,
         space = Order::DISCRIMINATOR.len() + Order::INIT_SPACE,
         seeds = [ORDER_SEED, owner.key().as_ref(), &params.order_id.to_le_bytes()],
         bump
@@ -59,9 +86,16 @@ pub struct CreateOrder<'info> {
     #[account()]
     pub baskt: Account<'info, Baskt>,
 
+    /// Position targeted by close and increase orders
+    #[account(
+        constraint = Some(target_position.key()) == params.target_position @ PerpetualsError::InvalidTargetPosition,
+    )]
+    pub target_position: Option<Account<'info, Position>>,
+
+    /// Token account funding the collateral, held by the authority
     #[account(
         mut,
-        constraint = owner_collateral_account.owner == owner.key() @ PerpetualsError::UnauthorizedTokenOwner,
+        constraint = owner_collateral_account.owner == authority.key() @ PerpetualsError::UnauthorizedTokenOwner,
         constraint = owner_collateral_account.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
         constraint = owner_collateral_account.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority,
         constraint = owner_collateral_account.mint == collateral_mint.key() @ PerpetualsError::InvalidMint
@@ -75,7 +109,7 @@ pub struct CreateOrder<'info> {
 
     #[account(
         init_if_needed,
-        payer = owner,
+        payer = authority,
         seeds = [USER_ESCROW_SEED, owner.key().as_ref()],
         bump,
         token::mint = collateral_mint,
@@ -110,12 +144,40 @@ pub fn create_order(
     params: CreateOrderParams,
 ) -> Result<()> {
 
+    // Delegates may only add exposure within their baskt scope and notional cap
+    let delegated_notional = match params.action {
+        OrderAction::Open | OrderAction::Increase => params.notional_value,
+        OrderAction::Close => 0,
+    };
+    TradingDelegate::check_authority(
+        ctx.accounts.trading_delegate.as_deref(),
+        ctx.accounts.owner.key(),
+        ctx.accounts.authority.key(),
+        ctx.accounts.baskt.key(),
+        delegated_notional,
+        Clock::get()?.unix_timestamp,
+    )?;
+
     if params.order_type == OrderType::Limit {
         require!(params.limit_price > 0, PerpetualsError::InvalidInput);
     }
 
+    // Stop-loss, take-profit and trailing-stop orders protect an existing position
+    if params.order_type.is_trigger() {
+        require!(params.action == OrderAction::Close, PerpetualsError::InvalidOrderAction);
+        require!(params.trigger_price > 0, PerpetualsError::InvalidInput);
+    }
+
+    // Direction of the targeted position, known for close orders
+    let mut target_is_long = None;
+
     // Validate based on action type
-    if params.action == OrderAction::Open {
+    if matches!(params.action, OrderAction::Open | OrderAction::Increase) {
+        // Increase orders add size to an existing position
+        if params.action == OrderAction::Increase {
+            validate_target_position(ctx.accounts)?;
+        }
+
         // Ensure the baskt is active for trading
         require!(
             ctx.accounts.baskt.is_trading(),
@@ -130,58 +192,26 @@ pub fn create_order(
             );
         }
 
-        // Basic collateral check
-        require!(params.collateral > 0, PerpetualsError::InsufficientCollateral);
-
-        // Notional value must be > 0 and within configured bounds
-        require!(params.notional_value > 0, PerpetualsError::ZeroSizedPosition);          
-
-
-        const MAX_REASONABLE_NOTIONAL: u64 = 1_000_000_000_000_000; // 1B USDC
-        require!(
-            params.notional_value <= MAX_REASONABLE_NOTIONAL,
-            PerpetualsError::MathOverflow
-        );
-
-        let min_collateral_ratio_bps = effective_u64(
-            ctx.accounts.baskt.config.get_min_collateral_ratio_bps(),
-            ctx.accounts.protocol.config.min_collateral_ratio_bps,
-        );
-
-        // Calculate effective collateral ratio: use the higher of min collateral ratio or leverage requirement
-        let leverage_ratio_bps = BPS_DIVISOR.checked_div(params.leverage_bps).ok_or(PerpetualsError::MathOverflow)?;
-        let effective_collateral_ratio_bps = std::cmp::max(min_collateral_ratio_bps, leverage_ratio_bps);
-
-        // Calculate required collateral using the effective ratio
-        let min_collateral = calc_min_collateral_from_notional(params.notional_value, effective_collateral_ratio_bps)?;
-
-        let opening_fee = calc_opening_fee_with_effective_rate(
+        validate_order_collateral(
+            &ctx.accounts.baskt,
+            &ctx.accounts.protocol,
             params.notional_value,
-            ctx.accounts.baskt.config.get_opening_fee_bps(),
-            ctx.accounts.protocol.config.opening_fee_bps,
+            params.collateral,
+            params.leverage_bps,
         )?;
-
-        let total_required = min_collateral
-            .checked_add(opening_fee)
-            .ok_or(PerpetualsError::MathOverflow)?;
-
-        require!(
-            params.collateral >= total_required,
-            PerpetualsError::InsufficientCollateral
-        );
     } else {
         require!(
-            ctx.accounts.baskt.is_trading() || ctx.accounts.baskt.is_unwinding(),
+            ctx.accounts.baskt.allows_exits(),
             PerpetualsError::InvalidBasktState
         );
-        // Close orders still require a target position
+        // Close orders must target an open position of the caller on this baskt
+        let position = validate_target_position(ctx.accounts)?;
+        target_is_long = Some(position.is_long);
+        let size_as_contracts = params.size_as_contracts.ok_or(PerpetualsError::InvalidInput)?;
         require!(
-            params.target_position.is_some(),
-            PerpetualsError::InvalidTargetPosition
+            size_as_contracts <= position.size,
+            PerpetualsError::OrderSizeExceedsPosition
         );
-
-        // TODO nshmadhani / siddu: HERE
-        // Check if position size is >= than order size
     }
 
     let order = &mut ctx.accounts.order;
@@ -217,6 +247,15 @@ pub fn create_order(
             order.init_close(CloseOrderParams {
                 size_as_contracts: params.size_as_contracts.unwrap(), 
                 target_position,
+            }, params.reduce_only)?;
+        }
+        OrderAction::Increase => {
+            let target_position = params.target_position.ok_or(PerpetualsError::InvalidTargetPosition)?;
+            order.init_increase(IncreaseOrderParams {
+                notional_value: params.notional_value,
+                leverage_bps: params.leverage_bps,
+                collateral: params.collateral,
+                target_position,
             })?;
         }
     }
@@ -224,18 +263,34 @@ pub fn create_order(
     // Step 3: Initialize order type-specific parameters
     match params.order_type {
         OrderType::Market => {
-            order.init_market()?;
+            order.init_market(SlippageGuard {
+                reference_price: params.limit_price,
+                max_slippage_bps: params.max_slippage_bps,
+            })?;
         }
         OrderType::Limit => {
-            order.init_limit(LimitOrderParams {
-                limit_price: params.limit_price,
-                max_slippage_bps: params.max_slippage_bps,
+            order.init_limit(LimitOrderParams::new(params.limit_price))?;
+        }
+        OrderType::StopLoss | OrderType::TakeProfit => {
+            order.init_trigger(params.order_type, TriggerOrderParams {
+                trigger_price: params.trigger_price,
+                trigger_direction: params.trigger_direction,
             })?;
         }
+        OrderType::Twap => {
+            order.init_twap(params.tranche_count, params.tranche_interval_seconds)?;
+        }
+        OrderType::TrailingStop => {
+            let position_is_long = target_is_long.ok_or(PerpetualsError::InvalidOrderAction)?;
+            order.init_trailing_stop(params.trail_bps, params.trigger_price, position_is_long)?;
+        }
     }
 
-    // Transfer collateral to escrow if this is an open order
-    if params.action == OrderAction::Open {
+    // Step 4: Optional good-til-time expiry
+    order.init_expiry(params.expires_at)?;
+
+    // Transfer collateral to escrow if this is an open or increase order
+    if matches!(params.action, OrderAction::Open | OrderAction::Increase) {
         token::transfer(
             CpiContext::new(
                 ctx.accounts.token_program.to_account_info(),
@@ -245,7 +300,7 @@ pub fn create_order(
                         .accounts
                         .owner_collateral_escrow_account
                         .to_account_info(),
-                    authority: ctx.accounts.owner.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
                 },
             ),
             params.collateral,
@@ -265,9 +320,79 @@ pub fn create_order(
         limit_price: params.limit_price,
         max_slippage_bps: params.max_slippage_bps,
         order_type: params.order_type,
+        trigger_price: params.trigger_price,
+        trigger_direction: params.trigger_direction,
+        expires_at: params.expires_at,
         leverage_bps: params.leverage_bps,
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
+
+/// Checks that the target position is an open position of the order owner on this baskt
+fn validate_target_position<'a>(accounts: &'a CreateOrder) -> Result<&'a Position> {
+    let position = accounts
+        .target_position
+        .as_deref()
+        .ok_or(PerpetualsError::InvalidTargetPosition)?;
+
+    require_keys_eq!(position.owner, accounts.owner.key(), PerpetualsError::Unauthorized);
+    require_keys_eq!(position.baskt_id, accounts.baskt.key(), PerpetualsError::InvalidBaskt);
+    require!(
+        position.status == PositionStatus::Open,
+        PerpetualsError::PositionAlreadyClosed
+    );
+    Ok(position)
+}
+
+/// Checks that an open or increase order's collateral covers the minimum collateral ratio
+/// (or the leverage requirement, if higher) plus the opening fee on its notional
+pub(crate) fn validate_order_collateral(
+    baskt: &Baskt,
+    protocol: &Protocol,
+    notional_value: u64,
+    collateral: u64,
+    leverage_bps: u64,
+) -> Result<()> {
+    // Basic collateral check
+    require!(collateral > 0, PerpetualsError::InsufficientCollateral);
+
+    // Notional value must be > 0 and within configured bounds
+    require!(notional_value > 0, PerpetualsError::ZeroSizedPosition);
+
+    const MAX_REASONABLE_NOTIONAL: u64 = 1_000_000_000_000_000; // 1B USDC
+    require!(
+        notional_value <= MAX_REASONABLE_NOTIONAL,
+        PerpetualsError::MathOverflow
+    );
+
+    let min_collateral_ratio_bps = effective_u64(
+        baskt.config.get_min_collateral_ratio_bps(),
+        protocol.config.min_collateral_ratio_bps,
+    );
+
+    // Calculate effective collateral ratio: use the higher of min collateral ratio or leverage requirement
+    let leverage_ratio_bps = BPS_DIVISOR.checked_div(leverage_bps).ok_or(PerpetualsError::MathOverflow)?;
+    let effective_collateral_ratio_bps = std::cmp::max(min_collateral_ratio_bps, leverage_ratio_bps);
+
+    // Calculate required collateral using the effective ratio
+    let min_collateral = calc_min_collateral_from_notional(notional_value, effective_collateral_ratio_bps)?;
+
+    let opening_fee = calc_opening_fee_with_effective_rate(
+        notional_value,
+        baskt.config.get_opening_fee_bps(),
+        protocol.config.opening_fee_bps,
+    )?;
+
+    let total_required = min_collateral
+        .checked_add(opening_fee)
+        .ok_or(PerpetualsError::MathOverflow)?;
+
+    require!(
+        collateral >= total_required,
+        PerpetualsError::InsufficientCollateral
+    );
+
+    Ok(())
+}
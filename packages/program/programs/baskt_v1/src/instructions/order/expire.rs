@@ -0,0 +1,113 @@
+use crate::constants::{AUTHORITY_SEED, ORDER_SEED, PROTOCOL_SEED, USER_ESCROW_SEED};
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::{
+    order::{Order, OrderStatus},
+    protocol::Protocol,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+//----------------------------------------------------------------------------
+// INSTRUCTION HANDLERS: ORDER
+//----------------------------------------------------------------------------
+
+/**
+    1. Anyone can clean up an order once its expires_at has passed
+    2. Rent goes back to the order owner, not the caller
+    3. Escrowed collateral is refunded to the owner's token account
+*/
+
+#[derive(Accounts)]
+pub struct ExpireOrder<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.owner.as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+        constraint = order.status as u8 == OrderStatus::Pending as u8 @ PerpetualsError::OrderAlreadyProcessed,
+        close = order_owner // Close the order account and return rent to owner
+    )]
+    pub order: Account<'info, Order>,
+
+    /// CHECK: Order owner receiving the rent refund
+    #[account(
+        mut,
+        constraint = order_owner.key() == order.owner @ PerpetualsError::InvalidInput
+    )]
+    pub order_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = owner_collateral_account.owner == order.owner @ PerpetualsError::UnauthorizedTokenOwner,
+        constraint = owner_collateral_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint
+    )]
+    pub owner_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [USER_ESCROW_SEED, order.owner.as_ref()],
+        bump,
+        constraint = owner_collateral_escrow_account.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = owner_collateral_escrow_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = owner_collateral_escrow_account.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
+        constraint = owner_collateral_escrow_account.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority
+    )]
+    pub owner_collateral_escrow_account: Account<'info, TokenAccount>,
+
+    ///CHECK: PDA used for token authority. Needed to sign the transfer from escrow.
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn expire_order(ctx: Context<ExpireOrder>) -> Result<()> {
+    let order = &ctx.accounts.order;
+    let clock = Clock::get()?;
+
+    require!(order.is_expired(clock.unix_timestamp), PerpetualsError::OrderNotExpired);
+
+    // Only open and increase orders have collateral to return from escrow
+    let escrowed_collateral = order.escrowed_collateral();
+    if escrowed_collateral > 0 {
+        let signer_seeds = [AUTHORITY_SEED, &[ctx.bumps.program_authority]];
+        let signer = &[&signer_seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx
+                        .accounts
+                        .owner_collateral_escrow_account
+                        .to_account_info(),
+                    to: ctx.accounts.owner_collateral_account.to_account_info(),
+                    authority: ctx.accounts.program_authority.to_account_info(),
+                },
+                signer,
+            ),
+            escrowed_collateral,
+        )?;
+    }
+
+    emit!(OrderExpiredEvent {
+        owner: order.owner,
+        order_id: order.order_id as u64,
+        baskt_id: order.baskt_id,
+        expired_by: ctx.accounts.caller.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -3,3 +3,15 @@ pub use create_order::*;
 
 pub mod cancel;
 pub use cancel::*;
+
+pub mod expire;
+pub use expire::*;
+
+pub mod amend;
+pub use amend::*;
+
+pub mod order_group;
+pub use order_group::*;
+
+pub mod trailing_stop;
+pub use trailing_stop::*;
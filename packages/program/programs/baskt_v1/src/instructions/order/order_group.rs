@@ -0,0 +1,122 @@
+use crate::constants::ORDER_GROUP_SEED;
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::{
+    order::{Order, OrderAction, OrderStatus},
+    order_group::OrderGroup,
+};
+use crate::utils::close_account;
+use anchor_lang::prelude::*;
+
+//----------------------------------------------------------------------------
+// INSTRUCTION HANDLERS: ORDER GROUP
+//----------------------------------------------------------------------------
+
+/**
+    1. Link two or more pending close orders (e.g. a TP and an SL) of the owner
+    2. remaining_accounts: the member orders, writable, in group order
+    3. Filling one member in close_position cancels the others
+*/
+
+#[derive(Accounts)]
+#[instruction(group_id: u32)]
+pub struct CreateOrderGroup<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = OrderGroup::DISCRIMINATOR.len() + OrderGroup::INIT_SPACE,
+        seeds = [ORDER_GROUP_SEED, owner.key().as_ref(), &group_id.to_le_bytes()],
+        bump
+    )]
+    pub order_group: Account<'info, OrderGroup>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_order_group<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateOrderGroup<'info>>,
+    group_id: u32,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let group_key = ctx.accounts.order_group.key();
+
+    let mut members = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts.iter() {
+        require!(info.is_writable, PerpetualsError::InvalidOrderGroup);
+        let mut order = Account::<Order>::try_from(info)?;
+        require_keys_eq!(order.owner, owner, PerpetualsError::Unauthorized);
+        require!(
+            order.status == OrderStatus::Pending,
+            PerpetualsError::OrderAlreadyProcessed
+        );
+        require!(
+            order.action == OrderAction::Close,
+            PerpetualsError::InvalidOrderAction
+        );
+        require!(order.order_group.is_none(), PerpetualsError::InvalidOrderGroup);
+
+        order.order_group = Some(group_key);
+        order.exit(&crate::ID)?;
+        members.push(info.key());
+    }
+
+    let member_count = members.len() as u8;
+    ctx.accounts
+        .order_group
+        .initialize(owner, group_id, members, ctx.bumps.order_group)?;
+
+    emit!(OrderGroupCreatedEvent {
+        owner,
+        order_group: group_key,
+        group_id,
+        member_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Cancel the other members of `filled_order`'s group and close the group, refunding
+/// rent to `rent_receiver`. The siblings are the last `orders.len() - 1` entries of
+/// `remaining_accounts`, in group order. Members already cancelled or expired are skipped.
+pub fn cancel_order_group_siblings<'info>(
+    filled_order: &Account<'info, Order>,
+    order_group: Option<&Account<'info, OrderGroup>>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    rent_receiver: &AccountInfo<'info>,
+    timestamp: i64,
+) -> Result<()> {
+    let Some(group_key) = filled_order.order_group else {
+        return Ok(());
+    };
+    let group = order_group.ok_or(PerpetualsError::OrderGroupRequired)?;
+    require_keys_eq!(group.key(), group_key, PerpetualsError::InvalidOrderGroup);
+
+    let sibling_count = group.orders.len().saturating_sub(1);
+    require!(
+        remaining_accounts.len() >= sibling_count,
+        PerpetualsError::InvalidOrderGroup
+    );
+    let sibling_infos = &remaining_accounts[remaining_accounts.len() - sibling_count..];
+
+    for (member, info) in group.siblings(filled_order.key()).zip(sibling_infos) {
+        require_keys_eq!(info.key(), *member, PerpetualsError::InvalidOrderGroup);
+        if info.data_is_empty() {
+            continue;
+        }
+
+        let sibling = Account::<Order>::try_from(info)?;
+        emit!(OrderCancelledEvent {
+            owner: sibling.owner,
+            order_id: sibling.order_id as u64,
+            baskt_id: sibling.baskt_id,
+            timestamp,
+        });
+        close_account(info, rent_receiver)?;
+    }
+
+    close_account(&group.to_account_info(), rent_receiver)
+}
@@ -0,0 +1,57 @@
+use crate::constants::ORDER_SEED;
+use crate::error::PerpetualsError;
+use crate::events::*;
+use crate::state::{
+    baskt::Baskt,
+    order::{Order, OrderStatus, OrderType},
+};
+use crate::utils::load_baskt_oracle_nav;
+use anchor_lang::prelude::*;
+
+//----------------------------------------------------------------------------
+// INSTRUCTION HANDLERS: TRAILING STOP
+//----------------------------------------------------------------------------
+
+/// Permissionless crank moving a trailing stop's watermark to the oracle NAV
+#[derive(Accounts)]
+pub struct UpdateTrailingStop<'info> {
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.owner.as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+        constraint = order.status as u8 == OrderStatus::Pending as u8 @ PerpetualsError::OrderAlreadyProcessed,
+        constraint = order.order_type as u8 == OrderType::TrailingStop as u8 @ PerpetualsError::InvalidInput,
+    )]
+    pub order: Account<'info, Order>,
+
+    #[account(
+        constraint = baskt.key() == order.baskt_id @ PerpetualsError::InvalidBaskt,
+    )]
+    pub baskt: Account<'info, Baskt>,
+    // remaining_accounts: for each baskt asset, in order,
+    // the SyntheticAsset followed by its oracle source accounts
+}
+
+pub fn update_trailing_stop<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateTrailingStop<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let oracle_nav = load_baskt_oracle_nav(&ctx.accounts.baskt, ctx.remaining_accounts, &clock)?;
+    // A glitched source must not drag the watermark
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+
+    let order = &mut ctx.accounts.order;
+    if order.update_trailing_watermark(oracle_nav.nav)? {
+        let trailing = order.get_trailing_stop_params()?;
+        emit!(TrailingStopUpdatedEvent {
+            owner: order.owner,
+            order_id: order.order_id as u64,
+            baskt_id: order.baskt_id,
+            watermark: trailing.watermark,
+            stop_price: trailing.stop_price()?,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
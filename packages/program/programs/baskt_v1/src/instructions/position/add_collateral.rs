@@ -1,10 +1,11 @@
 use {
-    crate::constants::{AUTHORITY_SEED, ESCROW_SEED, POSITION_SEED, PROTOCOL_SEED},
+    crate::constants::{AUTHORITY_SEED, ESCROW_SEED, POSITION_SEED, PROTOCOL_SEED, TRADING_DELEGATE_SEED},
     crate::error::PerpetualsError,
     crate::events::*,
     crate::state::{
         position::{Position, PositionStatus, ProgramAuthority},
         protocol::Protocol,
+        trading_delegate::TradingDelegate,
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{self, Token, TokenAccount, Transfer},
@@ -19,8 +20,19 @@ pub struct AddCollateralParams {
 #[derive(Accounts)]
 #[instruction(params: AddCollateralParams)]
 pub struct AddCollateral<'info> {
+    /// Owner, or a trading delegate of the owner; funds the collateral
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    /// CHECK: Position owner; must be the authority unless trading_delegate authorizes it
+    pub owner: UncheckedAccount<'info>,
+
+    /// Required when the authority is not the owner
+    #[account(
+        seeds = [TRADING_DELEGATE_SEED, owner.key().as_ref(), authority.key().as_ref()],
+        bump = trading_delegate.bump,
+    )]
+    pub trading_delegate: Option<Account<'info, TradingDelegate>>,
 
     #[account(
         mut,
@@ -28,12 +40,14 @@ pub struct AddCollateral<'info> {
         bump = position.bump,
         constraint = position.owner == owner.key() @ PerpetualsError::Unauthorized,
         constraint = position.status as u8 == PositionStatus::Open as u8 @ PerpetualsError::PositionAlreadyClosed,
+        constraint = !position.is_cross_margined() @ PerpetualsError::PositionIsCrossMargined,
     )]
     pub position: Account<'info, Position>,
 
+    /// Token account funding the collateral, held by the authority
     #[account(
         mut,
-        constraint = owner_collateral_account.owner == owner.key() @ PerpetualsError::Unauthorized,
+        constraint = owner_collateral_account.owner == authority.key() @ PerpetualsError::Unauthorized,
         constraint = owner_collateral_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
         constraint = owner_collateral_account.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
         constraint = owner_collateral_account.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority
@@ -79,7 +93,17 @@ pub fn add_collateral(ctx: Context<AddCollateral>, params: AddCollateralParams)
     let position = &mut ctx.accounts.position;
     let clock = Clock::get()?;
 
-    // Transfer tokens from owner to escrow
+    // Adding collateral only reduces risk, so the delegate's notional cap does not apply
+    TradingDelegate::check_authority(
+        ctx.accounts.trading_delegate.as_deref(),
+        position.owner,
+        ctx.accounts.authority.key(),
+        position.baskt_id,
+        0,
+        clock.unix_timestamp,
+    )?;
+
+    // Transfer tokens from the authority to escrow
     token::transfer(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -89,7 +113,7 @@ pub fn add_collateral(ctx: Context<AddCollateral>, params: AddCollateralParams)
                     .accounts
                     .owner_collateral_escrow_account
                     .to_account_info(),
-                authority: ctx.accounts.owner.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
             },
         ),
         params.additional_collateral,
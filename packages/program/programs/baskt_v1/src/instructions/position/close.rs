@@ -1,20 +1,27 @@
 use {
     crate::constants::{
-        AUTHORITY_SEED, ESCROW_SEED, LIQUIDITY_POOL_SEED, ORDER_SEED, POOL_AUTHORITY_SEED,
+        ADL_RANKING_SEED, AUTHORITY_SEED, ESCROW_SEED, INSURANCE_FUND_SEED, LIQUIDITY_POOL_SEED, NAV_HISTORY_SEED, ORDER_SEED, POOL_AUTHORITY_SEED,
         POSITION_SEED, PROTOCOL_SEED,
     },
     crate::error::PerpetualsError,
     crate::events::*,
+    crate::instructions::order::cancel_order_group_siblings,
     crate::state::{
+        adl_ranking::AdlRanking,
         baskt::Baskt,
+        insurance_fund::InsuranceFund,
         liquidity::LiquidityPool,
+        margin_account::MarginAccount,
+        nav_history::NavHistory,
         order::{Order, OrderAction, OrderStatus},
+        order_group::OrderGroup,
         position::{Position, PositionStatus, ProgramAuthority},
         protocol::{Protocol, Role},
     },
     crate::utils::{
-        effective_u64, execute_settlement_transfers, update_pool_state,
+        effective_u64, execute_insurance_transfers, execute_settlement_transfers, update_pool_state,
         ClosingType, TransferParams, close_account, close_escrow_account, calculate_position_settlement, update_position_after_settlement,
+        check_circuit_breaker, verify_baskt_price, MarginCollateral,
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{self, Token, TokenAccount},
@@ -63,7 +70,7 @@ pub struct ClosePosition<'info> {
     #[account(
         mut,
         constraint = baskt.key() == position.baskt_id @ PerpetualsError::InvalidBaskt,
-        constraint = baskt.is_trading() || baskt.is_unwinding() @ PerpetualsError::InvalidBasktState
+        constraint = baskt.allows_exits() @ PerpetualsError::InvalidBasktState
     )]
     pub baskt: Box<Account<'info, Baskt>>,
 
@@ -129,6 +136,39 @@ pub struct ClosePosition<'info> {
     )]
     pub usdc_vault: Account<'info, TokenAccount>,
 
+    /// Insurance fund absorbing bad debt ahead of the liquidity pool
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    /// Token vault holding the insurance fund balance
+    #[account(
+        mut,
+        constraint = insurance_vault.key() == insurance_fund.vault @ PerpetualsError::InvalidInsuranceVault,
+        constraint = insurance_vault.key() != usdc_vault.key() @ PerpetualsError::InvalidInput
+    )]
+    pub insurance_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: ADL ranking of the baskt, which may not exist yet; the position is dropped
+    /// from it once closed
+    #[account(
+        mut,
+        seeds = [ADL_RANKING_SEED, baskt.key().as_ref()],
+        bump
+    )]
+    pub adl_ranking: UncheckedAccount<'info>,
+
+    /// Margin account of a cross-margined position, whose shared collateral backs the close
+    #[account(mut)]
+    pub margin_account: Option<Box<Account<'info, MarginAccount>>>,
+
+    /// Token account holding the margin account's shared collateral
+    #[account(mut)]
+    pub margin_escrow: Option<Box<Account<'info, TokenAccount>>>,
+
     /// PDA used for token authority over escrow - still needed for CPI signing
     #[account(
         seeds = [AUTHORITY_SEED],
@@ -143,7 +183,20 @@ pub struct ClosePosition<'info> {
     )]
     pub pool_authority: UncheckedAccount<'info>,
 
+    /// One-cancels-other group of the order, required when the order is grouped
+    #[account(mut)]
+    pub order_group: Option<Account<'info, OrderGroup>>,
+
+    /// NAV history, required when the baskt has a circuit breaker configured
+    #[account(
+        seeds = [NAV_HISTORY_SEED, baskt.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Account<'info, NavHistory>>,
+
     pub token_program: Program<'info, Token>,
+    // remaining_accounts: the baskt oracle accounts (see load_baskt_oracle_prices),
+    // then, for grouped orders, the other group members in group order
 }
 
 pub fn close_position<'info>(
@@ -157,17 +210,35 @@ pub fn close_position<'info>(
 
 
     let close_params = order.get_close_params()?;
+    require!(!order.is_expired(clock.unix_timestamp), PerpetualsError::OrderExpired);
 
-    let size_to_close = params.size_to_close.unwrap_or(close_params.size_as_contracts);
-
-    require!(size_to_close > 0, PerpetualsError::InvalidPositionSize);
-    require!(size_to_close <= position.size, PerpetualsError::InvalidPositionSize);
+    let size_to_close = order.close_fill_size(params.size_to_close, position.size)?;
 
     
-    order.validate_execution_price(params.exit_price)?;
+    order.validate_execution_price(params.exit_price, position.is_long)?;
+
+    // Verify the exit price against the NAV implied by the aggregated oracle prices
+    let max_price_deviation_bps = effective_u64(
+        ctx.accounts.baskt.risk_config.get_max_price_deviation_bps(),
+        ctx.accounts.protocol.config.max_price_deviation_bps,
+    );
+    let oracle_nav = verify_baskt_price(
+        &ctx.accounts.baskt,
+        ctx.remaining_accounts,
+        params.exit_price,
+        max_price_deviation_bps,
+    )?;
+    // Never close on prices the oracle sources disagree about
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+    check_circuit_breaker(
+        &ctx.accounts.baskt,
+        ctx.accounts.nav_history.as_deref(),
+        clock.unix_timestamp,
+        oracle_nav.nav,
+    )?;
 
     // Validate target position
-    let target_pos_key = order.get_close_params()?.target_position;
+    let target_pos_key = close_params.target_position;
 
     require_keys_eq!(
         target_pos_key,
@@ -193,6 +264,28 @@ pub fn close_position<'info>(
         ctx.accounts.protocol.config.closing_fee_bps,
     );
 
+    // A cross-margined position settles against the shared collateral of its account,
+    // and its payout returns there
+    let mut margin = MarginCollateral::for_position(
+        position,
+        ctx.accounts.margin_account.as_deref_mut(),
+        ctx.accounts.margin_escrow.as_deref(),
+    )?;
+    if let Some(margin) = margin.as_mut() {
+        margin.stage(
+            &ctx.accounts.token_program,
+            position,
+            &ctx.accounts.owner_collateral_escrow_account,
+            &ctx.accounts.program_authority.to_account_info(),
+            ctx.bumps.program_authority,
+        )?;
+        ctx.accounts.owner_collateral_escrow_account.reload()?;
+    }
+    let payout_account = match &margin {
+        Some(margin) => margin.margin_escrow.to_account_info(),
+        None => ctx.accounts.owner_collateral_account.to_account_info(),
+    };
+
     // Calculate settlement details using shared utility
     let settlement_details = calculate_position_settlement(
         position,
@@ -200,13 +293,14 @@ pub fn close_position<'info>(
         params.exit_price,
         ClosingType::Normal { closing_fee_bps },
         ctx.accounts.protocol.config.treasury_cut_bps,
+        ctx.accounts.protocol.config.insurance_cut_bps,
         rebalance_fee_owed,
     )?;
 
     execute_settlement_transfers(
         &ctx.accounts.token_program,
         &ctx.accounts.owner_collateral_escrow_account,
-        &ctx.accounts.owner_collateral_account.to_account_info(),
+        &payout_account,
         Some(&ctx.accounts.treasury_token.to_account_info()),
         &ctx.accounts.usdc_vault.to_account_info(),
         &ctx.accounts.program_authority.to_account_info(),
@@ -227,12 +321,43 @@ pub fn close_position<'info>(
         &settlement_details,
     )?;
 
+    // Route the insurance cut into the fund and let it absorb bad debt before the pool does
+    let insurance_settlement = ctx.accounts.insurance_fund.settle(
+        settlement_details.fee_to_insurance,
+        settlement_details.pool_shortfall,
+        ctx.accounts.insurance_vault.amount,
+    )?;
+    execute_insurance_transfers(
+        &ctx.accounts.token_program,
+        &ctx.accounts.owner_collateral_escrow_account,
+        &ctx.accounts.insurance_vault,
+        &ctx.accounts.usdc_vault.to_account_info(),
+        &ctx.accounts.program_authority.to_account_info(),
+        ctx.bumps.program_authority,
+        &insurance_settlement,
+    )?;
+    if insurance_settlement.bad_debt_covered > 0 {
+        ctx.accounts
+            .liquidity_pool
+            .increase_liquidity(insurance_settlement.bad_debt_covered)?;
+    }
+
     // Update position state after settlement
     update_position_after_settlement(
         position,
         size_to_close,
         settlement_details.collateral_to_release,
     )?;
+    if let Some(margin) = margin.as_mut() {
+        margin.unstage(
+            &ctx.accounts.token_program,
+            position,
+            &ctx.accounts.owner_collateral_escrow_account,
+            &ctx.accounts.program_authority.to_account_info(),
+            ctx.bumps.program_authority,
+            settlement_details.user_payout_u64,
+        )?;
+    }
 
     emit!(PositionClosedEvent {
         order_id: order.order_id as u64,
@@ -258,9 +383,15 @@ pub fn close_position<'info>(
         base_fee: settlement_details.base_fee,
         rebalance_fee: settlement_details.rebalance_fee,
         bad_debt_amount: settlement_details.bad_debt_amount,
+        fee_to_insurance: insurance_settlement.fee_received,
+        bad_debt_covered: insurance_settlement.bad_debt_covered,
         collateral_released: settlement_details.collateral_to_release,
     });
 
+    // Track open interest and refresh the mark price for the new skew
+    ctx.accounts.baskt.remove_open_interest(position.is_long, size_to_close);
+    ctx.accounts.baskt.update_prices(oracle_nav.nav, clock.unix_timestamp)?;
+
     // Handle instruction-specific logic (account closing, event emission)
     if is_full_close {
         // Decrement open positions count
@@ -280,10 +411,21 @@ pub fn close_position<'info>(
             ctx.bumps.program_authority,
         )?;
 
+        AdlRanking::remove_from_account(&ctx.accounts.adl_ranking, &ctx.accounts.position.key())?;
+
         // Close position account manually since we removed the close attribute
         close_account(&ctx.accounts.position.to_account_info(), &ctx.accounts.matcher.to_account_info())?;
   
     }
+
+    // Filling one member of a one-cancels-other group cancels the rest
+    cancel_order_group_siblings(
+        &ctx.accounts.order,
+        ctx.accounts.order_group.as_ref(),
+        ctx.remaining_accounts,
+        &ctx.accounts.order_owner.to_account_info(),
+        clock.unix_timestamp,
+    )?;
  
 
     Ok(())
@@ -1,19 +1,22 @@
 use crate::constants::{
-    AUTHORITY_SEED, BASKT_SEED, ESCROW_SEED, LIQUIDITY_POOL_SEED, POOL_AUTHORITY_SEED,
+    ADL_RANKING_SEED, AUTHORITY_SEED, BASKT_SEED, ESCROW_SEED, INSURANCE_FUND_SEED, LIQUIDITY_POOL_SEED, POOL_AUTHORITY_SEED,
     POSITION_SEED, PROTOCOL_SEED,
 };
 use crate::error::PerpetualsError;
 use crate::events::*;
+use crate::state::adl_ranking::AdlRanking;
 use crate::state::baskt::{Baskt, BasktStatus};
+use crate::state::insurance_fund::InsuranceFund;
 use crate::state::liquidity::LiquidityPool;
+use crate::state::margin_account::MarginAccount;
 use crate::state::position::{Position, PositionStatus, ProgramAuthority};
 use crate::state::protocol::{Protocol, Role};
 use crate::utils::{
     position_utils::{
-        close_escrow_account, execute_settlement_transfers,
+        close_escrow_account, execute_insurance_transfers, execute_settlement_transfers,
         update_pool_state, calculate_position_settlement, update_position_after_settlement, ClosingType, TransferParams,
     },
-    effective_u64, close_account,
+    effective_u64, close_account, verify_baskt_price, MarginCollateral,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
@@ -82,6 +85,39 @@ pub struct ForceClosePosition<'info> {
     )]
     pub usdc_vault: Account<'info, TokenAccount>,
 
+    /// Insurance fund absorbing bad debt ahead of the liquidity pool
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    /// Token vault holding the insurance fund balance
+    #[account(
+        mut,
+        constraint = insurance_vault.key() == insurance_fund.vault @ PerpetualsError::InvalidInsuranceVault,
+        constraint = insurance_vault.key() != usdc_vault.key() @ PerpetualsError::InvalidInput
+    )]
+    pub insurance_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: ADL ranking of the baskt, which may not exist yet; the position is dropped
+    /// from it once closed
+    #[account(
+        mut,
+        seeds = [ADL_RANKING_SEED, baskt.key().as_ref()],
+        bump
+    )]
+    pub adl_ranking: UncheckedAccount<'info>,
+
+    /// Margin account of a cross-margined position, whose shared collateral backs the close
+    #[account(mut)]
+    pub margin_account: Option<Box<Account<'info, MarginAccount>>>,
+
+    /// Token account holding the margin account's shared collateral
+    #[account(mut)]
+    pub margin_escrow: Option<Box<Account<'info, TokenAccount>>>,
+
     /// Protocol treasury token account for fee collection
     #[account(
         mut,
@@ -135,6 +171,20 @@ pub fn force_close_position<'info>(
 
     require!(settlement_price > 0, PerpetualsError::InvalidOraclePrice);
 
+    // Verify the close price against the NAV implied by the aggregated oracle prices
+    let max_price_deviation_bps = effective_u64(
+        baskt.risk_config.get_max_price_deviation_bps(),
+        ctx.accounts.protocol.config.max_price_deviation_bps,
+    );
+    let oracle_nav = verify_baskt_price(
+        baskt,
+        ctx.remaining_accounts,
+        settlement_price,
+        max_price_deviation_bps,
+    )?;
+    // Never force an exit on prices the oracle sources disagree about
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+
     // Update both funding and borrow indices to settlement values
     position.update_market_indices(
         settlement_funding_index,
@@ -161,6 +211,28 @@ pub fn force_close_position<'info>(
         ctx.accounts.protocol.config.closing_fee_bps,
     );
 
+    // A cross-margined position settles against the shared collateral of its account,
+    // and its payout returns there
+    let mut margin = MarginCollateral::for_position(
+        position,
+        ctx.accounts.margin_account.as_deref_mut(),
+        ctx.accounts.margin_escrow.as_deref(),
+    )?;
+    if let Some(margin) = margin.as_mut() {
+        margin.stage(
+            &ctx.accounts.token_program,
+            position,
+            &ctx.accounts.owner_collateral_escrow_account,
+            &ctx.accounts.program_authority.to_account_info(),
+            ctx.bumps.program_authority,
+        )?;
+        ctx.accounts.owner_collateral_escrow_account.reload()?;
+    }
+    let payout_account = match &margin {
+        Some(margin) => margin.margin_escrow.to_account_info(),
+        None => ctx.accounts.owner_collateral_account.to_account_info(),
+    };
+
     // Calculate settlement details using shared utility
     let settlement_details = calculate_position_settlement(
         position,
@@ -168,6 +240,7 @@ pub fn force_close_position<'info>(
         params.close_price,
         ClosingType::ForceClose { closing_fee_bps },
         ctx.accounts.protocol.config.treasury_cut_bps,
+        ctx.accounts.protocol.config.insurance_cut_bps,
         rebalance_fee_owed,
     )?;
 
@@ -178,7 +251,7 @@ pub fn force_close_position<'info>(
     let transfer_result = execute_settlement_transfers(
         &ctx.accounts.token_program,
         &ctx.accounts.owner_collateral_escrow_account,
-        &ctx.accounts.owner_collateral_account.to_account_info(),
+        &payout_account,
         Some(&ctx.accounts.treasury_token.to_account_info()), // Include treasury for force close with fees
         &ctx.accounts.usdc_vault.to_account_info(),
         &ctx.accounts.program_authority.to_account_info(),
@@ -199,12 +272,43 @@ pub fn force_close_position<'info>(
         &settlement_details,
     )?;
 
+    // Route the insurance cut into the fund and let it absorb bad debt before the pool does
+    let insurance_settlement = ctx.accounts.insurance_fund.settle(
+        settlement_details.fee_to_insurance,
+        settlement_details.pool_shortfall,
+        ctx.accounts.insurance_vault.amount,
+    )?;
+    execute_insurance_transfers(
+        &ctx.accounts.token_program,
+        &ctx.accounts.owner_collateral_escrow_account,
+        &ctx.accounts.insurance_vault,
+        &ctx.accounts.usdc_vault.to_account_info(),
+        &ctx.accounts.program_authority.to_account_info(),
+        ctx.bumps.program_authority,
+        &insurance_settlement,
+    )?;
+    if insurance_settlement.bad_debt_covered > 0 {
+        ctx.accounts
+            .liquidity_pool
+            .increase_liquidity(insurance_settlement.bad_debt_covered)?;
+    }
+
     // Update position state after settlement
     update_position_after_settlement(
         position,
         size_to_close,
         settlement_details.collateral_to_release,
     )?;
+    if let Some(margin) = margin.as_mut() {
+        margin.unstage(
+            &ctx.accounts.token_program,
+            position,
+            &ctx.accounts.owner_collateral_escrow_account,
+            &ctx.accounts.program_authority.to_account_info(),
+            ctx.bumps.program_authority,
+            settlement_details.user_payout_u64,
+        )?;
+    }
 
 
     // Emit force close event
@@ -231,9 +335,15 @@ pub fn force_close_position<'info>(
         base_fee: settlement_details.base_fee,
         rebalance_fee: settlement_details.rebalance_fee,
         bad_debt_amount: settlement_details.bad_debt_amount,
+        fee_to_insurance: insurance_settlement.fee_received,
+        bad_debt_covered: insurance_settlement.bad_debt_covered,
         collateral_released: settlement_details.collateral_to_release,
     });
 
+    // Track open interest and refresh the mark price for the new skew
+    baskt.remove_open_interest(position.is_long, size_to_close);
+    baskt.update_prices(oracle_nav.nav, clock.unix_timestamp)?;
+
     if is_full_close {
         baskt.open_positions = baskt
             .open_positions
@@ -249,6 +359,8 @@ pub fn force_close_position<'info>(
             ctx.bumps.program_authority,
         )?;
 
+        AdlRanking::remove_from_account(&ctx.accounts.adl_ranking, &position.key())?;
+
         // Close position account manually since we removed the close attribute
         close_account(&position.to_account_info(), &ctx.accounts.authority.to_account_info())?;
     }
@@ -0,0 +1,315 @@
+use {
+    crate::constants::{
+        AUTHORITY_SEED, BPS_DIVISOR, ESCROW_SEED, LIQUIDITY_POOL_SEED, NAV_HISTORY_SEED, ORDER_SEED, POSITION_SEED,
+        PRICE_PRECISION, PROTOCOL_SEED, USER_ESCROW_SEED,
+    },
+    crate::error::PerpetualsError,
+    crate::events::*,
+    crate::math::mul_div_u64,
+    crate::state::{
+        baskt::Baskt,
+        liquidity::LiquidityPool,
+        nav_history::NavHistory,
+        order::{Order, OrderAction, OrderStatus},
+        position::{Position, PositionStatus, ProgramAuthority},
+        protocol::{Protocol, Role},
+    },
+    crate::utils::{
+        calc_min_collateral_from_notional, calc_opening_fee_with_effective_rate, effective_u64,
+        split_fee, check_circuit_breaker, verify_baskt_price,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{self, Token, TokenAccount, Transfer},
+};
+
+/// Parameters for increasing a position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct IncreasePositionParams {
+    pub increase_price: u64,
+}
+
+/// IncreasePosition
+#[derive(Accounts)]
+pub struct IncreasePosition<'info> {
+    #[account(mut)]
+    pub matcher: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.owner.as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+        constraint = order.status as u8 == OrderStatus::Pending as u8 @ PerpetualsError::OrderAlreadyProcessed,
+        constraint = order.action as u8 == OrderAction::Increase as u8 @ PerpetualsError::InvalidOrderAction,
+        close = order_owner
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    /// CHECK: Order owner for return rent to owner
+    #[account(
+        mut,
+        constraint = order_owner.key() == order.owner @ PerpetualsError::InvalidInput
+    )]
+    pub order_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, position.owner.as_ref(), &position.position_id.to_le_bytes()],
+        bump = position.bump,
+        constraint = position.owner == order.owner @ PerpetualsError::Unauthorized,
+        constraint = position.status as u8 == PositionStatus::Open as u8 @ PerpetualsError::PositionAlreadyClosed,
+        constraint = !position.is_cross_margined() @ PerpetualsError::PositionIsCrossMargined,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        mut,
+        constraint = baskt.key() == position.baskt_id @ PerpetualsError::InvalidBaskt,
+        constraint = baskt.key() == order.baskt_id @ PerpetualsError::InvalidBaskt,
+        constraint = baskt.is_trading() @ PerpetualsError::BasktNotActive
+    )]
+    pub baskt: Box<Account<'info, Baskt>>,
+
+    /// Protocol account for checking permissions
+    /// @dev Requires Matcher role to increase positions
+    #[account(
+        constraint = protocol.feature_flags.allow_open_position && protocol.feature_flags.allow_trading @ PerpetualsError::PositionOperationsDisabled,
+        constraint = protocol.has_permission(matcher.key(), Role::Matcher) @ PerpetualsError::Unauthorized,
+        seeds = [PROTOCOL_SEED],
+        bump
+    )]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_ESCROW_SEED, order.owner.as_ref()],
+        bump,
+        constraint = order_escrow.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = order_escrow.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint
+    )]
+    pub order_escrow: Box<Account<'info, TokenAccount>>,
+
+    /// Position escrow token account
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, position.key().as_ref()],
+        bump,
+        constraint = owner_collateral_escrow_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = owner_collateral_escrow_account.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+    )]
+    pub owner_collateral_escrow_account: Box<Account<'info, TokenAccount>>,
+
+    /// PDA used for token authority over escrow
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Box<Account<'info, ProgramAuthority>>,
+
+    /// Protocol treasury token account for fee collection
+    #[account(
+        mut,
+        constraint = treasury_token.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = treasury_token.owner == protocol.treasury @ PerpetualsError::InvalidTreasuryAccount,
+        constraint = treasury_token.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
+        constraint = treasury_token.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority
+    )]
+    pub treasury_token: Box<Account<'info, TokenAccount>>,
+
+    /// BLP token vault for liquidity pool fees
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == liquidity_pool.usdc_vault @ PerpetualsError::InvalidUsdcVault,
+        constraint = usdc_vault.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = treasury_token.key() != usdc_vault.key() @ PerpetualsError::InvalidInput
+    )]
+    pub usdc_vault: Box<Account<'info, TokenAccount>>,
+
+    /// NAV history, required when the baskt has a circuit breaker configured
+    #[account(
+        seeds = [NAV_HISTORY_SEED, baskt.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Account<'info, NavHistory>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn increase_position<'info>(
+    ctx: Context<'_, '_, 'info, 'info, IncreasePosition<'info>>,
+    params: IncreasePositionParams,
+) -> Result<()> {
+    let order = &ctx.accounts.order;
+    let position = &mut ctx.accounts.position;
+    let clock = Clock::get()?;
+
+    let increase_params = order.get_increase_params()?;
+    require!(!order.is_expired(clock.unix_timestamp), PerpetualsError::OrderExpired);
+    require_keys_eq!(
+        increase_params.target_position,
+        position.key(),
+        PerpetualsError::InvalidTargetPosition
+    );
+
+    // 1. Validate execution price against the order's limit or slippage guard
+    order.validate_execution_price(params.increase_price, position.is_long)?;
+
+    // 2. Verify the price against the NAV implied by the aggregated oracle prices
+    let max_price_deviation_bps = effective_u64(
+        ctx.accounts.baskt.risk_config.get_max_price_deviation_bps(),
+        ctx.accounts.protocol.config.max_price_deviation_bps,
+    );
+    let oracle_nav = verify_baskt_price(
+        &ctx.accounts.baskt,
+        ctx.remaining_accounts,
+        params.increase_price,
+        max_price_deviation_bps,
+    )?;
+    // Never increase on prices the oracle sources disagree about
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+    check_circuit_breaker(
+        &ctx.accounts.baskt,
+        ctx.accounts.nav_history.as_deref(),
+        clock.unix_timestamp,
+        oracle_nav.nav,
+    )?;
+
+    // 3. Settle funding, borrow and rebalance fees on the existing size before it changes
+    let market_indices = &ctx.accounts.baskt.market_indices;
+    position.update_market_indices(
+        market_indices.cumulative_funding_index,
+        market_indices.cumulative_borrow_index,
+        params.increase_price,
+    )?;
+    let rebalance_fee_owed = position.apply_rebalance_fee(
+        ctx.accounts.baskt.rebalance_fee_index.cumulative_index,
+        params.increase_price,
+    )?;
+
+    // 4. Fees on the added size are paid from the order collateral
+    let opening_fee = calc_opening_fee_with_effective_rate(
+        increase_params.notional_value,
+        ctx.accounts.baskt.config.get_opening_fee_bps(),
+        ctx.accounts.protocol.config.opening_fee_bps,
+    )?;
+    let total_fee = opening_fee
+        .checked_add(rebalance_fee_owed)
+        .ok_or(PerpetualsError::MathOverflow)?;
+    let net_collateral_amount = increase_params
+        .collateral
+        .checked_sub(total_fee)
+        .ok_or(PerpetualsError::InsufficientCollateral)?;
+
+    let additional_size = mul_div_u64(
+        increase_params.notional_value,
+        PRICE_PRECISION,
+        params.increase_price,
+    )?;
+    require!(additional_size > 0, PerpetualsError::ZeroSizedPosition);
+
+    position.increase(additional_size, net_collateral_amount, params.increase_price)?;
+
+    // 5. The increased position must meet the collateral requirement on its full notional
+    let effective_leverage_bps = mul_div_u64(BPS_DIVISOR, increase_params.leverage_bps, BPS_DIVISOR)?;
+    let effective_min_cr_bps = std::cmp::max(
+        effective_u64(
+            ctx.accounts.baskt.config.get_min_collateral_ratio_bps(),
+            ctx.accounts.protocol.config.min_collateral_ratio_bps,
+        ),
+        effective_leverage_bps,
+    );
+    let total_notional = mul_div_u64(position.size, params.increase_price, PRICE_PRECISION)?;
+    let min_collateral = calc_min_collateral_from_notional(total_notional, effective_min_cr_bps)?;
+    require!(
+        position.collateral >= min_collateral,
+        PerpetualsError::InsufficientCollateral
+    );
+
+    // Track open interest and refresh the mark price for the new skew
+    ctx.accounts.baskt.add_open_interest(position.is_long, additional_size)?;
+    ctx.accounts.baskt.update_prices(oracle_nav.nav, clock.unix_timestamp)?;
+
+    // Signer seeds for program authority
+    let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED, &[ctx.bumps.program_authority]];
+    let authority_signer: &[&[&[u8]]] = &[authority_seeds];
+
+    // Split and transfer fees between treasury and BLP
+    let (fee_to_treasury, fee_to_blp) = split_fee(total_fee, ctx.accounts.protocol.config.treasury_cut_bps)?;
+
+    if fee_to_treasury > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.order_escrow.to_account_info(),
+                    to: ctx.accounts.treasury_token.to_account_info(),
+                    authority: ctx.accounts.program_authority.to_account_info(),
+                },
+                authority_signer,
+            ),
+            fee_to_treasury,
+        )?;
+    }
+
+    if fee_to_blp > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.order_escrow.to_account_info(),
+                    to: ctx.accounts.usdc_vault.to_account_info(),
+                    authority: ctx.accounts.program_authority.to_account_info(),
+                },
+                authority_signer,
+            ),
+            fee_to_blp,
+        )?;
+
+        // Keep liquidity pool accounting in line with the vault balance
+        ctx.accounts.liquidity_pool.increase_liquidity(fee_to_blp)?;
+    }
+
+    // Transfer net collateral from order escrow to the position escrow
+    if net_collateral_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.order_escrow.to_account_info(),
+                    to: ctx
+                        .accounts
+                        .owner_collateral_escrow_account
+                        .to_account_info(),
+                    authority: ctx.accounts.program_authority.to_account_info(),
+                },
+                authority_signer,
+            ),
+            net_collateral_amount,
+        )?;
+    }
+
+    emit!(PositionIncreasedEvent {
+        order_id: order.order_id as u64,
+        owner: position.owner,
+        position_id: position.position_id as u64,
+        baskt_id: position.baskt_id,
+        size_added: additional_size,
+        new_size: position.size,
+        collateral_added: net_collateral_amount,
+        new_collateral: position.collateral,
+        increase_price: params.increase_price,
+        new_entry_price: position.entry_price,
+        rebalance_fee: rebalance_fee_owed,
+        fee_to_treasury,
+        fee_to_blp,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
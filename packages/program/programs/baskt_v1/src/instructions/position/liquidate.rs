@@ -1,19 +1,25 @@
 use {
     crate::constants::{
-        AUTHORITY_SEED, ESCROW_SEED, LIQUIDITY_POOL_SEED, POOL_AUTHORITY_SEED, POSITION_SEED,
-        PROTOCOL_SEED,
+        ADL_RANKING_SEED, AUTHORITY_SEED, ESCROW_SEED, INSURANCE_FUND_SEED, LIQUIDITY_POOL_SEED, NAV_HISTORY_SEED, POOL_AUTHORITY_SEED,
+        POSITION_SEED, PRICE_PRECISION, PROTOCOL_SEED,
     },
     crate::error::PerpetualsError,
     crate::events::*,
+    crate::math::mul_div_u64,
     crate::state::{
+        adl_ranking::AdlRanking,
         baskt::Baskt,
+        insurance_fund::InsuranceFund,
         liquidity::LiquidityPool,
+        nav_history::NavHistory,
         position::{Position, PositionStatus, ProgramAuthority},
         protocol::{Protocol, Role},
     },
     crate::utils::{
-        effective_u64, execute_settlement_transfers, update_pool_state,
+        calc_fee, calc_partial_liquidation_size, check_circuit_breaker, calculate_partial_liquidation_settlement,
+        effective_u64, execute_insurance_transfers, execute_settlement_transfers, update_pool_state,
         ClosingType, TransferParams, close_account, close_escrow_account, calculate_position_settlement, update_position_after_settlement,
+        validate_baskt_partial_liquidation_target, verify_baskt_price,
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{Token, TokenAccount},
@@ -23,7 +29,7 @@ use {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
 pub struct LiquidatePositionParams {
     pub exit_price: u64,
-    pub size_to_close: Option<u64>, // None = full liquidation, Some(size) = partial liquidation
+    pub size_to_close: Option<u64>, // None = full (or, in partial mode, program-sized) liquidation, Some(size) = partial liquidation
 }
 
 /// LiquidatePosition
@@ -37,14 +43,14 @@ pub struct LiquidatePosition<'info> {
         seeds = [POSITION_SEED, position.owner.as_ref(), &position.position_id.to_le_bytes()],
         bump = position.bump,
         constraint = position.status as u8 == PositionStatus::Open as u8 @ PerpetualsError::PositionAlreadyClosed,
-        close = liquidator
+        constraint = !position.is_cross_margined() @ PerpetualsError::PositionIsCrossMargined
     )]
     pub position: Account<'info, Position>,
 
     #[account(
         mut,
         constraint = baskt.key() == position.baskt_id @ PerpetualsError::InvalidBaskt,
-        constraint = baskt.is_trading() || baskt.is_unwinding() @ PerpetualsError::InvalidBasktState
+        constraint = baskt.allows_exits() @ PerpetualsError::InvalidBasktState
     )]
     pub baskt: Account<'info, Baskt>,
 
@@ -111,6 +117,31 @@ pub struct LiquidatePosition<'info> {
     )]
     pub usdc_vault: Account<'info, TokenAccount>,
 
+    /// Insurance fund absorbing bad debt ahead of the liquidity pool
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    /// Token vault holding the insurance fund balance
+    #[account(
+        mut,
+        constraint = insurance_vault.key() == insurance_fund.vault @ PerpetualsError::InvalidInsuranceVault,
+        constraint = insurance_vault.key() != usdc_vault.key() @ PerpetualsError::InvalidInput
+    )]
+    pub insurance_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: ADL ranking of the baskt, which may not exist yet; the position is dropped
+    /// from it once liquidated
+    #[account(
+        mut,
+        seeds = [ADL_RANKING_SEED, baskt.key().as_ref()],
+        bump
+    )]
+    pub adl_ranking: UncheckedAccount<'info>,
+
     /// PDA used for token authority over escrow
     #[account(
         seeds = [AUTHORITY_SEED],
@@ -125,6 +156,14 @@ pub struct LiquidatePosition<'info> {
     )]
     pub pool_authority: UncheckedAccount<'info>,
 
+    /// NAV history, required when the baskt has a liquidation TWAP window or a circuit
+    /// breaker configured
+    #[account(
+        seeds = [NAV_HISTORY_SEED, baskt.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Account<'info, NavHistory>>,
+
     pub token_program: Program<'info, Token>,
 }
 
@@ -138,6 +177,26 @@ pub fn liquidate_position<'info>(
 
     require!(params.exit_price > 0, PerpetualsError::InvalidOraclePrice);
 
+    // Verify the exit price against the NAV implied by the aggregated oracle prices
+    let max_price_deviation_bps = effective_u64(
+        ctx.accounts.baskt.risk_config.get_max_price_deviation_bps(),
+        ctx.accounts.protocol.config.max_price_deviation_bps,
+    );
+    let oracle_nav = verify_baskt_price(
+        &ctx.accounts.baskt,
+        ctx.remaining_accounts,
+        params.exit_price,
+        max_price_deviation_bps,
+    )?;
+    // Never liquidate on prices the oracle sources disagree about
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+    check_circuit_breaker(
+        &ctx.accounts.baskt,
+        ctx.accounts.nav_history.as_deref(),
+        clock.unix_timestamp,
+        oracle_nav.nav,
+    )?;
+
     // Update both funding and borrow indices for the full position first
     position.update_market_indices(
         market_indices.cumulative_funding_index,
@@ -148,61 +207,133 @@ pub fn liquidate_position<'info>(
     // Apply rebalance fee to position
     let rebalance_fee_owed = position.apply_rebalance_fee(ctx.accounts.baskt.rebalance_fee_index.cumulative_index, params.exit_price)?;
 
-    // Determine size to liquidate
-    let size_to_liquidate = params.size_to_close.unwrap_or(position.size);
-    require!(size_to_liquidate > 0, PerpetualsError::ZeroSizedPosition);
-    require!(
-        size_to_liquidate <= position.size,
-        PerpetualsError::InvalidPositionSize
-    );
-
-    let is_full_liquidation = size_to_liquidate == position.size;
-
-    // Determine size to liquidate
-    let size_to_liquidate = params.size_to_close.unwrap_or(position.size);
-    require!(size_to_liquidate > 0, PerpetualsError::ZeroSizedPosition);
-    require!(
-        size_to_liquidate <= position.size,
-        PerpetualsError::InvalidPositionSize
-    );
-
-    let is_full_liquidation = size_to_liquidate == position.size;
-
     // Get effective liquidation threshold from baskt config or protocol config
     let liquidation_threshold_bps = effective_u64(
         ctx.accounts.baskt.config.get_liquidation_threshold_bps(),
         ctx.accounts.protocol.config.liquidation_threshold_bps,
     );
 
-    // Check if position is liquidatable (using current price for the portion being liquidated)
+    // Check if position is liquidatable against the mark price derived from the oracle NAV;
+    // settlement below still happens at the index (exit) price
+    let mark_price = ctx.accounts.baskt.compute_mark_price(oracle_nav.nav)?;
     let is_liquidatable = position.is_liquidatable(
-        params.exit_price, 
+        mark_price,
         liquidation_threshold_bps,
         ctx.accounts.baskt.rebalance_fee_index.cumulative_index,
     )?;
     require!(is_liquidatable, PerpetualsError::PositionNotLiquidatable);
 
+    // Optionally require the TWAP to breach as well, so a single wick cannot liquidate
+    if let Some(window) = ctx.accounts.baskt.risk_config.get_liquidation_twap_window_seconds() {
+        let nav_history = ctx
+            .accounts
+            .nav_history
+            .as_ref()
+            .ok_or(PerpetualsError::NavHistoryRequired)?;
+        let twap = nav_history.twap(clock.unix_timestamp, window as i64)?;
+        let is_twap_liquidatable = position.is_liquidatable(
+            ctx.accounts.baskt.compute_mark_price(twap)?,
+            liquidation_threshold_bps,
+            ctx.accounts.baskt.rebalance_fee_index.cumulative_index,
+        )?;
+        require!(is_twap_liquidatable, PerpetualsError::PositionNotLiquidatable);
+    }
+
     // Get effective liquidation fee from baskt config or protocol config
     let liquidation_fee_bps = effective_u64(
         ctx.accounts.baskt.config.get_liquidation_fee_bps(),
         ctx.accounts.protocol.config.liquidation_fee_bps,
     );
 
-    // Calculate settlement details using shared utility
-    let settlement_details = calculate_position_settlement(
-        position,
-        size_to_liquidate,
-        params.exit_price,
-        ClosingType::Liquidation { liquidation_fee_bps },
-        ctx.accounts.protocol.config.treasury_cut_bps,
-        rebalance_fee_owed,
-    )?;
+    // Determine size to liquidate. In partial mode the program caps the cut at the size
+    // that restores the target margin, unless the position is below the hard threshold.
+    // Partial mode only applies while the target is valid for this baskt's effective
+    // thresholds; otherwise the position is liquidated in full.
+    let partial_target_bps = if validate_baskt_partial_liquidation_target(
+        &ctx.accounts.baskt.config,
+        &ctx.accounts.protocol.config,
+    )
+    .is_ok()
+    {
+        ctx.accounts.protocol.config.partial_liquidation_target_bps
+    } else {
+        0
+    };
+    let size_to_liquidate = if partial_target_bps > 0 {
+        let equity = position
+            .calculate_equity(mark_price, ctx.accounts.baskt.rebalance_fee_index.cumulative_index)?
+            .checked_sub(rebalance_fee_owed as i128)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        let notional = mul_div_u64(position.size, mark_price, PRICE_PRECISION)?;
+        let max_partial_size = calc_partial_liquidation_size(
+            position.size,
+            equity,
+            notional,
+            partial_target_bps,
+            liquidation_fee_bps,
+        )?;
+        // A cut of 0 would leave a liquidatable position in place, so fall back to full
+        let full_liquidation_allowed = max_partial_size == 0
+            || equity
+                < calc_fee(notional, ctx.accounts.protocol.config.full_liquidation_threshold_bps)? as i128;
+
+        match params.size_to_close {
+            Some(size) => {
+                require!(
+                    full_liquidation_allowed || size <= max_partial_size,
+                    PerpetualsError::PartialLiquidationTooLarge
+                );
+                size
+            }
+            None if full_liquidation_allowed => position.size,
+            None => max_partial_size,
+        }
+    } else {
+        params.size_to_close.unwrap_or(position.size)
+    };
+    require!(size_to_liquidate > 0, PerpetualsError::ZeroSizedPosition);
+    require!(
+        size_to_liquidate <= position.size,
+        PerpetualsError::InvalidPositionSize
+    );
+
+    let is_full_liquidation = size_to_liquidate == position.size;
+
+    // Partial cuts in partial mode settle against the whole collateral so the remaining
+    // position keeps what is left; everything else settles pro rata
+    let (settlement_details, remaining_collateral) = if partial_target_bps > 0 && !is_full_liquidation {
+        let settlement = calculate_partial_liquidation_settlement(
+            position,
+            size_to_liquidate,
+            params.exit_price,
+            liquidation_fee_bps,
+            ctx.accounts.protocol.config.treasury_cut_bps,
+            ctx.accounts.protocol.config.insurance_cut_bps,
+            rebalance_fee_owed,
+        )?;
+        (settlement.details, Some(settlement.remaining_collateral))
+    } else {
+        let details = calculate_position_settlement(
+            position,
+            size_to_liquidate,
+            params.exit_price,
+            ClosingType::Liquidation { liquidation_fee_bps },
+            ctx.accounts.protocol.config.treasury_cut_bps,
+            ctx.accounts.protocol.config.insurance_cut_bps,
+            rebalance_fee_owed,
+        )?;
+        (details, None)
+    };
 
     // Execute all settlement transfers
     let transfer_result = execute_settlement_transfers(
         &ctx.accounts.token_program,
         &ctx.accounts.owner_collateral_escrow_account,
-        &ctx.accounts.owner_collateral_account.to_account_info(),
+        // Partial-mode credits from the pool stay in escrow as collateral
+        &match remaining_collateral {
+            Some(_) => ctx.accounts.owner_collateral_escrow_account.to_account_info(),
+            None => ctx.accounts.owner_collateral_account.to_account_info(),
+        },
         Some(&ctx.accounts.treasury_token.to_account_info()),
         &ctx.accounts.usdc_vault.to_account_info(),
         &ctx.accounts.program_authority.to_account_info(),
@@ -223,12 +354,36 @@ pub fn liquidate_position<'info>(
         &settlement_details,
     )?;
 
+    // Route the insurance cut into the fund and let it absorb bad debt before the pool does
+    let insurance_settlement = ctx.accounts.insurance_fund.settle(
+        settlement_details.fee_to_insurance,
+        settlement_details.pool_shortfall,
+        ctx.accounts.insurance_vault.amount,
+    )?;
+    execute_insurance_transfers(
+        &ctx.accounts.token_program,
+        &ctx.accounts.owner_collateral_escrow_account,
+        &ctx.accounts.insurance_vault,
+        &ctx.accounts.usdc_vault.to_account_info(),
+        &ctx.accounts.program_authority.to_account_info(),
+        ctx.bumps.program_authority,
+        &insurance_settlement,
+    )?;
+    if insurance_settlement.bad_debt_covered > 0 {
+        ctx.accounts
+            .liquidity_pool
+            .increase_liquidity(insurance_settlement.bad_debt_covered)?;
+    }
+
     // Update position state after settlement
     update_position_after_settlement(
         position,
         size_to_liquidate,
         settlement_details.collateral_to_release,
     )?;
+    if let Some(remaining_collateral) = remaining_collateral {
+        position.collateral = remaining_collateral;
+    }
 
     // Emit liquidation event
     emit!(PositionLiquidatedEvent {
@@ -254,9 +409,18 @@ pub fn liquidate_position<'info>(
         base_fee: settlement_details.base_fee,
         rebalance_fee: settlement_details.rebalance_fee,
         bad_debt_amount: settlement_details.bad_debt_amount,
+        fee_to_insurance: insurance_settlement.fee_received,
+        bad_debt_covered: insurance_settlement.bad_debt_covered,
         collateral_released: settlement_details.collateral_to_release,
     });
 
+    // Track open interest and refresh the mark price for the new skew
+    ctx.accounts.baskt.remove_open_interest(position.is_long, size_to_liquidate);
+    ctx.accounts.baskt.update_prices(oracle_nav.nav, clock.unix_timestamp)?;
+
+    // A liquidated position is no longer an auto-deleveraging candidate
+    AdlRanking::remove_from_account(&ctx.accounts.adl_ranking, &position.key())?;
+
     // Handle instruction-specific logic (account closing)
     if is_full_liquidation {
         // Decrement open positions count
@@ -276,7 +440,7 @@ pub fn liquidate_position<'info>(
             ctx.bumps.program_authority,
         )?;
 
-        // Close position account manually since we removed the close attribute
+        // Close the position account only here; a partial liquidation leaves it open
         close_account(&position.to_account_info(), &ctx.accounts.liquidator.to_account_info())?;
     }
 
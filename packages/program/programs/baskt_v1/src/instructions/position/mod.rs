@@ -1,11 +1,15 @@
 pub mod add_collateral;
 pub mod close;
 pub mod force_close;
+pub mod increase;
 pub mod liquidate;
 pub mod open;
+pub mod remove_collateral;
 
 pub use add_collateral::*;
 pub use close::*;
 pub use force_close::*;
+pub use increase::*;
 pub use liquidate::*;
 pub use open::*;
+pub use remove_collateral::*;
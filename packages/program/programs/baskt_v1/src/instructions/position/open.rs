@@ -1,6 +1,6 @@
 use {
     crate::constants::{
-        AUTHORITY_SEED, BPS_DIVISOR, ESCROW_SEED, LIQUIDITY_POOL_SEED, ORDER_SEED, POSITION_SEED,
+        AUTHORITY_SEED, BPS_DIVISOR, ESCROW_SEED, LIQUIDITY_POOL_SEED, NAV_HISTORY_SEED, ORDER_SEED, POSITION_SEED,
         PRICE_PRECISION, PROTOCOL_SEED, USER_ESCROW_SEED,
     },
     crate::error::PerpetualsError,
@@ -9,13 +9,14 @@ use {
     crate::state::{
         baskt::Baskt,
         liquidity::LiquidityPool,
+        nav_history::NavHistory,
         order::{Order, OrderAction, OrderStatus, OrderType},
         position::{Position, ProgramAuthority},
         protocol::{Protocol, Role},
     },
     crate::utils::{
-        calc_min_collateral_from_notional, calc_opening_fee_with_effective_rate, effective_u64,
-        split_fee,
+        calc_min_collateral_from_notional, calc_opening_fee_with_effective_rate, close_account,
+        effective_u64, split_fee, check_circuit_breaker, verify_baskt_price,
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer},
@@ -41,7 +42,7 @@ pub struct OpenPosition<'info> {
         bump = order.bump,
         constraint = order.status as u8 == OrderStatus::Pending as u8 @ PerpetualsError::OrderAlreadyProcessed,
         constraint = order.action as u8 == OrderAction::Open as u8 @ PerpetualsError::InvalidOrderAction,
-        close = order_owner // Close the order account and return rent to matcher
+        // Closed to order_owner once fully filled; TWAP orders stay open between tranches
     )]
     pub order: Box<Account<'info, Order>>,
 
@@ -138,6 +139,13 @@ pub struct OpenPosition<'info> {
     )]
     pub usdc_vault: Box<Account<'info, TokenAccount>>,
 
+    /// NAV history, required when the baskt has a circuit breaker configured
+    #[account(
+        seeds = [NAV_HISTORY_SEED, baskt.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Account<'info, NavHistory>>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub rent: Sysvar<'info, Rent>,
@@ -156,10 +164,33 @@ pub fn open_position<'info>(
     
 
     let open_params = order.get_open_params()?;
+    require!(!order.is_expired(clock.unix_timestamp), PerpetualsError::OrderExpired);
 
-    // 1. Validate execution price is within order's slippage bounds
-    order.validate_execution_price(params.entry_price)?;
+    // Whole order, or the next tranche of a TWAP order
+    let (fill_notional, fill_collateral) = order.next_open_fill(clock.unix_timestamp)?;
 
+    // 1. Validate execution price against the order's limit or slippage guard
+    order.validate_execution_price(params.entry_price, open_params.is_long)?;
+
+    // 2. Verify the entry price against the NAV implied by the aggregated oracle prices
+    let max_price_deviation_bps = effective_u64(
+        ctx.accounts.baskt.risk_config.get_max_price_deviation_bps(),
+        ctx.accounts.protocol.config.max_price_deviation_bps,
+    );
+    let oracle_nav = verify_baskt_price(
+        &ctx.accounts.baskt,
+        ctx.remaining_accounts,
+        params.entry_price,
+        max_price_deviation_bps,
+    )?;
+    // Never open on prices the oracle sources disagree about
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+    check_circuit_breaker(
+        &ctx.accounts.baskt,
+        ctx.accounts.nav_history.as_deref(),
+        clock.unix_timestamp,
+        oracle_nav.nav,
+    )?;
 
 
     // ------------------------------------------------------------------
@@ -174,30 +205,29 @@ pub fn open_position<'info>(
 
 
     let opening_fee = calc_opening_fee_with_effective_rate(
-        open_params.notional_value,
+        fill_notional,
         ctx.accounts.baskt.config.get_opening_fee_bps(),
         ctx.accounts.protocol.config.opening_fee_bps,
     )?;
 
  
     let min_collateral_real =
-        calc_min_collateral_from_notional(open_params.notional_value, effective_min_cr_bps)?;
+        calc_min_collateral_from_notional(fill_notional, effective_min_cr_bps)?;
     let total_required_real = min_collateral_real
         .checked_add(opening_fee)
         .ok_or(PerpetualsError::MathOverflow)?;
 
     require!(
-        open_params.collateral >= total_required_real,
+        fill_collateral >= total_required_real,
         PerpetualsError::InsufficientCollateral
     );
 
     // 6. Calculate net collateral after fee (using real notional for fee calculation)
-    let net_collateral_amount = open_params
-        .collateral
+    let net_collateral_amount = fill_collateral
         .checked_sub(opening_fee)
         .ok_or(PerpetualsError::InsufficientCollateral)?;
 
-    let num_of_contracts = mul_div_u64(open_params.notional_value, PRICE_PRECISION, params.entry_price)?;
+    let num_of_contracts = mul_div_u64(fill_notional, PRICE_PRECISION, params.entry_price)?;
 
     require!(num_of_contracts > 0, PerpetualsError::ZeroSizedPosition);
     position.initialize(
@@ -223,6 +253,10 @@ pub fn open_position<'info>(
         .checked_add(1)
         .ok_or(PerpetualsError::MathOverflow)?;
 
+    // Track open interest and refresh the mark price for the new skew
+    ctx.accounts.baskt.add_open_interest(open_params.is_long, num_of_contracts)?;
+    ctx.accounts.baskt.update_prices(oracle_nav.nav, clock.unix_timestamp)?;
+
     // Signer seeds for program authority
     let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED, &[ctx.bumps.program_authority]];
     let authority_signer: &[&[&[u8]]] = &[authority_seeds];
@@ -307,5 +341,25 @@ pub fn open_position<'info>(
         timestamp: clock.unix_timestamp,
     });
 
+    // Close the order once nothing is left to fill, returning rent to its owner
+    let order = &mut ctx.accounts.order;
+    let order_complete = order.record_open_fill(fill_notional, fill_collateral, clock.unix_timestamp)?;
+    if order.order_type == OrderType::Twap {
+        let twap = order.get_twap_params()?;
+        emit!(TwapTrancheFilledEvent {
+            order_id: order.order_id as u64,
+            owner: order.owner,
+            position_id: params.position_id as u64,
+            tranches_filled: twap.tranches_filled,
+            tranche_count: twap.tranche_count,
+            notional_filled: twap.notional_filled,
+            collateral_used: twap.collateral_used,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    if order_complete {
+        close_account(&order.to_account_info(), &ctx.accounts.order_owner.to_account_info())?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,244 @@
+use {
+    crate::constants::{AUTHORITY_SEED, ESCROW_SEED, LIQUIDITY_POOL_SEED, NAV_HISTORY_SEED, POSITION_SEED, PROTOCOL_SEED},
+    crate::error::PerpetualsError,
+    crate::events::*,
+    crate::state::{
+        baskt::Baskt,
+        liquidity::LiquidityPool,
+        nav_history::NavHistory,
+        position::{Position, PositionStatus, ProgramAuthority},
+        protocol::Protocol,
+    },
+    crate::utils::{effective_u64, split_fee, check_circuit_breaker, verify_baskt_price},
+    anchor_lang::prelude::*,
+    anchor_spl::token::{self, Token, TokenAccount, Transfer},
+};
+
+/// Parameters for removing collateral from a position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RemoveCollateralParams {
+    pub amount: u64,
+    pub current_price: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RemoveCollateralParams)]
+pub struct RemoveCollateral<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, owner.key().as_ref(), &position.position_id.to_le_bytes()],
+        bump = position.bump,
+        constraint = position.owner == owner.key() @ PerpetualsError::Unauthorized,
+        constraint = position.status as u8 == PositionStatus::Open as u8 @ PerpetualsError::PositionAlreadyClosed,
+        constraint = !position.is_cross_margined() @ PerpetualsError::PositionIsCrossMargined,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(
+        constraint = baskt.key() == position.baskt_id @ PerpetualsError::InvalidBaskt,
+        constraint = baskt.is_trading() @ PerpetualsError::BasktNotActive
+    )]
+    pub baskt: Box<Account<'info, Baskt>>,
+
+    /// Token account receiving the withdrawn collateral
+    #[account(
+        mut,
+        constraint = owner_collateral_account.owner == owner.key() @ PerpetualsError::Unauthorized,
+        constraint = owner_collateral_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+    )]
+    pub owner_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, position.key().as_ref()],
+        bump,
+        constraint = owner_collateral_escrow_account.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = owner_collateral_escrow_account.owner == program_authority.key() @ PerpetualsError::InvalidProgramAuthority,
+        constraint = owner_collateral_escrow_account.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
+        constraint = owner_collateral_escrow_account.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority
+    )]
+    pub owner_collateral_escrow_account: Account<'info, TokenAccount>,
+
+    /// PDA used for token authority over escrow
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub program_authority: Account<'info, ProgramAuthority>,
+
+    /// Protocol account - required for validating the feature flag
+    #[account(
+        constraint = protocol.feature_flags.allow_collateral_withdrawal @ PerpetualsError::PositionOperationsDisabled,
+        seeds = [PROTOCOL_SEED],
+        bump
+    )]
+    pub protocol: Box<Account<'info, Protocol>>,
+
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+
+    /// Protocol treasury token account for rebalance fee collection
+    #[account(
+        mut,
+        constraint = treasury_token.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = treasury_token.owner == protocol.treasury @ PerpetualsError::InvalidTreasuryAccount,
+        constraint = treasury_token.delegate.is_none() @ PerpetualsError::TokenHasDelegate,
+        constraint = treasury_token.close_authority.is_none() @ PerpetualsError::TokenHasCloseAuthority
+    )]
+    pub treasury_token: Account<'info, TokenAccount>,
+
+    /// BLP token vault for liquidity pool fees
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == liquidity_pool.usdc_vault @ PerpetualsError::InvalidUsdcVault,
+        constraint = usdc_vault.mint == protocol.collateral_mint @ PerpetualsError::InvalidMint,
+        constraint = treasury_token.key() != usdc_vault.key() @ PerpetualsError::InvalidInput,
+        constraint = owner_collateral_account.key() != usdc_vault.key() @ PerpetualsError::InvalidInput
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// NAV history, required when the baskt has a circuit breaker configured
+    #[account(
+        seeds = [NAV_HISTORY_SEED, baskt.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Account<'info, NavHistory>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn remove_collateral<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RemoveCollateral<'info>>,
+    params: RemoveCollateralParams,
+) -> Result<()> {
+    require!(params.amount > 0, PerpetualsError::InsufficientCollateral);
+    require!(params.current_price > 0, PerpetualsError::InvalidOraclePrice);
+
+    let position = &mut ctx.accounts.position;
+    let clock = Clock::get()?;
+
+    // 1. Verify the price against the NAV implied by the aggregated oracle prices
+    let max_price_deviation_bps = effective_u64(
+        ctx.accounts.baskt.risk_config.get_max_price_deviation_bps(),
+        ctx.accounts.protocol.config.max_price_deviation_bps,
+    );
+    let oracle_nav = verify_baskt_price(
+        &ctx.accounts.baskt,
+        ctx.remaining_accounts,
+        params.current_price,
+        max_price_deviation_bps,
+    )?;
+    // Never release margin on prices the oracle sources disagree about
+    require!(!oracle_nav.disagreement, PerpetualsError::OracleSourcesDisagree);
+    check_circuit_breaker(
+        &ctx.accounts.baskt,
+        ctx.accounts.nav_history.as_deref(),
+        clock.unix_timestamp,
+        oracle_nav.nav,
+    )?;
+
+    // 2. Settle funding, borrow and rebalance fees so the margin check sees current equity
+    let market_indices = &ctx.accounts.baskt.market_indices;
+    position.update_market_indices(
+        market_indices.cumulative_funding_index,
+        market_indices.cumulative_borrow_index,
+        params.current_price,
+    )?;
+    let rebalance_fee_owed = position.apply_rebalance_fee(
+        ctx.accounts.baskt.rebalance_fee_index.cumulative_index,
+        params.current_price,
+    )?;
+    if rebalance_fee_owed > 0 {
+        position.remove_collateral(rebalance_fee_owed)?;
+    }
+
+    // 3. The remaining position must stay above the effective min collateral ratio
+    let min_collateral_ratio_bps = effective_u64(
+        ctx.accounts.baskt.config.get_min_collateral_ratio_bps(),
+        ctx.accounts.protocol.config.min_collateral_ratio_bps,
+    );
+    let withdrawable = position.withdrawable_collateral(
+        params.current_price,
+        ctx.accounts.baskt.rebalance_fee_index.cumulative_index,
+        min_collateral_ratio_bps,
+    )?;
+    require!(
+        params.amount <= withdrawable,
+        PerpetualsError::InsufficientCollateral
+    );
+    position.remove_collateral(params.amount)?;
+
+    // Signer seeds for program authority
+    let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED, &[ctx.bumps.program_authority]];
+    let authority_signer: &[&[&[u8]]] = &[authority_seeds];
+
+    // Split and transfer the settled rebalance fee between treasury and BLP
+    let (fee_to_treasury, fee_to_blp) =
+        split_fee(rebalance_fee_owed, ctx.accounts.protocol.config.treasury_cut_bps)?;
+
+    if fee_to_treasury > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner_collateral_escrow_account.to_account_info(),
+                    to: ctx.accounts.treasury_token.to_account_info(),
+                    authority: ctx.accounts.program_authority.to_account_info(),
+                },
+                authority_signer,
+            ),
+            fee_to_treasury,
+        )?;
+    }
+
+    if fee_to_blp > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner_collateral_escrow_account.to_account_info(),
+                    to: ctx.accounts.usdc_vault.to_account_info(),
+                    authority: ctx.accounts.program_authority.to_account_info(),
+                },
+                authority_signer,
+            ),
+            fee_to_blp,
+        )?;
+
+        // Keep liquidity pool accounting in line with the vault balance
+        ctx.accounts.liquidity_pool.increase_liquidity(fee_to_blp)?;
+    }
+
+    // Transfer the withdrawn collateral from escrow to the owner
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_collateral_escrow_account.to_account_info(),
+                to: ctx.accounts.owner_collateral_account.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            authority_signer,
+        ),
+        params.amount,
+    )?;
+
+    emit!(CollateralRemovedEvent {
+        owner: position.owner,
+        position_id: position.position_id as u64,
+        baskt_id: position.baskt_id,
+        removed_collateral: params.amount,
+        new_total_collateral: position.collateral,
+        current_price: params.current_price,
+        rebalance_fee: rebalance_fee_owed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use {
     crate::constants::{
-        BPS_DIVISOR, MAX_FEE_BPS, MAX_GRACE_PERIOD,
-        MAX_TREASURY_CUT_BPS, MIN_COLLATERAL_RATIO_BPS, MIN_GRACE_PERIOD,
+        BPS_DIVISOR, MAX_CONFIGURABLE_PRICE_DEVIATION_BPS, MAX_FEE_BPS, MAX_GRACE_PERIOD,
+        MAX_INSURANCE_CUT_BPS, MAX_TREASURY_CUT_BPS, MIN_COLLATERAL_RATIO_BPS, MIN_GRACE_PERIOD,
         PROTOCOL_SEED,
         LIQUIDITY_POOL_SEED,
     },
@@ -9,6 +9,7 @@ use {
     crate::events::*,
     crate::state::protocol::{Protocol, Role},
     crate::state::liquidity::LiquidityPool,
+    crate::utils::validate_partial_liquidation_target,
     anchor_lang::prelude::*,
 };
 
@@ -101,6 +102,32 @@ crate::impl_bps_setter!(
     new_liquidation_fee_bps
 );
 
+// ----------------------------------------------------------------------------
+// Set Max Price Deviation Bps Instruction
+// ----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct SetMaxPriceDeviationBps<'info> {
+    #[account(
+        mut,
+        constraint = protocol.has_permission(authority.key(), Role::ConfigManager)
+            @ PerpetualsError::UnauthorizedRole,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
+
+crate::impl_bps_setter!(
+    set_max_price_deviation_bps,
+    SetMaxPriceDeviationBps<'info>,
+    max_price_deviation_bps,
+    MAX_CONFIGURABLE_PRICE_DEVIATION_BPS,
+    old_max_price_deviation_bps,
+    new_max_price_deviation_bps
+);
+
 // ----------------------------------------------------------------------------
 // Set Min Collateral Ratio Bps Instruction
 // ----------------------------------------------------------------------------
@@ -130,6 +157,14 @@ pub fn set_min_collateral_ratio_bps(
                 > ctx.accounts.protocol.config.liquidation_threshold_bps,
         PerpetualsError::InvalidCollateralRatio
     );
+    // the partial-liquidation target must stay within the new bounds
+    let config = &ctx.accounts.protocol.config;
+    validate_partial_liquidation_target(
+        config.partial_liquidation_target_bps,
+        config.full_liquidation_threshold_bps,
+        config.liquidation_threshold_bps,
+        new_min_collateral_ratio_bps,
+    )?;
 
     let protocol = &mut ctx.accounts.protocol;
     let old_min_collateral_ratio_bps = protocol.config.min_collateral_ratio_bps;
@@ -180,6 +215,14 @@ pub fn set_liquidation_threshold_bps(
                 < ctx.accounts.protocol.config.min_collateral_ratio_bps,
         PerpetualsError::InvalidCollateralRatio
     );
+    // the partial-liquidation target must stay within the new bounds
+    let config = &ctx.accounts.protocol.config;
+    validate_partial_liquidation_target(
+        config.partial_liquidation_target_bps,
+        config.full_liquidation_threshold_bps,
+        new_liquidation_threshold_bps,
+        config.min_collateral_ratio_bps,
+    )?;
 
     let protocol = &mut ctx.accounts.protocol;
     let old_liquidation_threshold_bps = protocol.config.liquidation_threshold_bps;
@@ -201,6 +244,56 @@ pub fn set_liquidation_threshold_bps(
     Ok(())
 }
 
+// ----------------------------------------------------------------------------
+// Set Partial Liquidation Config Instruction
+// ----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct SetPartialLiquidationConfig<'info> {
+    /// Signer that must have the ConfigManager role
+    #[account(
+        mut,
+        constraint = protocol.has_permission(authority.key(), Role::ConfigManager) @ PerpetualsError::UnauthorizedRole,
+    )]
+    pub authority: Signer<'info>,
+
+    /// Protocol account containing configuration
+    #[account(mut, seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
+
+pub fn set_partial_liquidation_config(
+    ctx: Context<SetPartialLiquidationConfig>,
+    target_bps: u64,
+    full_liquidation_threshold_bps: u64,
+) -> Result<()> {
+    // validation: 0 disables partial mode; otherwise the target sits between the
+    // liquidation threshold and the min collateral ratio, and the full-liquidation
+    // threshold below the liquidation threshold
+    let config = &ctx.accounts.protocol.config;
+    validate_partial_liquidation_target(
+        target_bps,
+        full_liquidation_threshold_bps,
+        config.liquidation_threshold_bps,
+        config.min_collateral_ratio_bps,
+    )?;
+
+    let protocol = &mut ctx.accounts.protocol;
+    protocol.config.partial_liquidation_target_bps = target_bps;
+    protocol.config.full_liquidation_threshold_bps = full_liquidation_threshold_bps;
+    let clock = Clock::get()?;
+    protocol.config.last_updated = clock.unix_timestamp;
+    protocol.config.last_updated_by = ctx.accounts.authority.key();
+
+    emit!(ProtocolStateUpdatedEvent {
+        protocol: protocol.key(),
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 // ----------------------------------------------------------------------------
 // Update Treasury Instruction
 // ----------------------------------------------------------------------------
@@ -325,7 +418,41 @@ pub struct SetFundingCutBps<'info> {
     pub protocol: Account<'info, Protocol>,
 }
 
+// ----------------------------------------------------------------------------
+// Set Insurance Cut Bps Instruction
+// ----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct SetInsuranceCutBps<'info> {
+    /// Signer that must have the ConfigManager role
+    #[account(
+        mut,
+        constraint = protocol.has_permission(authority.key(), Role::ConfigManager) @ PerpetualsError::UnauthorizedRole,
+    )]
+    pub authority: Signer<'info>,
 
+    /// Protocol account containing configuration
+    #[account(mut, seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
+
+// ----------------------------------------------------------------------------
+// Set ADL Pnl To Pool Bps Instruction
+// ----------------------------------------------------------------------------
+
+#[derive(Accounts)]
+pub struct SetAdlPnlToPoolBps<'info> {
+    /// Signer that must have the ConfigManager role
+    #[account(
+        mut,
+        constraint = protocol.has_permission(authority.key(), Role::ConfigManager) @ PerpetualsError::UnauthorizedRole,
+    )]
+    pub authority: Signer<'info>,
+
+    /// Protocol account containing configuration
+    #[account(mut, seeds = [PROTOCOL_SEED], bump)]
+    pub protocol: Account<'info, Protocol>,
+}
 
 // ----------------------------------------------------------------------------
 // Set Rebalance Request Fee Instruction
@@ -413,6 +540,24 @@ crate::impl_bps_setter!(
     new_treasury_cut_bps
 );
 
+crate::impl_bps_setter!(
+    set_insurance_cut_bps,
+    SetInsuranceCutBps<'info>,
+    insurance_cut_bps,
+    MAX_INSURANCE_CUT_BPS,
+    old_insurance_cut_bps,
+    new_insurance_cut_bps
+);
+
+crate::impl_bps_setter!(
+    set_adl_pnl_to_pool_bps,
+    SetAdlPnlToPoolBps<'info>,
+    adl_pnl_to_pool_bps,
+    BPS_DIVISOR,
+    old_adl_pnl_to_pool_bps,
+    new_adl_pnl_to_pool_bps
+);
+
 crate::impl_bps_setter!(
     set_funding_cut_bps,
     SetFundingCutBps<'info>,
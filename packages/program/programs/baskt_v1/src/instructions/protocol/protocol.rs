@@ -85,6 +85,7 @@ pub fn add_role(ctx: Context<AddRole>, role_type: u8) -> Result<()> {
         6 => Role::FundingManager,
         7 => Role::ConfigManager,
         8 => Role::Keeper,
+        9 => Role::Oracle,
         _ => return Err(PerpetualsError::InvalidRoleType.into()),
     };
     
@@ -129,6 +130,8 @@ pub fn remove_role(ctx: Context<RemoveRole>, role_type: u8) -> Result<()> {
         5 => Role::Liquidator,
         6 => Role::FundingManager,
         7 => Role::ConfigManager,
+        8 => Role::Keeper,
+        9 => Role::Oracle,
         _ => return Err(PerpetualsError::InvalidRoleType.into()),
     };
 
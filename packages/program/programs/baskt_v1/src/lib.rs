@@ -1,6 +1,8 @@
 #![allow(deprecated)]
 
-use crate::state::baskt::AssetConfig;
+use crate::state::asset::OracleConfig;
+use crate::state::baskt::{AssetConfig, CircuitBreakerConfig};
+use crate::state::trading_delegate::TradingDelegateConfig;
 use anchor_lang::prelude::*;
 
 pub mod constants;
@@ -8,6 +10,7 @@ pub mod error;
 pub mod events;
 pub mod instructions;
 pub mod math;
+pub mod reader;
 pub mod state;
 pub mod utils;
 
@@ -15,7 +18,9 @@ declare_id!("EwEg2kTxcKDX8uhWFUDUJRXcYRSfUfDSekDrAHNVTTuK");
 
 use crate::instructions::baskt_all::baskt_config::{
     SetBasktClosingFeeBps, SetBasktLiquidationFeeBps, SetBasktLiquidationThresholdBps,
-    SetBasktMinCollateralRatioBps, SetBasktOpeningFeeBps, UpdateBasktConfig,
+    SetBasktCircuitBreaker, SetBasktLiquidationTwapWindow, SetBasktMaxPriceDeviationBps, SetBasktMinCollateralRatioBps,
+    SetBasktOpeningFeeBps,
+    UpdateBasktConfig,
     UpdateBasktConfigParams,
 };
 use crate::instructions::baskt_all::{
@@ -23,24 +28,33 @@ use crate::instructions::baskt_all::{
     activate::{ActivateBaskt, ActivateBasktParams},
     decomission::{DecommissionBaskt},
     close::{CloseBaskt},
+    quote_nav::{QuoteNav},
+    nav_history::{InitializeNavHistory, RecordNav},
+    baskt_price::{InitializeBasktPrice},
+    circuit_breaker::{ResumeBaskt},
     rebalance::{Rebalance},
     rebalance_request::{RebalanceRequest},
     market_indices::{update_market_indices},
 };
-use crate::instructions::config::{SetFundingCutBps, SetTreasuryCutBps};
+use crate::instructions::config::{
+    SetAdlPnlToPoolBps, SetFundingCutBps, SetInsuranceCutBps, SetTreasuryCutBps,
+};
 use crate::instructions::protocol::UpdateFeatureFlagsParams;
 use instructions::*;
 // Import position instruction structs and params
 use crate::instructions::config::{
-    SetClosingFeeBps, SetLiquidationFeeBps, SetLiquidationThresholdBps, SetMinCollateralRatioBps,
+    SetClosingFeeBps, SetLiquidationFeeBps, SetLiquidationThresholdBps, SetMaxPriceDeviationBps,
+    SetMinCollateralRatioBps, SetPartialLiquidationConfig,
     SetMinLiquidity, SetOpeningFeeBps, UpdateTreasury, SetRebalanceRequestFee, SetBasktCreationFee,
 };
 use crate::instructions::position::{
     add_collateral::{AddCollateral, AddCollateralParams},
     close::{ClosePosition, ClosePositionParams},
     force_close::{ForceClosePosition, ForceClosePositionParams},
+    increase::{IncreasePosition, IncreasePositionParams},
     liquidate::{LiquidatePosition, LiquidatePositionParams},
     open::{OpenPosition, OpenPositionParams},
+    remove_collateral::{RemoveCollateral, RemoveCollateralParams},
 };
 
 #[program]
@@ -89,6 +103,13 @@ pub mod baskt {
         instructions::config::set_liquidation_fee_bps(ctx, new_liquidation_fee_bps)
     }
 
+    pub fn set_max_price_deviation_bps(
+        ctx: Context<SetMaxPriceDeviationBps>,
+        new_max_price_deviation_bps: u64,
+    ) -> Result<()> {
+        instructions::config::set_max_price_deviation_bps(ctx, new_max_price_deviation_bps)
+    }
+
     pub fn set_treasury_cut_bps(
         ctx: Context<SetTreasuryCutBps>,
         new_treasury_cut_bps: u64,
@@ -96,6 +117,13 @@ pub mod baskt {
         instructions::config::set_treasury_cut_bps(ctx, new_treasury_cut_bps)
     }
 
+    pub fn set_insurance_cut_bps(
+        ctx: Context<SetInsuranceCutBps>,
+        new_insurance_cut_bps: u64,
+    ) -> Result<()> {
+        instructions::config::set_insurance_cut_bps(ctx, new_insurance_cut_bps)
+    }
+
     pub fn set_funding_cut_bps(
         ctx: Context<SetFundingCutBps>,
         new_funding_cut_bps: u64,
@@ -103,6 +131,13 @@ pub mod baskt {
         instructions::config::set_funding_cut_bps(ctx, new_funding_cut_bps)
     }
 
+    pub fn set_adl_pnl_to_pool_bps(
+        ctx: Context<SetAdlPnlToPoolBps>,
+        new_adl_pnl_to_pool_bps: u64,
+    ) -> Result<()> {
+        instructions::config::set_adl_pnl_to_pool_bps(ctx, new_adl_pnl_to_pool_bps)
+    }
+
     pub fn set_min_collateral_ratio_bps(
         ctx: Context<SetMinCollateralRatioBps>,
         new_min_collateral_ratio_bps: u64,
@@ -117,6 +152,18 @@ pub mod baskt {
         instructions::config::set_liquidation_threshold_bps(ctx, new_liquidation_threshold_bps)
     }
 
+    pub fn set_partial_liquidation_config(
+        ctx: Context<SetPartialLiquidationConfig>,
+        target_bps: u64,
+        full_liquidation_threshold_bps: u64,
+    ) -> Result<()> {
+        instructions::config::set_partial_liquidation_config(
+            ctx,
+            target_bps,
+            full_liquidation_threshold_bps,
+        )
+    }
+
     pub fn update_treasury(ctx: Context<UpdateTreasury>, new_treasury: Pubkey) -> Result<()> {
         instructions::config::update_treasury(ctx, new_treasury)
     }
@@ -194,6 +241,33 @@ pub mod baskt {
         )
     }
 
+    pub fn set_baskt_max_price_deviation_bps(
+        ctx: Context<SetBasktMaxPriceDeviationBps>,
+        new_max_price_deviation_bps: Option<u64>,
+    ) -> Result<()> {
+        instructions::baskt_all::baskt_config::set_baskt_max_price_deviation_bps(
+            ctx,
+            new_max_price_deviation_bps,
+        )
+    }
+
+    pub fn set_baskt_circuit_breaker(
+        ctx: Context<SetBasktCircuitBreaker>,
+        new_circuit_breaker: Option<CircuitBreakerConfig>,
+    ) -> Result<()> {
+        instructions::baskt_all::baskt_config::set_baskt_circuit_breaker(ctx, new_circuit_breaker)
+    }
+
+    pub fn set_baskt_liquidation_twap_window(
+        ctx: Context<SetBasktLiquidationTwapWindow>,
+        new_window_seconds: Option<u64>,
+    ) -> Result<()> {
+        instructions::baskt_all::baskt_config::set_baskt_liquidation_twap_window(
+            ctx,
+            new_window_seconds,
+        )
+    }
+
     pub fn update_baskt_config(
         ctx: Context<UpdateBasktConfig>,
         params: UpdateBasktConfigParams,
@@ -206,6 +280,12 @@ pub mod baskt {
         instructions::baskt_all::decomission::decommission_baskt(ctx)
     }
 
+    pub fn resume_baskt<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResumeBaskt<'info>>,
+    ) -> Result<()> {
+        instructions::baskt_all::circuit_breaker::resume_baskt(ctx)
+    }
+
     pub fn force_close_position<'info>(
         ctx: Context<'_, '_, 'info, 'info, ForceClosePosition<'info>>,
         params: ForceClosePositionParams,
@@ -217,10 +297,60 @@ pub mod baskt {
         instructions::baskt_all::close::close_baskt(ctx)
     }
 
+    pub fn quote_nav<'info>(ctx: Context<'_, '_, 'info, 'info, QuoteNav<'info>>) -> Result<u64> {
+        instructions::baskt_all::quote_nav::quote_nav(ctx)
+    }
+
+    pub fn initialize_nav_history(ctx: Context<InitializeNavHistory>) -> Result<()> {
+        instructions::baskt_all::nav_history::initialize_nav_history(ctx)
+    }
+
+    pub fn record_nav<'info>(ctx: Context<'_, '_, 'info, 'info, RecordNav<'info>>) -> Result<()> {
+        instructions::baskt_all::nav_history::record_nav(ctx)
+    }
+
+    pub fn initialize_baskt_price(ctx: Context<InitializeBasktPrice>) -> Result<()> {
+        instructions::baskt_all::baskt_price::initialize_baskt_price(ctx)
+    }
+
     pub fn add_asset(ctx: Context<AddAsset>, params: AddAssetParams) -> Result<()> {
         instructions::asset::add_asset(ctx, params)
     }
 
+    pub fn update_asset_oracle_config(
+        ctx: Context<UpdateAssetOracleConfig>,
+        oracle_config: OracleConfig,
+    ) -> Result<()> {
+        instructions::asset::update_asset_oracle_config(ctx, oracle_config)
+    }
+
+    pub fn migrate_asset(ctx: Context<MigrateAsset>, params: MigrateAssetParams) -> Result<()> {
+        instructions::asset::migrate_asset(ctx, params)
+    }
+
+    pub fn set_manual_price(ctx: Context<SetManualPrice>, price: u64) -> Result<()> {
+        instructions::asset::set_manual_price(ctx, price)
+    }
+
+    // Protocol-owned price feeds
+    pub fn initialize_price_feed(ctx: Context<InitializePriceFeed>) -> Result<()> {
+        instructions::oracle::initialize_price_feed(ctx)
+    }
+
+    pub fn update_price_feed(
+        ctx: Context<UpdatePriceFeed>,
+        params: PriceFeedUpdateParams,
+    ) -> Result<()> {
+        instructions::oracle::update_price_feed(ctx, params)
+    }
+
+    pub fn post_signed_price(
+        ctx: Context<PostSignedPrice>,
+        params: PriceFeedUpdateParams,
+    ) -> Result<()> {
+        instructions::oracle::post_signed_price(ctx, params)
+    }
+
     pub fn rebalance(
         ctx: Context<Rebalance>,
         asset_configs: Vec<AssetConfig>,
@@ -234,6 +364,17 @@ pub mod baskt {
         instructions::baskt_all::rebalance_request::rebalance_request(ctx)
     }
 
+    pub fn set_trading_delegate(
+        ctx: Context<SetTradingDelegate>,
+        config: TradingDelegateConfig,
+    ) -> Result<()> {
+        instructions::delegate::set_trading_delegate(ctx, config)
+    }
+
+    pub fn revoke_trading_delegate(ctx: Context<RevokeTradingDelegate>) -> Result<()> {
+        instructions::delegate::revoke_trading_delegate(ctx)
+    }
+
     pub fn create_order(
         ctx: Context<CreateOrder>,
         params: instructions::order::create_order::CreateOrderParams,
@@ -245,6 +386,30 @@ pub mod baskt {
         instructions::order::cancel_order(ctx)
     }
 
+    pub fn amend_order(
+        ctx: Context<AmendOrder>,
+        params: instructions::order::amend::AmendOrderParams,
+    ) -> Result<()> {
+        instructions::order::amend_order(ctx, params)
+    }
+
+    pub fn create_order_group<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateOrderGroup<'info>>,
+        group_id: u32,
+    ) -> Result<()> {
+        instructions::order::create_order_group(ctx, group_id)
+    }
+
+    pub fn update_trailing_stop<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateTrailingStop<'info>>,
+    ) -> Result<()> {
+        instructions::order::update_trailing_stop(ctx)
+    }
+
+    pub fn expire_order(ctx: Context<ExpireOrder>) -> Result<()> {
+        instructions::order::expire_order(ctx)
+    }
+
     pub fn open_position<'info>(
         ctx: Context<'_, '_, 'info, 'info, OpenPosition<'info>>,
         params: OpenPositionParams,
@@ -256,6 +421,52 @@ pub mod baskt {
         instructions::position::add_collateral::add_collateral(ctx, params)
     }
 
+    pub fn remove_collateral<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveCollateral<'info>>,
+        params: RemoveCollateralParams,
+    ) -> Result<()> {
+        instructions::position::remove_collateral::remove_collateral(ctx, params)
+    }
+
+    pub fn create_margin_account(ctx: Context<CreateMarginAccount>) -> Result<()> {
+        instructions::margin::create_margin_account(ctx)
+    }
+
+    pub fn deposit_margin_collateral(ctx: Context<DepositMarginCollateral>, amount: u64) -> Result<()> {
+        instructions::margin::deposit_margin_collateral(ctx, amount)
+    }
+
+    pub fn withdraw_margin_collateral<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawMarginCollateral<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::margin::withdraw_margin_collateral(ctx, amount)
+    }
+
+    pub fn attach_margin_position(ctx: Context<AttachMarginPosition>) -> Result<()> {
+        instructions::margin::attach_margin_position(ctx)
+    }
+
+    pub fn detach_margin_position<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DetachMarginPosition<'info>>,
+        collateral: u64,
+    ) -> Result<()> {
+        instructions::margin::detach_margin_position(ctx, collateral)
+    }
+
+    pub fn liquidate_margin_account<'info>(
+        ctx: Context<'_, '_, 'info, 'info, LiquidateMarginAccount<'info>>,
+    ) -> Result<()> {
+        instructions::margin::liquidate_margin_account(ctx)
+    }
+
+    pub fn increase_position<'info>(
+        ctx: Context<'_, '_, 'info, 'info, IncreasePosition<'info>>,
+        params: IncreasePositionParams,
+    ) -> Result<()> {
+        instructions::position::increase::increase_position(ctx, params)
+    }
+
     pub fn close_position<'info>(
         ctx: Context<'_, '_, 'info, 'info, ClosePosition<'info>>,
         params: ClosePositionParams,
@@ -270,6 +481,20 @@ pub mod baskt {
         instructions::position::liquidate::liquidate_position(ctx, params)
     }
 
+    // Auto-Deleveraging
+    pub fn update_adl_ranking<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateAdlRanking<'info>>,
+    ) -> Result<()> {
+        instructions::adl::update_adl_ranking(ctx)
+    }
+
+    pub fn auto_deleverage_position<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AutoDeleveragePosition<'info>>,
+        params: AutoDeleverageParams,
+    ) -> Result<()> {
+        instructions::adl::auto_deleverage_position(ctx, params)
+    }
+
     // Liquidity Pool Management
     pub fn initialize_liquidity_pool(
         ctx: Context<InitializeLiquidityPool>,
@@ -279,6 +504,10 @@ pub mod baskt {
         instructions::liquidity::initialize_liquidity_pool(ctx, deposit_fee_bps, withdrawal_fee_bps)
     }
 
+    pub fn initialize_insurance_fund(ctx: Context<InitializeInsuranceFund>) -> Result<()> {
+        instructions::liquidity::initialize_insurance_fund(ctx)
+    }
+
     pub fn add_liquidity(
         ctx: Context<AddLiquidity>,
         amount: u64,
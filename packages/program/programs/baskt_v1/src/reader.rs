@@ -0,0 +1,126 @@
+//! Helpers for other programs consuming baskt prices.
+//!
+//! Depend on this crate with the `no-entrypoint` (or `cpi`) feature and read a
+//! `BasktPriceAccount` passed to your instruction:
+//!
+//! ```ignore
+//! let price = baskt::reader::read_baskt_price(&price_info, &baskt_key, &Clock::get()?, 150)?;
+//! ```
+
+use crate::constants::BASKT_PRICE_ACCOUNT_VERSION;
+use crate::error::PerpetualsError;
+use crate::state::baskt_price::{BasktPriceAccount, BasktPriceStatus};
+use anchor_lang::prelude::*;
+
+/// Validated baskt price
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BasktPrice {
+    pub nav: u64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub slot: u64,
+    pub publish_time: i64,
+}
+
+/// Deserialize a `BasktPriceAccount` owned by this program without validating it
+pub fn load_baskt_price_account(info: &AccountInfo) -> Result<BasktPriceAccount> {
+    require_keys_eq!(*info.owner, crate::ID, PerpetualsError::InvalidOracleAccount);
+    let data = info.try_borrow_data()?;
+    let account = BasktPriceAccount::try_deserialize(&mut &data[..])
+        .map_err(|_| PerpetualsError::InvalidOracleAccount)?;
+    require!(
+        account.version == BASKT_PRICE_ACCOUNT_VERSION,
+        PerpetualsError::UnsupportedPriceAccountVersion
+    );
+    Ok(account)
+}
+
+/// Read the price of `baskt`, requiring it to be trading and published within
+/// `max_age_slots` slots of `clock.slot`
+pub fn read_baskt_price(
+    info: &AccountInfo,
+    baskt: &Pubkey,
+    clock: &Clock,
+    max_age_slots: u64,
+) -> Result<BasktPrice> {
+    let account = load_baskt_price_account(info)?;
+    require_keys_eq!(account.baskt, *baskt, PerpetualsError::InvalidBaskt);
+    validate_baskt_price(&account, clock.slot, max_age_slots)
+}
+
+/// Check a loaded price account is usable at `current_slot`
+pub fn validate_baskt_price(
+    account: &BasktPriceAccount,
+    current_slot: u64,
+    max_age_slots: u64,
+) -> Result<BasktPrice> {
+    require!(
+        account.status == BasktPriceStatus::Trading,
+        PerpetualsError::InvalidBasktState
+    );
+    require!(account.nav > 0, PerpetualsError::InvalidOraclePrice);
+    require!(
+        current_slot.saturating_sub(account.slot) <= max_age_slots,
+        PerpetualsError::StaleOraclePrice
+    );
+    Ok(BasktPrice {
+        nav: account.nav,
+        conf: account.conf,
+        exponent: account.exponent,
+        slot: account.slot,
+        publish_time: account.publish_time,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::PRICE_EXPONENT;
+
+    fn account() -> BasktPriceAccount {
+        BasktPriceAccount {
+            version: BASKT_PRICE_ACCOUNT_VERSION,
+            baskt: Pubkey::new_unique(),
+            nav: 100_000_000,
+            conf: 50_000,
+            exponent: PRICE_EXPONENT,
+            slot: 1_000,
+            publish_time: 0,
+            status: BasktPriceStatus::Trading,
+            bump: 255,
+            reserved: [0; 64],
+        }
+    }
+
+    #[test]
+    fn test_layout_is_stable() {
+        let account = account();
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), 8 + BasktPriceAccount::INIT_SPACE);
+        assert_eq!(data.len(), 143);
+        assert_eq!(data[8], BASKT_PRICE_ACCOUNT_VERSION);
+        assert_eq!(&data[9..41], account.baskt.as_ref());
+        assert_eq!(&data[41..49], &account.nav.to_le_bytes());
+        assert_eq!(&data[49..57], &account.conf.to_le_bytes());
+        assert_eq!(&data[57..61], &PRICE_EXPONENT.to_le_bytes());
+        assert_eq!(&data[61..69], &account.slot.to_le_bytes());
+        assert_eq!(data[77], BasktPriceStatus::Trading as u8);
+        assert_eq!(data[78], 255);
+    }
+
+    #[test]
+    fn test_validate_baskt_price() {
+        let account = account();
+        assert_eq!(validate_baskt_price(&account, 1_010, 10).unwrap().nav, 100_000_000);
+        assert!(validate_baskt_price(&account, 1_011, 10).is_err());
+
+        let mut halted = account.clone();
+        halted.status = BasktPriceStatus::Decommissioning;
+        assert!(validate_baskt_price(&halted, 1_000, 10).is_err());
+
+        let mut unpublished = account;
+        unpublished.nav = 0;
+        assert!(validate_baskt_price(&unpublished, 1_000, 10).is_err());
+    }
+}
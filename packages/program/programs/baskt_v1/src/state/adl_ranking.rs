@@ -0,0 +1,162 @@
+use crate::constants::MAX_ADL_RANKING_ENTRIES;
+use crate::error::PerpetualsError;
+use anchor_lang::prelude::*;
+
+//----------------------------------------------------------------------------
+// STATE STRUCTURES: ADL RANKING
+//----------------------------------------------------------------------------
+
+/// One ranked auto-deleveraging candidate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, InitSpace)]
+pub struct AdlEntry {
+    pub position: Pubkey,
+    /// `Position::adl_score` when last scored
+    pub score: u128,
+    /// Unrealized profit when last scored
+    pub unrealized_pnl: u64,
+}
+
+/// Per-baskt ranking of the most profitable, most leveraged open positions, kept by
+/// keepers and consumed highest score first when the pool cannot cover their profits.
+#[account]
+#[derive(InitSpace)]
+pub struct AdlRanking {
+    pub baskt: Pubkey,
+    /// Sorted by descending score; ties broken by position key for a deterministic order
+    #[max_len(MAX_ADL_RANKING_ENTRIES)]
+    pub entries: Vec<AdlEntry>,
+    pub last_updated: i64,
+    pub bump: u8,
+}
+
+impl AdlRanking {
+    pub fn initialize(&mut self, baskt: Pubkey, bump: u8) {
+        self.baskt = baskt;
+        self.entries.clear();
+        self.last_updated = 0;
+        self.bump = bump;
+    }
+
+    /// Insert or rescore a position. Entries without profit are dropped, and only the
+    /// MAX_ADL_RANKING_ENTRIES highest scores are kept.
+    pub fn upsert(&mut self, entry: AdlEntry) {
+        self.remove(&entry.position);
+        if entry.score == 0 {
+            return;
+        }
+
+        let index = self
+            .entries
+            .iter()
+            .position(|e| {
+                e.score < entry.score || (e.score == entry.score && e.position > entry.position)
+            })
+            .unwrap_or(self.entries.len());
+        self.entries.insert(index, entry);
+        self.entries.truncate(MAX_ADL_RANKING_ENTRIES);
+    }
+
+    pub fn remove(&mut self, position: &Pubkey) {
+        self.entries.retain(|e| e.position != *position);
+    }
+
+    pub fn top(&self) -> Option<&AdlEntry> {
+        self.entries.first()
+    }
+
+    /// Drop a position from the ranking held in `ranking_info`, if the baskt has one.
+    /// Called by every handler that closes or liquidates a position, so closed positions
+    /// never hold a rank or count towards ranked profit.
+    pub fn remove_from_account(ranking_info: &AccountInfo, position: &Pubkey) -> Result<()> {
+        if ranking_info.owner != &crate::ID || ranking_info.data_is_empty() {
+            return Ok(());
+        }
+        let mut data = ranking_info.try_borrow_mut_data()?;
+        let mut ranking = AdlRanking::try_deserialize(&mut &data[..])?;
+        if ranking.entries.iter().any(|e| e.position == *position) {
+            ranking.remove(position);
+            ranking.try_serialize(&mut &mut data[..])?;
+        }
+        Ok(())
+    }
+
+    /// Whether `score` ranks at least as high as every other ranked position
+    pub fn outranks_others(&self, position: &Pubkey, score: u128) -> bool {
+        self.entries
+            .iter()
+            .filter(|e| e.position != *position)
+            .all(|e| e.score <= score)
+    }
+
+    /// Total unrealized profit across ranked positions
+    pub fn ranked_unrealized_pnl(&self) -> Result<u64> {
+        self.entries.iter().try_fold(0u64, |total, e| {
+            total
+                .checked_add(e.unrealized_pnl)
+                .ok_or(PerpetualsError::MathOverflow.into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(seed: u8, score: u128, unrealized_pnl: u64) -> AdlEntry {
+        AdlEntry {
+            position: Pubkey::new_from_array([seed; 32]),
+            score,
+            unrealized_pnl,
+        }
+    }
+
+    fn ranking() -> AdlRanking {
+        AdlRanking {
+            baskt: Pubkey::default(),
+            entries: Vec::new(),
+            last_updated: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_upsert_keeps_descending_order() {
+        let mut ranking = ranking();
+        ranking.upsert(entry(1, 100, 10));
+        ranking.upsert(entry(2, 300, 30));
+        ranking.upsert(entry(3, 200, 20));
+        ranking.upsert(entry(4, 200, 5));
+
+        let order: Vec<u8> = ranking.entries.iter().map(|e| e.position.to_bytes()[0]).collect();
+        assert_eq!(order, vec![2, 3, 4, 1]);
+        assert_eq!(ranking.ranked_unrealized_pnl().unwrap(), 65);
+
+        // Rescoring moves the entry; losing profit drops it
+        ranking.upsert(entry(1, 400, 40));
+        assert_eq!(ranking.top().unwrap().position, Pubkey::new_from_array([1; 32]));
+        ranking.upsert(entry(2, 0, 0));
+        assert_eq!(ranking.entries.len(), 3);
+    }
+
+    #[test]
+    fn test_outranks_others_ignores_own_entry() {
+        let mut ranking = ranking();
+        ranking.upsert(entry(1, 300, 30));
+        ranking.upsert(entry(2, 200, 20));
+
+        let top = Pubkey::new_from_array([1; 32]);
+        assert!(ranking.outranks_others(&top, 250));
+        assert!(!ranking.outranks_others(&top, 150));
+        assert!(ranking.outranks_others(&Pubkey::new_from_array([3; 32]), 300));
+    }
+
+    #[test]
+    fn test_upsert_keeps_only_highest_scores() {
+        let mut ranking = ranking();
+        for i in 0..(MAX_ADL_RANKING_ENTRIES as u8 + 2) {
+            ranking.upsert(entry(i, i as u128 + 1, 1));
+        }
+        assert_eq!(ranking.entries.len(), MAX_ADL_RANKING_ENTRIES);
+        assert_eq!(ranking.entries.last().unwrap().score, 3);
+    }
+}
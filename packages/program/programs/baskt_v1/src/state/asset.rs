@@ -1,3 +1,7 @@
+use crate::constants::{
+    DEFAULT_ORACLE_MAX_AGE_SECONDS, DEFAULT_ORACLE_MAX_CONF_BPS, DEFAULT_ORACLE_MAX_SPREAD_BPS,
+    MAX_ORACLE_SOURCES,
+};
 use anchor_lang::prelude::*;
 /// Permissions for the asset - optimized to use bitfield
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, InitSpace)]
@@ -38,6 +42,65 @@ impl AssetPermissions {
     }
 }
 
+/// A price source an asset can be priced from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum OracleSource {
+    /// Pyth `PriceUpdateV2` for the given feed id
+    Pyth { feed_id: [u8; 32] },
+    /// Protocol-owned `PriceFeed` PDA for the asset
+    PriceFeed,
+    /// Price set by an AssetManager via `set_manual_price`
+    Manual,
+}
+
+impl OracleSource {
+    /// Whether the source is read from a dedicated account in `remaining_accounts`
+    pub fn needs_account(&self) -> bool {
+        !matches!(self, OracleSource::Manual)
+    }
+}
+
+/// Per-asset oracle policy applied whenever the asset's price is consumed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct OracleConfig {
+    #[max_len(MAX_ORACLE_SOURCES)]
+    pub sources: Vec<OracleSource>, // Sources aggregated into the asset price (median)
+    pub min_sources: u8, // Minimum number of usable sources for a valid price
+    pub max_price_age_seconds: u64, // Reject price updates older than this
+    pub max_conf_bps: u64, // Max confidence interval as a fraction of price (basis points)
+    pub max_spread_bps: u64, // Spread between sources above which they are flagged as disagreeing
+}
+
+impl Default for OracleConfig {
+    fn default() -> Self {
+        Self {
+            sources: Vec::new(),
+            min_sources: 1,
+            max_price_age_seconds: DEFAULT_ORACLE_MAX_AGE_SECONDS,
+            max_conf_bps: DEFAULT_ORACLE_MAX_CONF_BPS,
+            max_spread_bps: DEFAULT_ORACLE_MAX_SPREAD_BPS,
+        }
+    }
+}
+
+/// Admin-set price used by `OracleSource::Manual`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct ManualPrice {
+    pub price: u64,        // PRICE_PRECISION
+    pub publish_time: i64, // Unix timestamp the price was set at
+}
+
+/// `SyntheticAsset` as written before oracle configs existed; those accounts hold only
+/// these fields and are grown in place by `migrate_asset`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct DeployedSyntheticAsset {
+    #[max_len(10)]
+    pub ticker: String,
+    pub permissions: AssetPermissions,
+    pub is_active: bool,
+    pub listing_time: u32,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct SyntheticAsset {
@@ -46,6 +109,8 @@ pub struct SyntheticAsset {
     pub permissions: AssetPermissions, // Optimized permissions (1 byte vs 2 bytes)
     pub is_active: bool,  // Whether the asset is active
     pub listing_time: u32, // Unix timestamp (4 bytes vs 8 bytes i64)
+    pub oracle_config: OracleConfig, // Price sources and staleness/confidence/spread policy
+    pub manual_price: ManualPrice, // Last admin-set price, used by OracleSource::Manual
 }
 
 impl SyntheticAsset {
@@ -55,11 +120,68 @@ impl SyntheticAsset {
         ticker: String,
         permissions: AssetPermissions,
         listing_time: u32,
+        oracle_sources: Vec<OracleSource>,
     ) -> Result<()> {
         self.ticker = ticker;
         self.permissions = permissions;
         self.is_active = true;
         self.listing_time = listing_time;
+        self.oracle_config = OracleConfig {
+            sources: oracle_sources,
+            ..OracleConfig::default()
+        };
+        self.manual_price = ManualPrice::default();
         Ok(())
     }
+    /// Rebuild an asset from its deployed layout, keeping its identity and listing
+    pub fn from_deployed(deployed: DeployedSyntheticAsset, oracle_config: OracleConfig) -> Self {
+        Self {
+            ticker: deployed.ticker,
+            permissions: deployed.permissions,
+            is_active: deployed.is_active,
+            listing_time: deployed.listing_time,
+            oracle_config,
+            manual_price: ManualPrice::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deployed_asset_migrates_unchanged() {
+        // Account data written by the first deployed program, after the discriminator
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&3u32.to_le_bytes());
+        bytes.extend_from_slice(b"BTC");
+        bytes.push(0x01); // permissions: longs only
+        bytes.push(0); // is_active
+        bytes.extend_from_slice(&1_700_000_000u32.to_le_bytes());
+        bytes.resize(DeployedSyntheticAsset::INIT_SPACE, 0);
+        assert_eq!(bytes.len(), 20);
+
+        let deployed = DeployedSyntheticAsset::deserialize(&mut &bytes[..]).unwrap();
+        let oracle_config = OracleConfig {
+            sources: vec![OracleSource::PriceFeed],
+            ..OracleConfig::default()
+        };
+        let asset = SyntheticAsset::from_deployed(deployed, oracle_config.clone());
+        assert_eq!(asset.ticker, "BTC");
+        assert!(asset.permissions.allow_longs());
+        assert!(!asset.permissions.allow_shorts());
+        assert!(!asset.is_active);
+        assert_eq!(asset.listing_time, 1_700_000_000);
+        assert_eq!(asset.oracle_config, oracle_config);
+        assert_eq!(asset.manual_price, ManualPrice::default());
+
+        // The migrated asset round-trips in the current layout
+        let mut data = Vec::new();
+        asset.serialize(&mut data).unwrap();
+        assert!(data.len() <= SyntheticAsset::INIT_SPACE);
+        let decoded = SyntheticAsset::deserialize(&mut &data[..]).unwrap();
+        assert_eq!(decoded.ticker, "BTC");
+        assert_eq!(decoded.oracle_config, oracle_config);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::constants::BPS_DIVISOR;
+use crate::constants::{BPS_DIVISOR, MAX_MARK_PREMIUM_BPS};
 use crate::error::PerpetualsError;
 use crate::state::asset::SyntheticAsset;
 use crate::state::fee_index::RebalanceFeeIndex;
@@ -13,6 +13,15 @@ pub enum BasktStatus {
     Pending = 0,
     Active = 1,
     Decommissioning = 2,
+    /// Tripped circuit breaker: no new exposure until a BasktManager resumes trading
+    Halted = 3,
+}
+
+/// Halt the baskt when the oracle NAV moves more than `move_bps` within `window_seconds`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct CircuitBreakerConfig {
+    pub move_bps: u64,
+    pub window_seconds: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
@@ -165,6 +174,94 @@ impl Default for BasktConfig {
     }
 }
 
+/// Baskt overrides added after launch. Kept apart from `BasktConfig`, whose flag bits are
+/// used up and which sits mid-account, so existing baskt accounts keep their layout.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct BasktRiskConfig {
+    pub flags: u8,
+    pub max_price_deviation_bps: u64,
+    pub liquidation_twap_window_seconds: u64,
+    pub circuit_breaker: CircuitBreakerConfig,
+}
+
+impl BasktRiskConfig {
+    pub fn has_max_price_deviation(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+    
+    pub fn has_liquidation_twap_window(&self) -> bool {
+        self.flags & 0x02 != 0
+    }
+    
+    pub fn has_circuit_breaker(&self) -> bool {
+        self.flags & 0x04 != 0
+    }
+    
+    pub fn get_max_price_deviation_bps(&self) -> Option<u64> {
+        if self.has_max_price_deviation() {
+            Some(self.max_price_deviation_bps)
+        } else {
+            None
+        }
+    }
+    
+    pub fn get_circuit_breaker(&self) -> Option<CircuitBreakerConfig> {
+        if self.has_circuit_breaker() {
+            Some(self.circuit_breaker)
+        } else {
+            None
+        }
+    }
+    
+    /// When set, liquidations also require the NAV TWAP over this window to breach
+    pub fn get_liquidation_twap_window_seconds(&self) -> Option<u64> {
+        if self.has_liquidation_twap_window() {
+            Some(self.liquidation_twap_window_seconds)
+        } else {
+            None
+        }
+    }
+    
+    pub fn set_max_price_deviation_bps(&mut self, deviation: Option<u64>) {
+        match deviation {
+            Some(value) => {
+                self.flags |= 0x01;
+                self.max_price_deviation_bps = value;
+            }
+            None => {
+                self.flags &= !0x01;
+                self.max_price_deviation_bps = 0;
+            }
+        }
+    }
+    
+    pub fn set_liquidation_twap_window_seconds(&mut self, window: Option<u64>) {
+        match window {
+            Some(value) => {
+                self.flags |= 0x02;
+                self.liquidation_twap_window_seconds = value;
+            }
+            None => {
+                self.flags &= !0x02;
+                self.liquidation_twap_window_seconds = 0;
+            }
+        }
+    }
+    
+    pub fn set_circuit_breaker(&mut self, circuit_breaker: Option<CircuitBreakerConfig>) {
+        match circuit_breaker {
+            Some(value) => {
+                self.flags |= 0x04;
+                self.circuit_breaker = value;
+            }
+            None => {
+                self.flags &= !0x04;
+                self.circuit_breaker = CircuitBreakerConfig::default();
+            }
+        }
+    }
+}
+
 #[derive(InitSpace, PartialEq, Debug, Default, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
 pub struct AssetConfig {
     pub asset_id: Pubkey,
@@ -191,7 +288,14 @@ pub struct Baskt {
     pub config: BasktConfig,
     pub market_indices: MarketIndices,
     pub rebalance_fee_index: RebalanceFeeIndex,
-    pub extra_space: [u8; 120],
+
+    pub long_open_interest: u64,  // Total size of open long positions
+    pub short_open_interest: u64, // Total size of open short positions
+    pub index_price: u64,         // Last oracle NAV (PRICE_PRECISION), used for settlement
+    pub mark_price: u64,          // Index plus open-interest skew premium, used for risk checks
+    pub price_updated_at: i64,
+    pub risk_config: BasktRiskConfig,
+    pub extra_space: [u8; 47],
 }
 
 impl Baskt {
@@ -223,7 +327,13 @@ impl Baskt {
         self.bump = _bump;
         self.rebalance_period = rebalance_period;
         self.config = BasktConfig::default();
+        self.risk_config = BasktRiskConfig::default();
         self.baseline_nav = 0;
+        self.long_open_interest = 0;
+        self.short_open_interest = 0;
+        self.index_price = 0;
+        self.mark_price = 0;
+        self.price_updated_at = 0;
         // Initialize rebalance fee index
         self.rebalance_fee_index.initialize(creation_time as i64)?;
         // Initialize market indices
@@ -270,7 +380,275 @@ impl Baskt {
         matches!(self.status, BasktStatus::Decommissioning)
     }
 
-    
+    /// Check if the baskt is halted by its circuit breaker
+    pub fn is_halted(&self) -> bool {
+        matches!(self.status, BasktStatus::Halted)
+    }
 
+    /// Whether existing positions can be closed or liquidated (Active, Halted or Decommissioning)
+    pub fn allows_exits(&self) -> bool {
+        self.is_trading() || self.is_halted() || self.is_unwinding()
+    }
 
+    /// Premium of the mark over the index price in signed basis points, derived from the
+    /// open-interest skew: `MAX_MARK_PREMIUM_BPS * (long_oi - short_oi) / (long_oi + short_oi)`
+    pub fn mark_premium_bps(&self) -> Result<i64> {
+        let long_oi = self.long_open_interest as i128;
+        let short_oi = self.short_open_interest as i128;
+        let total_oi = long_oi + short_oi;
+        if total_oi == 0 {
+            return Ok(0);
+        }
+        let premium = (long_oi - short_oi)
+            .checked_mul(MAX_MARK_PREMIUM_BPS as i128)
+            .ok_or(PerpetualsError::MathOverflow)?
+            / total_oi;
+        Ok(premium as i64)
+    }
+
+    /// Mark price for a given index price under the current open-interest skew
+    pub fn compute_mark_price(&self, index_price: u64) -> Result<u64> {
+        let premium_bps = self.mark_premium_bps()? as i128;
+        let mark = (index_price as i128)
+            .checked_mul(BPS_DIVISOR as i128 + premium_bps)
+            .ok_or(PerpetualsError::MathOverflow)?
+            / BPS_DIVISOR as i128;
+        u64::try_from(mark).map_err(|_| PerpetualsError::MathOverflow.into())
+    }
+
+    /// Store a fresh index price and the mark price derived from it
+    pub fn update_prices(&mut self, index_price: u64, timestamp: i64) -> Result<()> {
+        self.mark_price = self.compute_mark_price(index_price)?;
+        self.index_price = index_price;
+        self.price_updated_at = timestamp;
+        Ok(())
+    }
+
+    pub fn add_open_interest(&mut self, is_long: bool, size: u64) -> Result<()> {
+        let open_interest = if is_long {
+            &mut self.long_open_interest
+        } else {
+            &mut self.short_open_interest
+        };
+        *open_interest = open_interest
+            .checked_add(size)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Saturates at zero so positions opened before open interest was tracked can close
+    pub fn remove_open_interest(&mut self, is_long: bool, size: u64) {
+        let open_interest = if is_long {
+            &mut self.long_open_interest
+        } else {
+            &mut self.short_open_interest
+        };
+        *open_interest = open_interest.saturating_sub(size);
+    }
+
+    /// Compute the current NAV from per-asset prices (PRICE_PRECISION, same order as
+    /// `current_asset_configs`).
+    ///
+    /// Mirrors `calculateNav` in the SDK bit-for-bit: every asset contributes
+    /// `baseline_nav * (price - baseline_price) * weight * direction / baseline_price`
+    /// (truncated), the sum is divided by BPS_DIVISOR (truncated) and added to
+    /// `baseline_nav`. The result is floored at zero.
+    pub fn compute_nav(&self, prices: &[u64]) -> Result<u64> {
+        require!(
+            prices.len() == self.current_asset_configs.len(),
+            PerpetualsError::InvalidBasktConfig
+        );
+
+        let nav = self.baseline_nav as i128;
+        let mut nav_change: i128 = 0;
+        for (config, price) in self.current_asset_configs.iter().zip(prices) {
+            require!(config.baseline_price > 0, PerpetualsError::InvalidBasktConfig);
+            let direction: i128 = if config.direction { 1 } else { -1 };
+            let asset_change = (*price as i128)
+                .checked_sub(config.baseline_price as i128)
+                .and_then(|delta| delta.checked_mul(config.weight as i128))
+                .and_then(|weighted| weighted.checked_mul(direction))
+                .and_then(|directional| nav.checked_mul(directional))
+                .and_then(|scaled| scaled.checked_div(config.baseline_price as i128))
+                .ok_or(PerpetualsError::MathOverflow)?;
+            nav_change = nav_change
+                .checked_add(asset_change)
+                .ok_or(PerpetualsError::MathOverflow)?;
+        }
+
+        let new_nav = nav
+            .checked_add(nav_change / BPS_DIVISOR as i128)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        if new_nav <= 0 {
+            return Ok(0);
+        }
+        u64::try_from(new_nav).map_err(|_| PerpetualsError::MathOverflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::PRICE_PRECISION;
+
+    #[test]
+    fn test_account_size_matches_deployed_layout() {
+        // BasktConfig sits mid-account, so it must not grow either
+        assert_eq!(BasktConfig::INIT_SPACE, 41);
+        assert_eq!(Baskt::INIT_SPACE, 786);
+    }
+
+    /// (baseline_price, current_price, weight_bps, is_long)
+    type AssetCase = (u64, u64, u64, bool);
+
+    fn baskt_with(assets: &[AssetCase], baseline_nav: u64) -> Baskt {
+        Baskt {
+            uid: 0,
+            current_asset_configs: assets
+                .iter()
+                .map(|(baseline, _, weight, direction)| AssetConfig {
+                    asset_id: Pubkey::new_unique(),
+                    direction: *direction,
+                    weight: *weight,
+                    baseline_price: baseline * PRICE_PRECISION,
+                })
+                .collect(),
+            is_public: true,
+            creator: Pubkey::default(),
+            status: BasktStatus::Active,
+            open_positions: 0,
+            last_rebalance_time: 0,
+            bump: 0,
+            rebalance_period: 0,
+            baseline_nav,
+            config: BasktConfig::default(),
+            market_indices: MarketIndices {
+                cumulative_funding_index: 0,
+                current_funding_rate: 0,
+                cumulative_borrow_index: 0,
+                current_borrow_rate: 0,
+                last_update_timestamp: 0,
+            },
+            rebalance_fee_index: RebalanceFeeIndex::default(),
+            long_open_interest: 0,
+            short_open_interest: 0,
+            index_price: 0,
+            mark_price: 0,
+            price_updated_at: 0,
+            risk_config: BasktRiskConfig::default(),
+            extra_space: [0; 47],
+        }
+    }
+
+    fn nav_of(assets: &[AssetCase], baseline_nav: u64) -> u64 {
+        let baskt = baskt_with(assets, baseline_nav * PRICE_PRECISION);
+        let prices: Vec<u64> = assets
+            .iter()
+            .map(|(_, current, _, _)| current * PRICE_PRECISION)
+            .collect();
+        baskt.compute_nav(&prices).unwrap()
+    }
+
+    #[test]
+    fn test_compute_nav_single_asset() {
+        assert_eq!(nav_of(&[(100, 110, 10_000, true)], 1000), 1100 * PRICE_PRECISION);
+        assert_eq!(nav_of(&[(100, 90, 10_000, true)], 1000), 900 * PRICE_PRECISION);
+        assert_eq!(nav_of(&[(100, 101, 10_000, true)], 1000), 1010 * PRICE_PRECISION);
+    }
+
+    #[test]
+    fn test_compute_nav_mixed_directions() {
+        let assets = [
+            (100, 110, 4_000, true),
+            (200, 180, 3_000, true),
+            (300, 330, 3_000, false),
+        ];
+        assert_eq!(nav_of(&assets, 1000), 980 * PRICE_PRECISION);
+
+        let assets = [
+            (100, 102, 2_500, true),
+            (200, 202, 2_500, true),
+            (300, 297, 2_500, false),
+            (400, 408, 2_500, false),
+        ];
+        assert_eq!(nav_of(&assets, 1000), 1005 * PRICE_PRECISION);
+    }
+
+    #[test]
+    fn test_compute_nav_edges() {
+        // Long asset goes to zero
+        let assets = [(100, 0, 5_000, true), (100, 100, 5_000, true)];
+        assert_eq!(nav_of(&assets, 1000), 500 * PRICE_PRECISION);
+
+        // Opposite directions cancel out
+        let assets = [(100, 110, 5_000, true), (100, 110, 5_000, false)];
+        assert_eq!(nav_of(&assets, 1000), 1000 * PRICE_PRECISION);
+
+        // Full-weight short with a 100x move is floored at zero
+        assert_eq!(nav_of(&[(100, 10_000, 10_000, false)], 1000), 0);
+    }
+
+    #[test]
+    fn test_compute_nav_truncates_like_sdk() {
+        // 1 unit of NAV with a 1bp move: each intermediate division truncates
+        let baskt = baskt_with(&[(10_000, 0, 10_000, true)], 1);
+        let nav = baskt.compute_nav(&[10_001 * PRICE_PRECISION]).unwrap();
+        assert_eq!(nav, 1);
+    }
+
+    #[test]
+    fn test_mark_price_follows_skew() {
+        let index = 100 * PRICE_PRECISION;
+        let mut baskt = baskt_with(&[(100, 100, 10_000, true)], 100);
+
+        // No open interest: mark equals index
+        assert_eq!(baskt.compute_mark_price(index).unwrap(), index);
+
+        // Balanced open interest: mark equals index
+        baskt.add_open_interest(true, 500).unwrap();
+        baskt.add_open_interest(false, 500).unwrap();
+        assert_eq!(baskt.compute_mark_price(index).unwrap(), index);
+
+        // Fully long: full premium above index
+        baskt.remove_open_interest(false, 500);
+        assert_eq!(baskt.mark_premium_bps().unwrap(), MAX_MARK_PREMIUM_BPS as i64);
+        assert_eq!(
+            baskt.compute_mark_price(index).unwrap(),
+            index + index * MAX_MARK_PREMIUM_BPS / BPS_DIVISOR
+        );
+
+        // 25% long / 75% short: half the premium below index
+        baskt.add_open_interest(false, 1_500).unwrap();
+        assert_eq!(baskt.mark_premium_bps().unwrap(), -(MAX_MARK_PREMIUM_BPS as i64) / 2);
+
+        baskt.update_prices(index, 42).unwrap();
+        assert_eq!(baskt.index_price, index);
+        assert!(baskt.mark_price < index);
+        assert_eq!(baskt.price_updated_at, 42);
+
+        // Removing more than is open saturates
+        baskt.remove_open_interest(true, u64::MAX);
+        assert_eq!(baskt.long_open_interest, 0);
+    }
+
+    #[test]
+    fn test_compute_nav_rejects_bad_input() {
+        let baskt = baskt_with(&[(100, 100, 10_000, true)], 1000);
+        assert!(baskt.compute_nav(&[]).is_err());
+
+        let mut baskt = baskt_with(&[(100, 100, 10_000, true)], 1000);
+        baskt.current_asset_configs[0].baseline_price = 0;
+        assert!(baskt.compute_nav(&[PRICE_PRECISION]).is_err());
+    }
+
+    #[test]
+    fn test_halted_allows_exits_only() {
+        let mut baskt = baskt_with(&[(100, 100, 10_000, true)], 1000);
+        baskt.status = BasktStatus::Halted;
+        assert!(!baskt.is_trading());
+        assert!(baskt.allows_exits());
+
+        baskt.status = BasktStatus::Pending;
+        assert!(!baskt.allows_exits());
+    }
 }
@@ -0,0 +1,84 @@
+use crate::constants::{BASKT_PRICE_ACCOUNT_VERSION, PRICE_EXPONENT};
+use crate::state::baskt::{Baskt, BasktStatus};
+use anchor_lang::prelude::*;
+
+/// Status of the published price, mirroring the baskt lifecycle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+#[repr(u8)]
+pub enum BasktPriceStatus {
+    /// No price has been published yet or the baskt is not active
+    Unknown = 0,
+    /// Baskt is trading; the price can be used
+    Trading = 1,
+    /// Baskt is winding down; the price is informational only
+    Decommissioning = 2,
+    /// Baskt circuit breaker tripped; the price is suspect
+    Halted = 3,
+}
+
+impl BasktPriceStatus {
+    pub fn from_baskt(baskt: &Baskt) -> Self {
+        match baskt.status {
+            BasktStatus::Pending => BasktPriceStatus::Unknown,
+            BasktStatus::Active => BasktPriceStatus::Trading,
+            BasktStatus::Decommissioning => BasktPriceStatus::Decommissioning,
+            BasktStatus::Halted => BasktPriceStatus::Halted,
+        }
+    }
+}
+
+/// Baskt NAV published for other programs to read (see `crate::reader`).
+///
+/// The layout is stable for a given `version`; fields are only ever appended by taking
+/// bytes from `reserved`, and any other change bumps `version`. Borsh layout, version 1:
+///
+/// | offset | size | field        |
+/// |--------|------|--------------|
+/// | 0      | 8    | discriminator|
+/// | 8      | 1    | version      |
+/// | 9      | 32   | baskt        |
+/// | 41     | 8    | nav (u64)    |
+/// | 49     | 8    | conf (u64)   |
+/// | 57     | 4    | exponent (i32, always PRICE_EXPONENT) |
+/// | 61     | 8    | slot (u64)   |
+/// | 69     | 8    | publish_time (i64) |
+/// | 77     | 1    | status (BasktPriceStatus) |
+/// | 78     | 1    | bump         |
+/// | 79     | 64   | reserved     |
+#[account]
+#[derive(InitSpace)]
+pub struct BasktPriceAccount {
+    pub version: u8,
+    pub baskt: Pubkey,
+    pub nav: u64,           // nav * 10^exponent is the NAV in USD
+    pub conf: u64,          // Confidence interval, same exponent as nav
+    pub exponent: i32,
+    pub slot: u64,          // Slot the NAV was computed in
+    pub publish_time: i64,  // Unix timestamp the NAV was computed at
+    pub status: BasktPriceStatus,
+    pub bump: u8,
+    pub reserved: [u8; 64],
+}
+
+impl BasktPriceAccount {
+    pub fn initialize(&mut self, baskt: Pubkey, bump: u8) {
+        self.version = BASKT_PRICE_ACCOUNT_VERSION;
+        self.baskt = baskt;
+        self.nav = 0;
+        self.conf = 0;
+        self.exponent = PRICE_EXPONENT;
+        self.slot = 0;
+        self.publish_time = 0;
+        self.status = BasktPriceStatus::Unknown;
+        self.bump = bump;
+        self.reserved = [0; 64];
+    }
+
+    pub fn publish(&mut self, baskt: &Baskt, nav: u64, conf: u64, clock: &Clock) {
+        self.nav = nav;
+        self.conf = conf;
+        self.slot = clock.slot;
+        self.publish_time = clock.unix_timestamp;
+        self.status = BasktPriceStatus::from_baskt(baskt);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::error::PerpetualsError;
+use anchor_lang::prelude::*;
+
+//----------------------------------------------------------------------------
+// STATE STRUCTURES: INSURANCE FUND
+//----------------------------------------------------------------------------
+
+/// Backstop for bad debt, funded by a cut of closing and liquidation fees.
+/// Settlements with bad debt draw on the vault first; only the remainder is
+/// socialized to LPs.
+#[account]
+#[derive(InitSpace)]
+pub struct InsuranceFund {
+    /// Token account holding the fund's collateral
+    pub vault: Pubkey,
+    /// Cumulative fee cuts received
+    pub total_fees_received: u64,
+    /// Cumulative bad debt paid from the fund into the pool
+    pub total_bad_debt_covered: u64,
+    /// Cumulative bad debt the fund could not cover and LPs absorbed
+    pub total_bad_debt_socialized: u64,
+    pub bump: u8,
+}
+
+/// Insurance fund side of a settlement
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InsuranceSettlement {
+    /// Fee cut moved from the position escrow into the vault
+    pub fee_received: u64,
+    /// Bad debt paid from the vault into the pool vault
+    pub bad_debt_covered: u64,
+    /// Bad debt left to LPs
+    pub bad_debt_socialized: u64,
+}
+
+impl InsuranceFund {
+    pub fn initialize(&mut self, vault: Pubkey, bump: u8) {
+        self.vault = vault;
+        self.total_fees_received = 0;
+        self.total_bad_debt_covered = 0;
+        self.total_bad_debt_socialized = 0;
+        self.bump = bump;
+    }
+
+    /// Record a settlement's fee cut and cover as much of its bad debt as the vault
+    /// (including that fee cut) holds.
+    pub fn settle(
+        &mut self,
+        fee_received: u64,
+        bad_debt: u64,
+        vault_balance: u64,
+    ) -> Result<InsuranceSettlement> {
+        let available = vault_balance
+            .checked_add(fee_received)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        let bad_debt_covered = std::cmp::min(bad_debt, available);
+        let bad_debt_socialized = bad_debt - bad_debt_covered;
+
+        self.total_fees_received = self
+            .total_fees_received
+            .checked_add(fee_received)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        self.total_bad_debt_covered = self
+            .total_bad_debt_covered
+            .checked_add(bad_debt_covered)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        self.total_bad_debt_socialized = self
+            .total_bad_debt_socialized
+            .checked_add(bad_debt_socialized)
+            .ok_or(PerpetualsError::MathOverflow)?;
+
+        Ok(InsuranceSettlement {
+            fee_received,
+            bad_debt_covered,
+            bad_debt_socialized,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fund() -> InsuranceFund {
+        InsuranceFund {
+            vault: Pubkey::default(),
+            total_fees_received: 0,
+            total_bad_debt_covered: 0,
+            total_bad_debt_socialized: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_settle_covers_bad_debt_before_socializing() {
+        let mut fund = fund();
+
+        let settlement = fund.settle(10, 0, 0).unwrap();
+        assert_eq!(settlement, InsuranceSettlement { fee_received: 10, ..Default::default() });
+
+        // Fully covered from the vault
+        let settlement = fund.settle(0, 40, 100).unwrap();
+        assert_eq!(settlement.bad_debt_covered, 40);
+        assert_eq!(settlement.bad_debt_socialized, 0);
+
+        // Vault runs dry: the remainder is socialized
+        let settlement = fund.settle(0, 100, 60).unwrap();
+        assert_eq!(settlement.bad_debt_covered, 60);
+        assert_eq!(settlement.bad_debt_socialized, 40);
+
+        assert_eq!(fund.total_fees_received, 10);
+        assert_eq!(fund.total_bad_debt_covered, 100);
+        assert_eq!(fund.total_bad_debt_socialized, 40);
+    }
+}
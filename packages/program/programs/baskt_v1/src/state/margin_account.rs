@@ -0,0 +1,172 @@
+use crate::constants::MAX_MARGIN_POSITIONS;
+use crate::error::PerpetualsError;
+use anchor_lang::prelude::*;
+
+//----------------------------------------------------------------------------
+// STATE STRUCTURES: MARGIN ACCOUNT
+//----------------------------------------------------------------------------
+
+/// Cross-margin account: collateral held once and shared by member positions
+/// across baskts. Member positions keep no collateral of their own.
+#[account]
+#[derive(InitSpace)]
+pub struct MarginAccount {
+    pub owner: Pubkey,
+    pub collateral: u64,
+    #[max_len(MAX_MARGIN_POSITIONS)]
+    pub positions: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+/// Valuation of one member position at its baskt mark price
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MarginPositionValue {
+    /// Unrealized PnL plus accrued funding/borrow, net of rebalance fees owed
+    pub equity: i128,
+    /// Notional times the applicable collateral ratio
+    pub required_margin: u64,
+}
+
+/// Aggregate health of a margin account
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MarginHealth {
+    pub total_equity: i128,
+    pub required_margin: u64,
+}
+
+impl MarginHealth {
+    pub fn is_healthy(&self) -> bool {
+        self.total_equity >= self.required_margin as i128
+    }
+}
+
+impl MarginAccount {
+    pub fn initialize(&mut self, owner: Pubkey, bump: u8) {
+        self.owner = owner;
+        self.collateral = 0;
+        self.positions = Vec::new();
+        self.bump = bump;
+    }
+
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, PerpetualsError::InsufficientCollateral);
+        self.collateral = self
+            .collateral
+            .checked_add(amount)
+            .ok_or(PerpetualsError::CollateralOverflow)?;
+        Ok(())
+    }
+
+    pub fn withdraw(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, PerpetualsError::InsufficientCollateral);
+        self.collateral = self
+            .collateral
+            .checked_sub(amount)
+            .ok_or(PerpetualsError::InsufficientCollateral)?;
+        Ok(())
+    }
+
+    pub fn add_position(&mut self, position: Pubkey) -> Result<()> {
+        require!(
+            !self.positions.contains(&position),
+            PerpetualsError::InvalidMarginPositions
+        );
+        require!(
+            self.positions.len() < MAX_MARGIN_POSITIONS,
+            PerpetualsError::MarginAccountFull
+        );
+        self.positions.push(position);
+        Ok(())
+    }
+
+    pub fn remove_position(&mut self, position: Pubkey) -> Result<()> {
+        let index = self
+            .positions
+            .iter()
+            .position(|key| *key == position)
+            .ok_or(PerpetualsError::InvalidMarginPositions)?;
+        self.positions.remove(index);
+        Ok(())
+    }
+
+    /// Total account equity (shared collateral plus every member's equity) against
+    /// the summed margin requirement. `values` must cover every member position.
+    pub fn health(&self, values: &[MarginPositionValue]) -> Result<MarginHealth> {
+        require!(
+            values.len() == self.positions.len(),
+            PerpetualsError::InvalidMarginPositions
+        );
+
+        let mut health = MarginHealth {
+            total_equity: self.collateral as i128,
+            required_margin: 0,
+        };
+        for value in values {
+            health.total_equity = health
+                .total_equity
+                .checked_add(value.equity)
+                .ok_or(PerpetualsError::MathOverflow)?;
+            health.required_margin = health
+                .required_margin
+                .checked_add(value.required_margin)
+                .ok_or(PerpetualsError::MathOverflow)?;
+        }
+        Ok(health)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn margin_account(collateral: u64, members: usize) -> MarginAccount {
+        MarginAccount {
+            owner: Pubkey::default(),
+            collateral,
+            positions: (0..members).map(|_| Pubkey::new_unique()).collect(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_health_nets_hedged_positions() {
+        let account = margin_account(100, 2);
+        // A losing leg offset by a winning leg on another baskt
+        let values = [
+            MarginPositionValue { equity: -500, required_margin: 50 },
+            MarginPositionValue { equity: 480, required_margin: 50 },
+        ];
+        let health = account.health(&values).unwrap();
+        assert_eq!(health.total_equity, 80);
+        assert_eq!(health.required_margin, 100);
+        assert!(!health.is_healthy());
+
+        let values = [
+            MarginPositionValue { equity: -500, required_margin: 40 },
+            MarginPositionValue { equity: 480, required_margin: 40 },
+        ];
+        assert!(account.health(&values).unwrap().is_healthy());
+    }
+
+    #[test]
+    fn test_health_requires_every_member() {
+        let account = margin_account(100, 2);
+        assert!(account.health(&[MarginPositionValue::default()]).is_err());
+    }
+
+    #[test]
+    fn test_position_membership() {
+        let mut account = margin_account(0, 0);
+        let position = Pubkey::new_unique();
+        account.add_position(position).unwrap();
+        assert!(account.add_position(position).is_err());
+        for _ in 1..MAX_MARGIN_POSITIONS {
+            account.add_position(Pubkey::new_unique()).unwrap();
+        }
+        assert!(account.add_position(Pubkey::new_unique()).is_err());
+
+        account.remove_position(position).unwrap();
+        assert!(account.remove_position(position).is_err());
+        assert_eq!(account.positions.len(), MAX_MARGIN_POSITIONS - 1);
+    }
+}
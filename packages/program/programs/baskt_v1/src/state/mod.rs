@@ -1,9 +1,17 @@
+pub mod adl_ranking;
 pub mod asset;
 pub mod baskt;
+pub mod baskt_price;
 pub mod fee_index;
+pub mod insurance_fund;
 pub mod market_indices;
+pub mod nav_history;
 pub mod liquidity;
+pub mod margin_account;
 pub mod order;
+pub mod order_group;
 pub mod position;
+pub mod price_feed;
 pub mod protocol;
+pub mod trading_delegate;
 pub mod withdraw_request;
@@ -0,0 +1,210 @@
+use crate::constants::{BPS_DIVISOR, NAV_HISTORY_CAPACITY};
+use crate::error::PerpetualsError;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct NavObservation {
+    pub timestamp: i64,
+    pub nav: u64, // PRICE_PRECISION
+}
+
+/// Ring buffer of oracle NAV observations for a baskt, written by the `record_nav` crank
+#[account]
+#[derive(InitSpace)]
+pub struct NavHistory {
+    pub baskt: Pubkey,
+    pub head: u16, // Index the next observation is written to once the buffer is full
+    #[max_len(NAV_HISTORY_CAPACITY)]
+    pub observations: Vec<NavObservation>,
+    pub bump: u8,
+}
+
+impl NavHistory {
+    /// Most recent observation, if any
+    pub fn latest(&self) -> Option<&NavObservation> {
+        if self.observations.is_empty() {
+            return None;
+        }
+        let len = self.observations.len();
+        let index = if len < NAV_HISTORY_CAPACITY {
+            len - 1
+        } else {
+            (self.head as usize + len - 1) % len
+        };
+        self.observations.get(index)
+    }
+
+    /// Observations from oldest to newest
+    pub fn chronological(&self) -> impl Iterator<Item = &NavObservation> {
+        let split = if self.observations.len() < NAV_HISTORY_CAPACITY {
+            0
+        } else {
+            self.head as usize
+        };
+        self.observations[split..]
+            .iter()
+            .chain(self.observations[..split].iter())
+    }
+
+    /// Drop every observation, e.g. so moves from before a halt no longer count
+    pub fn clear(&mut self) {
+        self.observations.clear();
+        self.head = 0;
+    }
+
+    /// Append an observation, overwriting the oldest once the buffer is full.
+    /// Observations must be at least `min_interval` seconds apart so a spammed crank
+    /// cannot flush the history.
+    pub fn record(&mut self, timestamp: i64, nav: u64, min_interval: i64) -> Result<()> {
+        if let Some(latest) = self.latest() {
+            require!(
+                timestamp >= latest.timestamp.saturating_add(min_interval),
+                PerpetualsError::NavRecordedTooSoon
+            );
+        }
+
+        let observation = NavObservation { timestamp, nav };
+        if self.observations.len() < NAV_HISTORY_CAPACITY {
+            self.observations.push(observation);
+        } else {
+            self.observations[self.head as usize] = observation;
+            self.head = ((self.head as usize + 1) % NAV_HISTORY_CAPACITY) as u16;
+        }
+        Ok(())
+    }
+
+    /// Largest move of `nav` relative to any observation in `[now - window, now]`,
+    /// in basis points of that observation. Zero when the window holds no observations.
+    pub fn max_move_bps(&self, now: i64, window: i64, nav: u64) -> Result<u64> {
+        let start = now.saturating_sub(window);
+        let mut max_move: u64 = 0;
+        for observation in self.chronological() {
+            if observation.timestamp < start || observation.nav == 0 {
+                continue;
+            }
+            let move_bps = (nav.abs_diff(observation.nav) as u128)
+                .checked_mul(BPS_DIVISOR as u128)
+                .ok_or(PerpetualsError::MathOverflow)?
+                / observation.nav as u128;
+            max_move = max_move.max(u64::try_from(move_bps).unwrap_or(u64::MAX));
+        }
+        Ok(max_move)
+    }
+
+    /// Time-weighted average NAV over `[now - window, now]`.
+    ///
+    /// Each observation is held until the next one (the latest until `now`). The history
+    /// must cover the whole window, i.e. hold an observation at or before its start.
+    pub fn twap(&self, now: i64, window: i64) -> Result<u64> {
+        require!(window > 0, PerpetualsError::InvalidInput);
+        let start = now
+            .checked_sub(window)
+            .ok_or(PerpetualsError::MathOverflow)?;
+
+        let mut observations = self.chronological().peekable();
+        let oldest = observations.peek().ok_or(PerpetualsError::InsufficientNavHistory)?;
+        require!(oldest.timestamp <= start, PerpetualsError::InsufficientNavHistory);
+
+        let mut weighted_sum: u128 = 0;
+        while let Some(observation) = observations.next() {
+            let segment_end = observations.peek().map_or(now, |next| next.timestamp.min(now));
+            let segment_start = observation.timestamp.max(start);
+            if segment_end > segment_start {
+                let duration = (segment_end - segment_start) as u128;
+                weighted_sum = weighted_sum
+                    .checked_add(
+                        (observation.nav as u128)
+                            .checked_mul(duration)
+                            .ok_or(PerpetualsError::MathOverflow)?,
+                    )
+                    .ok_or(PerpetualsError::MathOverflow)?;
+            }
+        }
+
+        u64::try_from(weighted_sum / window as u128).map_err(|_| PerpetualsError::MathOverflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> NavHistory {
+        NavHistory {
+            baskt: Pubkey::new_unique(),
+            head: 0,
+            observations: Vec::new(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_record_enforces_interval() {
+        let mut history = history();
+        history.record(100, 1_000, 10).unwrap();
+        assert!(history.record(109, 1_000, 10).is_err());
+        history.record(110, 1_100, 10).unwrap();
+        assert_eq!(history.latest().unwrap().nav, 1_100);
+    }
+
+    #[test]
+    fn test_ring_buffer_wraps() {
+        let mut history = history();
+        let total = NAV_HISTORY_CAPACITY as i64 + 5;
+        for i in 0..total {
+            history.record(i, i as u64, 1).unwrap();
+        }
+        assert_eq!(history.observations.len(), NAV_HISTORY_CAPACITY);
+        assert_eq!(history.latest().unwrap().timestamp, total - 1);
+
+        let timestamps: Vec<i64> = history.chronological().map(|o| o.timestamp).collect();
+        assert_eq!(timestamps.first(), Some(&5));
+        assert_eq!(timestamps.last(), Some(&(total - 1)));
+        assert!(timestamps.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_twap_is_time_weighted() {
+        let mut history = history();
+        history.record(0, 100, 1).unwrap();
+        history.record(30, 200, 1).unwrap();
+        history.record(90, 400, 1).unwrap();
+
+        // [0, 100]: 100 for 30s, 200 for 60s, 400 for 10s
+        assert_eq!(history.twap(100, 100).unwrap(), (100 * 30 + 200 * 60 + 400 * 10) / 100);
+        // [60, 100]: 200 for 30s, 400 for 10s
+        assert_eq!(history.twap(100, 40).unwrap(), (200 * 30 + 400 * 10) / 40);
+        // A single wick at the end barely moves a long window
+        assert!(history.twap(91, 91).unwrap() < 200);
+    }
+
+    #[test]
+    fn test_max_move_bps() {
+        let mut history = history();
+        assert_eq!(history.max_move_bps(100, 60, 1_000).unwrap(), 0);
+
+        history.record(0, 2_000, 1).unwrap();
+        history.record(50, 1_000, 1).unwrap();
+        history.record(90, 1_100, 1).unwrap();
+
+        // Observation at t=0 is outside the window; 1_200 vs 1_000 is the largest move
+        assert_eq!(history.max_move_bps(100, 60, 1_200).unwrap(), 2_000);
+        // Including t=0: 1_200 vs 2_000 is a 40% drop
+        assert_eq!(history.max_move_bps(100, 100, 1_200).unwrap(), 4_000);
+
+        // A cleared history re-seeded at the new level no longer sees the move
+        history.clear();
+        history.record(100, 1_200, 1).unwrap();
+        assert_eq!(history.max_move_bps(100, 100, 1_200).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_twap_requires_coverage() {
+        let mut history = history();
+        assert!(history.twap(100, 10).is_err());
+        history.record(50, 100, 1).unwrap();
+        assert!(history.twap(100, 60).is_err());
+        assert_eq!(history.twap(100, 50).unwrap(), 100);
+        assert!(history.twap(100, 0).is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::constants::{BPS_DIVISOR, PRICE_PRECISION};
+use crate::constants::{
+    BPS_DIVISOR, MAX_TWAP_TRANCHES, MAX_TWAP_TRANCHE_INTERVAL_SECONDS, MIN_TWAP_TRANCHES,
+    PRICE_PRECISION,
+};
 use crate::error::PerpetualsError;
 use crate::math::checked_as_u64;
 use crate::math::mul_div_u128;
@@ -15,6 +18,7 @@ pub enum OrderAction {
     #[default]
     Open = 0,
     Close = 1,
+    Increase = 2,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default, InitSpace)]
@@ -32,6 +36,30 @@ pub enum OrderType {
     #[default]
     Market = 0,
     Limit = 1,
+    StopLoss = 2,
+    TakeProfit = 3,
+    TrailingStop = 4,
+    Twap = 5,
+}
+
+impl OrderType {
+    /// Trigger orders close an existing position once the price crosses the trigger
+    pub fn is_trigger(&self) -> bool {
+        matches!(
+            self,
+            OrderType::StopLoss | OrderType::TakeProfit | OrderType::TrailingStop
+        )
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default, InitSpace)]
+#[repr(u8)]
+pub enum TriggerDirection {
+    /// Fires once the price is at or above the trigger price
+    #[default]
+    Above = 0,
+    /// Fires once the price is at or below the trigger price
+    Below = 1,
 }
 
 // Open order parameters - required for opening positions
@@ -50,17 +78,104 @@ pub struct CloseOrderParams {
     pub target_position: Pubkey, // Position to close
 }
 
-// Market order parameters - no additional fields needed
+// Increase order parameters - required for adding size to an existing position
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct IncreaseOrderParams {
+    pub notional_value: u64,     // Additional position value in USD
+    pub leverage_bps: u64,       // Leverage in basis points for the added size
+    pub collateral: u64,         // Additional collateral amount
+    pub target_position: Pubkey, // Position to increase
+}
+
+// Market order parameters - no additional fields needed; the optional slippage guard
+// is kept in `Order::slippage_guard`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, InitSpace)]
 pub struct MarketOrderParams {
     // No additional fields for market orders
 }
 
+// Slippage guard of a market order - optional bounds around a reference price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, InitSpace)]
+pub struct SlippageGuard {
+    pub reference_price: u64,   // Price the user saw when placing the order (0 = no guard)
+    pub max_slippage_bps: u64,  // Maximum acceptable slippage in basis points
+}
+
 // Limit order parameters - required for limit orders
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
 pub struct LimitOrderParams {
-    pub limit_price: u64,       // User-specified limit price
-    pub max_slippage_bps: u64,  // Maximum acceptable slippage in basis points
+    pub limit_price: u64,       // Worst acceptable price for the trade direction
+    pub deprecated_max_slippage_bps: u64, // Unused since limits became directional; always 0 for new orders
+}
+
+impl LimitOrderParams {
+    pub fn new(limit_price: u64) -> Self {
+        Self {
+            limit_price,
+            deprecated_max_slippage_bps: 0,
+        }
+    }
+}
+
+// Trigger order parameters - required for stop-loss and take-profit orders
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct TriggerOrderParams {
+    pub trigger_price: u64,                 // Price that arms the order
+    pub trigger_direction: TriggerDirection, // Side of the trigger price that fills
+}
+
+impl TriggerOrderParams {
+    pub fn is_triggered(&self, price: u64) -> bool {
+        match self.trigger_direction {
+            TriggerDirection::Above => price >= self.trigger_price,
+            TriggerDirection::Below => price <= self.trigger_price,
+        }
+    }
+}
+
+// Trailing stop parameters - the watermark is only moved by the on-chain crank
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct TrailingStopParams {
+    pub trail_bps: u64,                      // Retracement from the watermark that fills the order
+    pub watermark: u64,                      // Best oracle price seen: high for longs, low for shorts
+    pub trigger_direction: TriggerDirection, // Below for long positions, Above for shorts
+}
+
+impl TrailingStopParams {
+    /// Price the market must cross for the order to fill
+    pub fn stop_price(&self) -> Result<u64> {
+        let trail = mul_div_u64(self.watermark, self.trail_bps, BPS_DIVISOR)?;
+        match self.trigger_direction {
+            TriggerDirection::Below => Ok(self.watermark.saturating_sub(trail)),
+            TriggerDirection::Above => self
+                .watermark
+                .checked_add(trail)
+                .ok_or(PerpetualsError::MathOverflow.into()),
+        }
+    }
+
+    /// Ratchet the watermark toward `price`; returns whether it moved
+    pub fn update_watermark(&mut self, price: u64) -> bool {
+        let improved = match self.trigger_direction {
+            TriggerDirection::Below => price > self.watermark,
+            TriggerDirection::Above => price < self.watermark,
+        };
+        if improved {
+            self.watermark = price;
+        }
+        improved
+    }
+}
+
+// TWAP parent order parameters - open orders filled in equal tranches over time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, InitSpace)]
+pub struct TwapOrderParams {
+    pub tranche_count: u16,          // Number of tranches the order is split into
+    pub min_interval_seconds: u32,   // Minimum time between tranche fills
+    pub tranches_filled: u16,        // Tranches filled so far
+    pub last_fill_time: i64,         // Timestamp of the latest tranche fill
+    pub notional_filled: u64,        // Notional opened so far
+    pub collateral_used: u64,        // Escrowed collateral consumed so far
 }
 
 // Combined action parameters
@@ -68,6 +183,7 @@ pub struct LimitOrderParams {
 pub enum ActionParams {
     Open(OpenOrderParams),
     Close(CloseOrderParams),
+    Increase(IncreaseOrderParams),
 }
 
 // Combined order type parameters
@@ -75,6 +191,10 @@ pub enum ActionParams {
 pub enum OrderTypeParams {
     Market(MarketOrderParams),
     Limit(LimitOrderParams),
+    StopLoss(TriggerOrderParams),
+    TakeProfit(TriggerOrderParams),
+    TrailingStop(TrailingStopParams),
+    Twap(TwapOrderParams),
 }
 
 #[account]
@@ -84,19 +204,26 @@ pub struct Order {
     pub order_id: u32,                   // Unique identifier (timestamp-based)
     pub baskt_id: Pubkey,                // Reference to basket
 
-    pub action: OrderAction,             // Open or Close
-    pub order_type: OrderType,           // Market or Limit
+    pub action: OrderAction,             // Open, Close or Increase
+    pub order_type: OrderType,           // Market, Limit, StopLoss, TakeProfit, TrailingStop or Twap
     
     // Combined parameters using enums
-    pub action_params: ActionParams,     // Open or Close parameters
-    pub order_type_params: OrderTypeParams, // Market or Limit parameters
+    pub action_params: ActionParams,     // Open, Close or Increase parameters
+    pub order_type_params: OrderTypeParams, // Order type specific parameters
 
     pub status: OrderStatus,             // Pending, Filled, Cancelled
     pub timestamp: u32,                  // Creation timestamp
     pub bump: u8,
+    pub expires_at: Option<i64>,         // Good-til-time expiry (None = good-til-cancelled)
+    pub order_group: Option<Pubkey>,     // One-cancels-other group this order belongs to
+    pub reduce_only: bool,               // Close orders: clamp fills to the remaining position size
+    pub slippage_guard: SlippageGuard,   // Market orders: optional guard around a reference price
 
-    // Extra Space
-    pub extra_space: [u8; 150],
+    // Extra Space - new fields and larger order params are carved from here so the
+    // account keeps its original size. Variants of `ActionParams` and `OrderTypeParams`
+    // that existed at deployment keep their encoding, and new fields are appended here,
+    // so pending orders created before an upgrade decode unchanged.
+    pub extra_space: [u8; 59],
 }
 
 impl Order {
@@ -121,6 +248,15 @@ impl Order {
         Ok(())
     }
 
+    // Step 4: Optional good-til-time expiry, must be after the creation timestamp
+    pub fn init_expiry(&mut self, expires_at: Option<i64>) -> Result<()> {
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > self.timestamp as i64, PerpetualsError::InvalidInput);
+        }
+        self.expires_at = expires_at;
+        Ok(())
+    }
+
     // Step 2a: Initialize as Open order
     pub fn init_open(&mut self, open_params: OpenOrderParams) -> Result<()> {
         require!(
@@ -132,29 +268,112 @@ impl Order {
     }
 
     // Step 2b: Initialize as Close order
-    pub fn init_close(&mut self, close_params: CloseOrderParams) -> Result<()> {
+    pub fn init_close(&mut self, close_params: CloseOrderParams, reduce_only: bool) -> Result<()> {
         require!(
             self.action == OrderAction::Close,
             PerpetualsError::InvalidOrderAction
         );
         self.action_params = ActionParams::Close(close_params);
+        self.reduce_only = reduce_only;
+        Ok(())
+    }
+
+    // Step 2c: Initialize as Increase order
+    pub fn init_increase(&mut self, increase_params: IncreaseOrderParams) -> Result<()> {
+        require!(
+            self.action == OrderAction::Increase,
+            PerpetualsError::InvalidOrderAction
+        );
+        self.action_params = ActionParams::Increase(increase_params);
         Ok(())
     }
 
     // Step 3a: Initialize as Market order
-    pub fn init_market(&mut self) -> Result<()> {
+    pub fn init_market(&mut self, slippage_guard: SlippageGuard) -> Result<()> {
+        require!(
+            slippage_guard.max_slippage_bps <= BPS_DIVISOR,
+            PerpetualsError::InvalidInput
+        );
         self.order_type = OrderType::Market;
         self.order_type_params = OrderTypeParams::Market(MarketOrderParams {});
+        self.slippage_guard = slippage_guard;
         Ok(())
     }
 
     // Step 3b: Initialize as Limit order
     pub fn init_limit(&mut self, limit_params: LimitOrderParams) -> Result<()> {
+        require!(limit_params.limit_price > 0, PerpetualsError::InvalidInput);
         self.order_type = OrderType::Limit;
         self.order_type_params = OrderTypeParams::Limit(limit_params);
         Ok(())
     }
 
+    // Step 3c: Initialize as StopLoss or TakeProfit order (close orders only)
+    pub fn init_trigger(&mut self, order_type: OrderType, trigger_params: TriggerOrderParams) -> Result<()> {
+        require!(
+            self.action == OrderAction::Close,
+            PerpetualsError::InvalidOrderAction
+        );
+        require!(trigger_params.trigger_price > 0, PerpetualsError::InvalidInput);
+        self.order_type_params = match order_type {
+            OrderType::StopLoss => OrderTypeParams::StopLoss(trigger_params),
+            OrderType::TakeProfit => OrderTypeParams::TakeProfit(trigger_params),
+            _ => return Err(PerpetualsError::InvalidInput.into()),
+        };
+        self.order_type = order_type;
+        Ok(())
+    }
+
+    // Step 3d: Initialize as TrailingStop order (close orders only)
+    pub fn init_trailing_stop(&mut self, trail_bps: u64, watermark: u64, position_is_long: bool) -> Result<()> {
+        require!(
+            self.action == OrderAction::Close,
+            PerpetualsError::InvalidOrderAction
+        );
+        require!(
+            trail_bps > 0 && trail_bps < BPS_DIVISOR,
+            PerpetualsError::InvalidInput
+        );
+        require!(watermark > 0, PerpetualsError::InvalidInput);
+
+        self.order_type = OrderType::TrailingStop;
+        self.order_type_params = OrderTypeParams::TrailingStop(TrailingStopParams {
+            trail_bps,
+            watermark,
+            // Long exits fire on a drop from the high, short exits on a rise from the low
+            trigger_direction: if position_is_long {
+                TriggerDirection::Below
+            } else {
+                TriggerDirection::Above
+            },
+        });
+        Ok(())
+    }
+
+    // Step 3e: Initialize as TWAP parent order (open orders only)
+    pub fn init_twap(&mut self, tranche_count: u16, min_interval_seconds: u32) -> Result<()> {
+        require!(
+            self.action == OrderAction::Open,
+            PerpetualsError::InvalidOrderAction
+        );
+        require!(
+            (MIN_TWAP_TRANCHES..=MAX_TWAP_TRANCHES).contains(&tranche_count),
+            PerpetualsError::InvalidInput
+        );
+        require!(
+            min_interval_seconds > 0 && min_interval_seconds <= MAX_TWAP_TRANCHE_INTERVAL_SECONDS,
+            PerpetualsError::InvalidInput
+        );
+
+        self.order_type = OrderType::Twap;
+        self.order_type_params = OrderTypeParams::Twap(TwapOrderParams {
+            tranche_count,
+            min_interval_seconds,
+            ..Default::default()
+        });
+        Ok(())
+    }
+
     // Helper methods to safely access parameters
     pub fn get_open_params(&self) -> Result<&OpenOrderParams> {
         require!(
@@ -178,6 +397,51 @@ impl Order {
         }
     }
 
+    /// Contracts to close for a fill of `requested` (defaults to the order size).
+    /// Fills never exceed the order size; reduce-only orders are clamped to what is left
+    /// of the position, others must fit within it.
+    pub fn close_fill_size(&self, requested: Option<u64>, position_size: u64) -> Result<u64> {
+        let close_params = self.get_close_params()?;
+        let requested = requested.unwrap_or(close_params.size_as_contracts);
+        require!(
+            requested <= close_params.size_as_contracts,
+            PerpetualsError::InvalidPositionSize
+        );
+
+        let size = if self.reduce_only {
+            requested.min(position_size)
+        } else {
+            require!(requested <= position_size, PerpetualsError::InvalidPositionSize);
+            requested
+        };
+        require!(size > 0, PerpetualsError::InvalidPositionSize);
+        Ok(size)
+    }
+
+    pub fn get_increase_params(&self) -> Result<&IncreaseOrderParams> {
+        require!(
+            self.action == OrderAction::Increase,
+            PerpetualsError::InvalidOrderAction
+        );
+        match &self.action_params {
+            ActionParams::Increase(params) => Ok(params),
+            _ => Err(PerpetualsError::InvalidInput.into()),
+        }
+    }
+
+    /// Collateral held in the owner's order escrow for this order.
+    /// For TWAP orders this is the collateral of the unfilled tranches.
+    pub fn escrowed_collateral(&self) -> u64 {
+        match &self.action_params {
+            ActionParams::Open(params) => match &self.order_type_params {
+                OrderTypeParams::Twap(twap) => params.collateral.saturating_sub(twap.collateral_used),
+                _ => params.collateral,
+            },
+            ActionParams::Increase(params) => params.collateral,
+            ActionParams::Close(_) => 0,
+        }
+    }
+
     pub fn get_limit_params(&self) -> Result<&LimitOrderParams> {
         require!(
             self.order_type == OrderType::Limit,
@@ -189,6 +453,175 @@ impl Order {
         }
     }
 
+    pub fn get_slippage_guard(&self) -> Result<&SlippageGuard> {
+        require!(
+            self.order_type == OrderType::Market,
+            PerpetualsError::InvalidInput
+        );
+        match &self.order_type_params {
+            OrderTypeParams::Market(_) => Ok(&self.slippage_guard),
+            _ => Err(PerpetualsError::InvalidInput.into()),
+        }
+    }
+
+    pub fn get_trailing_stop_params(&self) -> Result<&TrailingStopParams> {
+        match &self.order_type_params {
+            OrderTypeParams::TrailingStop(params) => Ok(params),
+            _ => Err(PerpetualsError::InvalidInput.into()),
+        }
+    }
+
+    /// Feed an oracle price to a trailing stop; returns whether the watermark moved
+    pub fn update_trailing_watermark(&mut self, price: u64) -> Result<bool> {
+        require!(price > 0, PerpetualsError::InvalidOraclePrice);
+        match &mut self.order_type_params {
+            OrderTypeParams::TrailingStop(params) => Ok(params.update_watermark(price)),
+            _ => Err(PerpetualsError::InvalidInput.into()),
+        }
+    }
+
+    pub fn get_twap_params(&self) -> Result<&TwapOrderParams> {
+        match &self.order_type_params {
+            OrderTypeParams::Twap(params) => Ok(params),
+            _ => Err(PerpetualsError::InvalidInput.into()),
+        }
+    }
+
+    /// (notional, collateral) the next open fill may use at `now`: the whole order,
+    /// or for TWAP orders one tranche once the interval has elapsed. The last tranche
+    /// takes whatever rounding left over.
+    pub fn next_open_fill(&self, now: i64) -> Result<(u64, u64)> {
+        let open_params = self.get_open_params()?;
+        let twap = match &self.order_type_params {
+            OrderTypeParams::Twap(twap) => twap,
+            _ => return Ok((open_params.notional_value, open_params.collateral)),
+        };
+
+        require!(
+            twap.tranches_filled < twap.tranche_count,
+            PerpetualsError::OrderAlreadyProcessed
+        );
+        if twap.tranches_filled > 0 {
+            let next_fill_time = twap
+                .last_fill_time
+                .checked_add(twap.min_interval_seconds as i64)
+                .ok_or(PerpetualsError::MathOverflow)?;
+            require!(now >= next_fill_time, PerpetualsError::TrancheIntervalNotElapsed);
+        }
+
+        if twap.tranche_count - twap.tranches_filled == 1 {
+            Ok((
+                open_params.notional_value.saturating_sub(twap.notional_filled),
+                open_params.collateral.saturating_sub(twap.collateral_used),
+            ))
+        } else {
+            let tranche_count = twap.tranche_count as u64;
+            Ok((
+                open_params.notional_value / tranche_count,
+                open_params.collateral / tranche_count,
+            ))
+        }
+    }
+
+    /// Record an open fill returned by `next_open_fill`; returns whether the order is done
+    pub fn record_open_fill(&mut self, notional: u64, collateral: u64, now: i64) -> Result<bool> {
+        match &mut self.order_type_params {
+            OrderTypeParams::Twap(twap) => {
+                twap.tranches_filled = twap
+                    .tranches_filled
+                    .checked_add(1)
+                    .ok_or(PerpetualsError::MathOverflow)?;
+                twap.last_fill_time = now;
+                twap.notional_filled = twap
+                    .notional_filled
+                    .checked_add(notional)
+                    .ok_or(PerpetualsError::MathOverflow)?;
+                twap.collateral_used = twap
+                    .collateral_used
+                    .checked_add(collateral)
+                    .ok_or(PerpetualsError::MathOverflow)?;
+                Ok(twap.tranches_filled >= twap.tranche_count)
+            }
+            _ => Ok(true),
+        }
+    }
+
+    pub fn get_trigger_params(&self) -> Result<&TriggerOrderParams> {
+        match &self.order_type_params {
+            OrderTypeParams::StopLoss(params) | OrderTypeParams::TakeProfit(params) => Ok(params),
+            _ => Err(PerpetualsError::InvalidInput.into()),
+        }
+    }
+
+    /// Amend the price terms of a pending order.
+    /// `limit_price` is the limit for limit orders and the guard reference for market orders;
+    /// `max_slippage_bps` only applies to market orders.
+    pub fn amend_price(&mut self, limit_price: Option<u64>, max_slippage_bps: Option<u64>) -> Result<()> {
+        match &mut self.order_type_params {
+            OrderTypeParams::Limit(params) => {
+                require!(max_slippage_bps.is_none(), PerpetualsError::InvalidInput);
+                if let Some(limit_price) = limit_price {
+                    require!(limit_price > 0, PerpetualsError::InvalidInput);
+                    params.limit_price = limit_price;
+                }
+            }
+            OrderTypeParams::Market(_) => {
+                if let Some(limit_price) = limit_price {
+                    self.slippage_guard.reference_price = limit_price;
+                }
+                if let Some(max_slippage_bps) = max_slippage_bps {
+                    require!(max_slippage_bps <= BPS_DIVISOR, PerpetualsError::InvalidInput);
+                    self.slippage_guard.max_slippage_bps = max_slippage_bps;
+                }
+            }
+            OrderTypeParams::StopLoss(_)
+            | OrderTypeParams::TakeProfit(_)
+            | OrderTypeParams::TrailingStop(_)
+            | OrderTypeParams::Twap(_) => {
+                require!(
+                    limit_price.is_none() && max_slippage_bps.is_none(),
+                    PerpetualsError::InvalidInput
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Amend the notional and collateral of a pending open or increase order.
+    /// Returns the (notional, collateral, leverage_bps) the order now carries.
+    pub fn amend_size(&mut self, notional_value: Option<u64>, collateral: Option<u64>) -> Result<(u64, u64, u64)> {
+        // A TWAP order's tranche sizes are fixed once execution starts
+        if let OrderTypeParams::Twap(twap) = &self.order_type_params {
+            require!(
+                twap.tranches_filled == 0 || (notional_value.is_none() && collateral.is_none()),
+                PerpetualsError::OrderAlreadyProcessed
+            );
+        }
+        let (current_notional, current_collateral, leverage_bps) = match &mut self.action_params {
+            ActionParams::Open(params) => (&mut params.notional_value, &mut params.collateral, params.leverage_bps),
+            ActionParams::Increase(params) => (&mut params.notional_value, &mut params.collateral, params.leverage_bps),
+            ActionParams::Close(_) => {
+                require!(
+                    notional_value.is_none() && collateral.is_none(),
+                    PerpetualsError::InvalidOrderAction
+                );
+                return Ok((0, 0, 0));
+            }
+        };
+        if let Some(notional_value) = notional_value {
+            *current_notional = notional_value;
+        }
+        if let Some(collateral) = collateral {
+            *current_collateral = collateral;
+        }
+        Ok((*current_notional, *current_collateral, leverage_bps))
+    }
+
+    /// Whether a good-til-time order can no longer be filled at `now`
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+
     pub fn fill(&mut self) -> Result<()> {
         require!(
             self.status == OrderStatus::Pending,
@@ -207,39 +640,479 @@ impl Order {
         Ok(())
     }
 
-    /// Validate that the provided price is within acceptable slippage bounds
-    pub fn validate_execution_price(&self, execution_price: u64) -> Result<()> {
+    /// Whether filling this order buys the baskt: opening or increasing a long, or closing a short
+    pub fn is_buy(&self, position_is_long: bool) -> bool {
+        match self.action {
+            OrderAction::Open | OrderAction::Increase => position_is_long,
+            OrderAction::Close => !position_is_long,
+        }
+    }
+
+    /// Validate the execution price against the order type.
+    /// `position_is_long` is the direction of the position being opened or closed.
+    /// - Limit: buys fill at or below the limit, sells at or above it
+    /// - Market: within ±max_slippage_bps of the reference price, if one was given
+    /// - StopLoss / TakeProfit: only once the price has crossed the trigger
+    /// - TrailingStop: only once the price has retraced `trail_bps` from the watermark
+    /// - Twap: any price; tranches are paced by time instead
+    pub fn validate_execution_price(&self, execution_price: u64, position_is_long: bool) -> Result<()> {
         require!(execution_price > 0, PerpetualsError::InvalidOraclePrice);
-        
-        if self.order_type == OrderType::Limit {
-            let limit_params = self.get_limit_params()?;
-            
-            // Basic validation
-            require!(limit_params.limit_price > 0, PerpetualsError::InvalidOraclePrice);
-       
-            // Calculate acceptable price bounds
-            let slippage_amount = mul_div_u64(
-                limit_params.limit_price, 
-                limit_params.max_slippage_bps, 
-                BPS_DIVISOR
-            )?;
-
-            let lower_bound = limit_params.limit_price
-                .checked_sub(slippage_amount)
-                .unwrap_or(0);
-            let upper_bound = limit_params.limit_price
-                .checked_add(slippage_amount)
-                .ok_or(PerpetualsError::MathOverflow)?;
 
-            msg!("lower_bound: {}, upper_bound: {}", lower_bound, upper_bound);
-            msg!("execution_price: {}", execution_price);
+        match self.order_type {
+            OrderType::Limit => {
+                let limit_price = self.get_limit_params()?.limit_price;
+                require!(limit_price > 0, PerpetualsError::InvalidOraclePrice);
 
-            // Check if execution price is within bounds
-            require!(
-                execution_price >= lower_bound && execution_price <= upper_bound,
-                PerpetualsError::PriceOutOfBounds
-            );
+                let within_limit = if self.is_buy(position_is_long) {
+                    execution_price <= limit_price
+                } else {
+                    execution_price >= limit_price
+                };
+                require!(within_limit, PerpetualsError::PriceOutOfBounds);
+            }
+            OrderType::Market => {
+                let slippage_guard = self.get_slippage_guard()?;
+                if slippage_guard.reference_price > 0 {
+                    let slippage_amount = mul_div_u64(
+                        slippage_guard.reference_price,
+                        slippage_guard.max_slippage_bps,
+                        BPS_DIVISOR,
+                    )?;
+                    let lower_bound = slippage_guard
+                        .reference_price
+                        .saturating_sub(slippage_amount);
+                    let upper_bound = slippage_guard
+                        .reference_price
+                        .checked_add(slippage_amount)
+                        .ok_or(PerpetualsError::MathOverflow)?;
+
+                    require!(
+                        execution_price >= lower_bound && execution_price <= upper_bound,
+                        PerpetualsError::PriceOutOfBounds
+                    );
+                }
+            }
+            OrderType::StopLoss | OrderType::TakeProfit => {
+                // Trigger orders only fill once the price has crossed the trigger
+                require!(
+                    self.get_trigger_params()?.is_triggered(execution_price),
+                    PerpetualsError::TriggerNotReached
+                );
+            }
+            OrderType::TrailingStop => {
+                let trailing = self.get_trailing_stop_params()?;
+                let stop_price = trailing.stop_price()?;
+                let triggered = match trailing.trigger_direction {
+                    TriggerDirection::Below => execution_price <= stop_price,
+                    TriggerDirection::Above => execution_price >= stop_price,
+                };
+                require!(triggered, PerpetualsError::TriggerNotReached);
+            }
+            OrderType::Twap => {}
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_size_matches_deployed_layout() {
+        assert_eq!(Order::INIT_SPACE, 284);
+    }
+
+    // Borsh encoding of an order written by the first deployed program: the header,
+    // the given params, status, timestamp and bump, then the zeroed extra space
+    fn deployed_order_bytes(action: u8, order_type: u8, params: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&[1; 32]); // owner
+        bytes.extend_from_slice(&7u32.to_le_bytes()); // order_id
+        bytes.extend_from_slice(&[2; 32]); // baskt_id
+        bytes.push(action);
+        bytes.push(order_type);
+        bytes.extend_from_slice(params);
+        bytes.push(0); // status: Pending
+        bytes.extend_from_slice(&1_700_000_000u32.to_le_bytes()); // timestamp
+        bytes.push(254); // bump
+        bytes.resize(Order::INIT_SPACE, 0);
+        bytes
+    }
+
+    #[test]
+    fn test_deployed_orders_decode_unchanged() {
+        // Close order with a limit price and the since-deprecated slippage field
+        let mut params = vec![1];
+        params.extend_from_slice(&500u64.to_le_bytes());
+        params.extend_from_slice(&[3; 32]);
+        params.push(1);
+        params.extend_from_slice(&1_000u64.to_le_bytes());
+        params.extend_from_slice(&50u64.to_le_bytes());
+
+        let order = Order::deserialize(&mut &deployed_order_bytes(1, 1, &params)[..]).unwrap();
+        assert_eq!(order.order_id, 7);
+        assert_eq!(order.action, OrderAction::Close);
+        assert_eq!(order.order_type, OrderType::Limit);
+        let close_params = order.get_close_params().unwrap();
+        assert_eq!(close_params.size_as_contracts, 500);
+        assert_eq!(close_params.target_position, Pubkey::new_from_array([3; 32]));
+        let limit_params = order.get_limit_params().unwrap();
+        assert_eq!(limit_params.limit_price, 1_000);
+        assert_eq!(limit_params.deprecated_max_slippage_bps, 50);
+        assert_eq!(order.status, OrderStatus::Pending);
+        assert_eq!(order.timestamp, 1_700_000_000);
+        assert_eq!(order.bump, 254);
+        assert!(!order.reduce_only);
+        assert_eq!(order.expires_at, None);
+        assert_eq!(order.order_group, None);
+
+        // Open market order
+        let mut params = vec![0];
+        params.extend_from_slice(&10_000u64.to_le_bytes());
+        params.extend_from_slice(&20_000u64.to_le_bytes());
+        params.extend_from_slice(&5_000u64.to_le_bytes());
+        params.push(1);
+        params.push(0);
+
+        let order = Order::deserialize(&mut &deployed_order_bytes(0, 0, &params)[..]).unwrap();
+        assert_eq!(order.action, OrderAction::Open);
+        assert_eq!(order.order_type, OrderType::Market);
+        let open_params = order.get_open_params().unwrap();
+        assert_eq!(open_params.notional_value, 10_000);
+        assert_eq!(open_params.leverage_bps, 20_000);
+        assert_eq!(open_params.collateral, 5_000);
+        assert!(open_params.is_long);
+        assert_eq!(order.bump, 254);
+        assert_eq!(order.get_slippage_guard().unwrap().reference_price, 0);
+        assert!(order.validate_execution_price(1, true).is_ok());
+        assert_eq!(order.expires_at, None);
+    }
+
+    fn order(action: OrderAction) -> Order {
+        Order {
+            owner: Pubkey::default(),
+            order_id: 1,
+            baskt_id: Pubkey::default(),
+            action,
+            order_type: OrderType::Market,
+            action_params: match action {
+                OrderAction::Open => ActionParams::Open(OpenOrderParams {
+                    notional_value: 1,
+                    leverage_bps: BPS_DIVISOR,
+                    collateral: 1,
+                    is_long: true,
+                }),
+                OrderAction::Close => ActionParams::Close(CloseOrderParams {
+                    size_as_contracts: 1,
+                    target_position: Pubkey::default(),
+                }),
+                OrderAction::Increase => ActionParams::Increase(IncreaseOrderParams {
+                    notional_value: 1,
+                    leverage_bps: BPS_DIVISOR,
+                    collateral: 1,
+                    target_position: Pubkey::default(),
+                }),
+            },
+            order_type_params: OrderTypeParams::Market(MarketOrderParams::default()),
+            status: OrderStatus::Pending,
+            timestamp: 0,
+            bump: 0,
+            expires_at: None,
+            order_group: None,
+            reduce_only: false,
+            slippage_guard: SlippageGuard::default(),
+            extra_space: [0; 59],
+        }
+    }
+
+    fn limit_order(action: OrderAction, limit_price: u64) -> Order {
+        let mut order = order(action);
+        order.init_limit(LimitOrderParams::new(limit_price)).unwrap();
+        order
+    }
+
+    fn trigger_order(order_type: OrderType, trigger_price: u64, trigger_direction: TriggerDirection) -> Order {
+        let mut order = order(OrderAction::Close);
+        order
+            .init_trigger(order_type, TriggerOrderParams { trigger_price, trigger_direction })
+            .unwrap();
+        order
+    }
+
+    #[test]
+    fn test_limit_open_long_fills_at_or_below_limit() {
+        let order = limit_order(OrderAction::Open, 1_000);
+        assert!(order.validate_execution_price(900, true).is_ok());
+        assert!(order.validate_execution_price(1_000, true).is_ok());
+        assert!(order.validate_execution_price(1_001, true).is_err());
+    }
+
+    #[test]
+    fn test_limit_open_short_fills_at_or_above_limit() {
+        let order = limit_order(OrderAction::Open, 1_000);
+        assert!(order.validate_execution_price(1_100, false).is_ok());
+        assert!(order.validate_execution_price(1_000, false).is_ok());
+        assert!(order.validate_execution_price(999, false).is_err());
+    }
+
+    #[test]
+    fn test_limit_close_long_fills_at_or_above_limit() {
+        let order = limit_order(OrderAction::Close, 1_000);
+        assert!(order.validate_execution_price(1_100, true).is_ok());
+        assert!(order.validate_execution_price(1_000, true).is_ok());
+        assert!(order.validate_execution_price(999, true).is_err());
+    }
+
+    #[test]
+    fn test_limit_close_short_fills_at_or_below_limit() {
+        let order = limit_order(OrderAction::Close, 1_000);
+        assert!(order.validate_execution_price(900, false).is_ok());
+        assert!(order.validate_execution_price(1_000, false).is_ok());
+        assert!(order.validate_execution_price(1_001, false).is_err());
+    }
+
+    #[test]
+    fn test_limit_increase_follows_position_side() {
+        let order = limit_order(OrderAction::Increase, 1_000);
+        // Increasing a long buys, increasing a short sells
+        assert!(order.validate_execution_price(1_000, true).is_ok());
+        assert!(order.validate_execution_price(1_001, true).is_err());
+        assert!(order.validate_execution_price(1_000, false).is_ok());
+        assert!(order.validate_execution_price(999, false).is_err());
+    }
+
+    #[test]
+    fn test_market_slippage_guard_is_optional() {
+        for action in [OrderAction::Open, OrderAction::Close] {
+            for is_long in [true, false] {
+                // No reference price: any positive price fills
+                let unguarded = order(action);
+                assert!(unguarded.validate_execution_price(1, is_long).is_ok());
+                assert!(unguarded.validate_execution_price(u64::MAX, is_long).is_ok());
+                assert!(unguarded.validate_execution_price(0, is_long).is_err());
+
+                // 1% band around the reference price, regardless of side
+                let mut guarded = order(action);
+                guarded
+                    .init_market(SlippageGuard { reference_price: 1_000, max_slippage_bps: 100 })
+                    .unwrap();
+                assert!(guarded.validate_execution_price(990, is_long).is_ok());
+                assert!(guarded.validate_execution_price(1_010, is_long).is_ok());
+                assert!(guarded.validate_execution_price(989, is_long).is_err());
+                assert!(guarded.validate_execution_price(1_011, is_long).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_stop_loss_fills_only_past_trigger() {
+        // Stop-loss on a long: exit once the price falls to the trigger
+        let order = trigger_order(OrderType::StopLoss, 900, TriggerDirection::Below);
+        assert!(order.validate_execution_price(901, true).is_err());
+        assert!(order.validate_execution_price(900, true).is_ok());
+        assert!(order.validate_execution_price(850, true).is_ok());
+    }
+
+    #[test]
+    fn test_take_profit_fills_only_past_trigger() {
+        // Take-profit on a long: exit once the price rises to the trigger
+        let order = trigger_order(OrderType::TakeProfit, 1_100, TriggerDirection::Above);
+        assert!(order.validate_execution_price(1_099, true).is_err());
+        assert!(order.validate_execution_price(1_100, true).is_ok());
+        assert!(order.validate_execution_price(1_200, true).is_ok());
+    }
+
+    fn close_order(size_as_contracts: u64, reduce_only: bool) -> Order {
+        let mut order = order(OrderAction::Close);
+        order
+            .init_close(
+                CloseOrderParams {
+                    size_as_contracts,
+                    target_position: Pubkey::default(),
+                },
+                reduce_only,
+            )
+            .unwrap();
+        order
+    }
+
+    #[test]
+    fn test_close_fill_size() {
+        let order = close_order(100, false);
+        assert_eq!(order.close_fill_size(None, 150).unwrap(), 100);
+        assert_eq!(order.close_fill_size(Some(40), 150).unwrap(), 40);
+        // Never more than the order size
+        assert!(order.close_fill_size(Some(101), 150).is_err());
+        // Position shrank below the order size
+        assert!(order.close_fill_size(None, 60).is_err());
+    }
+
+    #[test]
+    fn test_reduce_only_close_clamps_to_position() {
+        let order = close_order(100, true);
+        assert_eq!(order.close_fill_size(None, 60).unwrap(), 60);
+        assert_eq!(order.close_fill_size(Some(40), 60).unwrap(), 40);
+        assert!(order.close_fill_size(Some(101), 150).is_err());
+        assert!(order.close_fill_size(None, 0).is_err());
+    }
+
+    #[test]
+    fn test_amend_price_by_order_type() {
+        let mut limit = limit_order(OrderAction::Open, 1_000);
+        limit.amend_price(Some(1_200), None).unwrap();
+        assert_eq!(limit.get_limit_params().unwrap().limit_price, 1_200);
+        // Limit orders have no slippage band
+        assert!(limit.amend_price(None, Some(50)).is_err());
+        assert!(limit.amend_price(Some(0), None).is_err());
+
+        let mut market = order(OrderAction::Open);
+        market.amend_price(Some(1_000), Some(100)).unwrap();
+        let slippage_guard = market.get_slippage_guard().unwrap();
+        assert_eq!(slippage_guard.reference_price, 1_000);
+        assert_eq!(slippage_guard.max_slippage_bps, 100);
+        assert!(market.amend_price(None, Some(BPS_DIVISOR + 1)).is_err());
+
+        let mut stop = trigger_order(OrderType::StopLoss, 900, TriggerDirection::Below);
+        assert!(stop.amend_price(Some(800), None).is_err());
+    }
+
+    #[test]
+    fn test_amend_size_only_for_collateralized_orders() {
+        let mut open = order(OrderAction::Open);
+        assert_eq!(open.amend_size(Some(500), None).unwrap(), (500, 1, BPS_DIVISOR));
+        assert_eq!(open.amend_size(None, Some(50)).unwrap(), (500, 50, BPS_DIVISOR));
+        assert_eq!(open.escrowed_collateral(), 50);
+
+        let mut increase = order(OrderAction::Increase);
+        assert_eq!(increase.amend_size(Some(7), Some(3)).unwrap(), (7, 3, BPS_DIVISOR));
+
+        let mut close = close_order(100, false);
+        assert!(close.amend_size(Some(7), None).is_err());
+        assert!(close.amend_size(None, None).is_ok());
+    }
+
+    #[test]
+    fn test_order_expiry() {
+        let mut order = order(OrderAction::Open);
+        order.timestamp = 100;
+
+        order.init_expiry(None).unwrap();
+        assert!(!order.is_expired(i64::MAX));
+
+        order.init_expiry(Some(200)).unwrap();
+        assert!(!order.is_expired(199));
+        assert!(order.is_expired(200));
+
+        // Expiry must be after creation
+        assert!(order.init_expiry(Some(100)).is_err());
+    }
+
+    fn trailing_stop(trail_bps: u64, watermark: u64, position_is_long: bool) -> Order {
+        let mut order = order(OrderAction::Close);
+        order.init_trailing_stop(trail_bps, watermark, position_is_long).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_trailing_stop_long_tracks_high() {
+        // 10% trail on a long starting at 1_000
+        let mut order = trailing_stop(1_000, 1_000, true);
+        assert!(order.validate_execution_price(901, true).is_err());
+        assert!(order.validate_execution_price(900, true).is_ok());
+
+        // Watermark only ratchets up
+        assert!(order.update_trailing_watermark(1_200).unwrap());
+        assert!(!order.update_trailing_watermark(1_100).unwrap());
+        assert_eq!(order.get_trailing_stop_params().unwrap().watermark, 1_200);
+        assert!(order.validate_execution_price(1_081, true).is_err());
+        assert!(order.validate_execution_price(1_080, true).is_ok());
+    }
+
+    #[test]
+    fn test_trailing_stop_short_tracks_low() {
+        let mut order = trailing_stop(1_000, 1_000, false);
+        assert!(order.validate_execution_price(1_099, false).is_err());
+        assert!(order.validate_execution_price(1_100, false).is_ok());
+
+        // Watermark only ratchets down
+        assert!(order.update_trailing_watermark(800).unwrap());
+        assert!(!order.update_trailing_watermark(900).unwrap());
+        assert!(order.validate_execution_price(879, false).is_err());
+        assert!(order.validate_execution_price(880, false).is_ok());
+    }
+
+    #[test]
+    fn test_trailing_stop_validation() {
+        let mut open = order(OrderAction::Open);
+        assert!(open.init_trailing_stop(100, 1_000, true).is_err());
+
+        let mut close = order(OrderAction::Close);
+        assert!(close.init_trailing_stop(0, 1_000, true).is_err());
+        assert!(close.init_trailing_stop(BPS_DIVISOR, 1_000, true).is_err());
+        assert!(close.init_trailing_stop(100, 0, true).is_err());
+        assert!(close.update_trailing_watermark(1_000).is_err());
+    }
+
+    fn twap_order(notional_value: u64, collateral: u64, tranche_count: u16, interval: u32) -> Order {
+        let mut order = order(OrderAction::Open);
+        order.action_params = ActionParams::Open(OpenOrderParams {
+            notional_value,
+            leverage_bps: BPS_DIVISOR,
+            collateral,
+            is_long: true,
+        });
+        order.init_twap(tranche_count, interval).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_twap_tranches_paced_and_remainder_last() {
+        let mut order = twap_order(1_000, 100, 3, 60);
+
+        assert_eq!(order.next_open_fill(0).unwrap(), (333, 33));
+        assert!(!order.record_open_fill(333, 33, 0).unwrap());
+        assert_eq!(order.escrowed_collateral(), 67);
+
+        // Too early for the next tranche
+        assert!(order.next_open_fill(59).is_err());
+        assert_eq!(order.next_open_fill(60).unwrap(), (333, 33));
+        assert!(!order.record_open_fill(333, 33, 60).unwrap());
+
+        // Last tranche picks up the rounding remainder
+        assert_eq!(order.next_open_fill(120).unwrap(), (334, 34));
+        assert!(order.record_open_fill(334, 34, 120).unwrap());
+        assert_eq!(order.escrowed_collateral(), 0);
+        assert!(order.next_open_fill(i64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_twap_size_locked_after_first_tranche() {
+        let mut order = twap_order(1_000, 100, 2, 60);
+        order.amend_size(Some(2_000), Some(200)).unwrap();
+
+        let (notional, collateral) = order.next_open_fill(0).unwrap();
+        order.record_open_fill(notional, collateral, 0).unwrap();
+        assert!(order.amend_size(None, Some(300)).is_err());
+        assert!(order.amend_price(Some(1), None).is_err());
+    }
+
+    #[test]
+    fn test_non_twap_fills_whole_order() {
+        let mut order = order(OrderAction::Open);
+        assert_eq!(order.next_open_fill(0).unwrap(), (1, 1));
+        assert!(order.record_open_fill(1, 1, 0).unwrap());
+        assert!(order.init_twap(1, 60).is_err());
+        assert!(order.init_twap(2, 0).is_err());
+    }
+
+    #[test]
+    fn test_trigger_requires_close_action() {
+        let params = TriggerOrderParams { trigger_price: 900, trigger_direction: TriggerDirection::Below };
+        let mut open = order(OrderAction::Open);
+        assert!(open.init_trigger(OrderType::StopLoss, params.clone()).is_err());
+
+        let mut close = order(OrderAction::Close);
+        assert!(close.init_trigger(OrderType::Limit, params).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use crate::constants::{MAX_ORDER_GROUP_SIZE, MIN_ORDER_GROUP_SIZE};
+use crate::error::PerpetualsError;
+use anchor_lang::prelude::*;
+
+//----------------------------------------------------------------------------
+// STATE STRUCTURES: ORDER GROUP
+//----------------------------------------------------------------------------
+
+/// One-cancels-other link between pending close orders of the same owner.
+/// Filling any member cancels the others and closes the group.
+#[account]
+#[derive(InitSpace)]
+pub struct OrderGroup {
+    pub owner: Pubkey,
+    pub group_id: u32,
+    #[max_len(MAX_ORDER_GROUP_SIZE)]
+    pub orders: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl OrderGroup {
+    pub fn initialize(&mut self, owner: Pubkey, group_id: u32, orders: Vec<Pubkey>, bump: u8) -> Result<()> {
+        require!(
+            (MIN_ORDER_GROUP_SIZE..=MAX_ORDER_GROUP_SIZE).contains(&orders.len()),
+            PerpetualsError::InvalidOrderGroup
+        );
+        for (i, order) in orders.iter().enumerate() {
+            require!(!orders[..i].contains(order), PerpetualsError::InvalidOrderGroup);
+        }
+
+        self.owner = owner;
+        self.group_id = group_id;
+        self.orders = orders;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Members other than `filled`, in group order
+    pub fn siblings(&self, filled: Pubkey) -> impl Iterator<Item = &Pubkey> {
+        self.orders.iter().filter(move |order| **order != filled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group() -> OrderGroup {
+        OrderGroup { owner: Pubkey::default(), group_id: 0, orders: vec![], bump: 0 }
+    }
+
+    #[test]
+    fn test_initialize_bounds_and_duplicates() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+
+        assert!(group().initialize(Pubkey::default(), 0, vec![a], 0).is_err());
+        assert!(group().initialize(Pubkey::default(), 0, vec![a, a], 0).is_err());
+        let too_many = (0..=MAX_ORDER_GROUP_SIZE).map(|_| Pubkey::new_unique()).collect();
+        assert!(group().initialize(Pubkey::default(), 0, too_many, 0).is_err());
+
+        let mut ok = group();
+        ok.initialize(Pubkey::default(), 0, vec![a, b], 0).unwrap();
+        assert_eq!(ok.siblings(a).copied().collect::<Vec<_>>(), vec![b]);
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     constants::{FUNDING_PRECISION, PRICE_PRECISION, BPS_DIVISOR},
     error::PerpetualsError,
     math::mul_div_u64,
-    utils::calc_fee,
+    utils::{calc_fee, calc_min_collateral_from_notional},
 };
 use anchor_lang::prelude::*;
 
@@ -39,9 +39,10 @@ pub struct Position {
     pub status: PositionStatus,
     pub timestamp_open: u32,
     pub bump: u8,
+    pub margin_account: Option<Pubkey>, // Cross-margin account holding this position's collateral
 
     // Extra Space
-    pub extra_space: [u8; 120],
+    pub extra_space: [u8; 87],
 }
 
 // Combined exit information
@@ -99,9 +100,15 @@ impl Position {
         self.status = PositionStatus::Open;
         self.timestamp_open = timestamp_open;
         self.bump = bump;
+        self.margin_account = None;
         Ok(())
     }
 
+    /// Whether the position's collateral is held by a margin account
+    pub fn is_cross_margined(&self) -> bool {
+        self.margin_account.is_some()
+    }
+
     /// Add collateral to an existing position
     pub fn add_collateral(&mut self, additional_collateral: u64) -> Result<()> {
         require!(
@@ -126,6 +133,47 @@ impl Position {
         Ok(())
     }
 
+    /// Add size at `price` to an open position; entry price becomes the size-weighted average.
+    /// Market and rebalance-fee indices must be settled at `price` before calling this.
+    pub fn increase(&mut self, additional_size: u64, additional_collateral: u64, price: u64) -> Result<()> {
+        require!(
+            self.status == PositionStatus::Open,
+            PerpetualsError::PositionAlreadyClosed
+        );
+        require!(additional_size > 0, PerpetualsError::ZeroSizedPosition);
+        require!(price > 0, PerpetualsError::InvalidOraclePrice);
+
+        let new_size = self
+            .size
+            .checked_add(additional_size)
+            .ok_or(PerpetualsError::MathOverflow)?;
+
+        // entry = (size * entry + added * price) / (size + added)
+        let weighted_value = (self.size as u128)
+            .checked_mul(self.entry_price as u128)
+            .ok_or(PerpetualsError::MathOverflow)?
+            .checked_add(
+                (additional_size as u128)
+                    .checked_mul(price as u128)
+                    .ok_or(PerpetualsError::MathOverflow)?,
+            )
+            .ok_or(PerpetualsError::MathOverflow)?;
+        let new_entry_price: u64 = weighted_value
+            .checked_div(new_size as u128)
+            .ok_or(PerpetualsError::MathOverflow)?
+            .try_into()
+            .map_err(|_| PerpetualsError::MathOverflow)?;
+
+        self.collateral = self
+            .collateral
+            .checked_add(additional_collateral)
+            .ok_or(PerpetualsError::CollateralOverflow)?;
+        self.size = new_size;
+        self.entry_price = new_entry_price;
+
+        Ok(())
+    }
+
     /// Update both funding and borrow indices for this position
     /// Combines funding and borrow calculations to avoid duplicate notional calculation
     pub fn update_market_indices(
@@ -187,13 +235,35 @@ impl Position {
         Ok(())
     }
 
-    /// Check if position can be liquidated at the given price
+    /// Check if position can be liquidated at the given mark price
     pub fn is_liquidatable(
         &self,
-        current_price: u64,
+        current_price: u64, // Baskt mark price, not the settlement (index) price
         liquidation_threshold_bps: u64,
         current_rebalance_fee_index: u64,
     ) -> Result<bool> {
+        let total_equity = self.calculate_equity(current_price, current_rebalance_fee_index)?;
+
+        // Calculate minimum required collateral based on threshold using current notional value
+
+        let current_notional_u64 = mul_div_u64(self.size, current_price, PRICE_PRECISION)?;
+        let min_collateral = calc_fee(current_notional_u64, liquidation_threshold_bps)? as u128;
+
+        msg!("total_equity: {}", total_equity);
+        msg!("min_collateral: {}", min_collateral);
+
+        // Liquidatable if total equity falls below the maintenance margin
+        // This allows liquidation even when equity is positive but below threshold
+        Ok(total_equity < min_collateral as i128)
+    }
+
+    /// Calculate position equity at a specific price: collateral plus unrealized PnL and
+    /// accrued funding/borrow, net of rebalance fees owed
+    pub fn calculate_equity(
+        &self,
+        current_price: u64,
+        current_rebalance_fee_index: u64,
+    ) -> Result<i128> {
         // Calculate unrealized PnL
         let unrealized_pnl = self.calculate_unrealized_pnl(current_price)?;
 
@@ -212,17 +282,81 @@ impl Position {
             .checked_sub(rebalance_fee_owed as i128)
             .ok_or(PerpetualsError::MathOverflow)?;
 
-        // Calculate minimum required collateral based on threshold using current notional value
+        Ok(total_equity)
+    }
 
-        let current_notional_u64 = mul_div_u64(self.size, current_price, PRICE_PRECISION)?;
-        let min_collateral = calc_fee(current_notional_u64, liquidation_threshold_bps)? as u128;
+    /// Remove collateral from an open position.
+    /// The caller must check the remaining collateral against the margin requirement.
+    pub fn remove_collateral(&mut self, amount: u64) -> Result<()> {
+        require!(
+            self.status == PositionStatus::Open,
+            PerpetualsError::PositionAlreadyClosed
+        );
+        require!(amount > 0, PerpetualsError::InsufficientCollateral);
 
-        msg!("total_equity: {}", total_equity);
-        msg!("min_collateral: {}", min_collateral);
+        self.collateral = self
+            .collateral
+            .checked_sub(amount)
+            .ok_or(PerpetualsError::InsufficientCollateral)?;
 
-        // Liquidatable if total equity falls below the maintenance margin
-        // This allows liquidation even when equity is positive but below threshold
-        Ok(total_equity < min_collateral as i128)
+        Ok(())
+    }
+
+    /// Maximum collateral that can be withdrawn while equity stays at or above
+    /// `min_collateral_ratio_bps` of the current notional.
+    /// Market and rebalance-fee indices must be settled at `current_price` before calling this.
+    pub fn withdrawable_collateral(
+        &self,
+        current_price: u64,
+        current_rebalance_fee_index: u64,
+        min_collateral_ratio_bps: u64,
+    ) -> Result<u64> {
+        let total_equity = self.calculate_equity(current_price, current_rebalance_fee_index)?;
+        let current_notional = mul_div_u64(self.size, current_price, PRICE_PRECISION)?;
+        let min_collateral = calc_min_collateral_from_notional(current_notional, min_collateral_ratio_bps)?;
+
+        let excess_equity = total_equity
+            .checked_sub(min_collateral as i128)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        if excess_equity <= 0 {
+            return Ok(0);
+        }
+
+        // Unrealized profit frees margin, but only collateral held in escrow can leave
+        Ok(std::cmp::min(excess_equity, self.collateral as i128) as u64)
+    }
+
+    /// Auto-deleveraging rank: profit over collateral times notional over equity, in basis
+    /// points, so the most profitable and most leveraged positions rank first. Profit is
+    /// net of accrued funding, borrow and rebalance fees.
+    /// Returns `(score, unrealized_profit)`; both are zero when the position is not in
+    /// profit or holds no collateral of its own.
+    pub fn adl_score(
+        &self,
+        current_price: u64,
+        current_rebalance_fee_index: u64,
+    ) -> Result<(u128, u64)> {
+        let total_equity = self.calculate_equity(current_price, current_rebalance_fee_index)?;
+        if self.collateral == 0 || total_equity <= self.collateral as i128 {
+            return Ok((0, 0));
+        }
+
+        let profit = (total_equity - self.collateral as i128) as u128;
+        let notional = mul_div_u64(self.size, current_price, PRICE_PRECISION)? as u128;
+
+        // pnl ratio (bps) * leverage, with leverage = notional / equity
+        let score = profit
+            .checked_mul(BPS_DIVISOR as u128)
+            .ok_or(PerpetualsError::MathOverflow)?
+            .checked_div(self.collateral as u128)
+            .ok_or(PerpetualsError::MathOverflow)?
+            .checked_mul(notional)
+            .ok_or(PerpetualsError::MathOverflow)?
+            .checked_div(total_equity as u128)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        let profit = u64::try_from(profit).map_err(|_| PerpetualsError::MathOverflow)?;
+
+        Ok((score, profit))
     }
 
     /// Calculate unrealized PnL at a specific price (scaled by token decimals)
@@ -305,3 +439,131 @@ impl Position {
         self.exit_info = exit_info;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_size_matches_deployed_layout() {
+        assert_eq!(Position::INIT_SPACE, 304);
+    }
+
+    fn open_position(size: u64, entry_price: u64, is_long: bool) -> Position {
+        let mut position = Position {
+            owner: Pubkey::default(),
+            position_id: 0,
+            baskt_id: Pubkey::default(),
+            size: 0,
+            collateral: 0,
+            is_long,
+            entry_price: 0,
+            exit_info: ExitInfo::None,
+            last_funding_index: 0,
+            funding_accumulated: 0,
+            last_borrow_index: 0,
+            borrow_accumulated: 0,
+            last_rebalance_fee_index: 0,
+            status: PositionStatus::Open,
+            timestamp_open: 0,
+            bump: 0,
+            margin_account: None,
+            extra_space: [0; 87],
+        };
+        position
+            .initialize(
+                Pubkey::default(),
+                0,
+                Pubkey::default(),
+                size,
+                1_000 * PRICE_PRECISION,
+                is_long,
+                entry_price,
+                0,
+                0,
+                0,
+                0,
+                0,
+            )
+            .unwrap();
+        position
+    }
+
+    #[test]
+    fn test_increase_weights_entry_price_by_size() {
+        let mut position = open_position(PRICE_PRECISION, 100 * PRICE_PRECISION, true);
+        position
+            .increase(3 * PRICE_PRECISION, 500 * PRICE_PRECISION, 200 * PRICE_PRECISION)
+            .unwrap();
+
+        assert_eq!(position.size, 4 * PRICE_PRECISION);
+        assert_eq!(position.collateral, 1_500 * PRICE_PRECISION);
+        // (1 * 100 + 3 * 200) / 4
+        assert_eq!(position.entry_price, 175 * PRICE_PRECISION);
+    }
+
+    #[test]
+    fn test_increase_preserves_unrealized_pnl() {
+        let mut position = open_position(2 * PRICE_PRECISION, 100 * PRICE_PRECISION, false);
+        let price = 90 * PRICE_PRECISION;
+        let pnl_before = position.calculate_unrealized_pnl(price).unwrap();
+
+        position.increase(2 * PRICE_PRECISION, 0, price).unwrap();
+        assert_eq!(position.calculate_unrealized_pnl(price).unwrap(), pnl_before);
+    }
+
+    #[test]
+    fn test_increase_rejects_closed_position() {
+        let mut position = open_position(PRICE_PRECISION, 100 * PRICE_PRECISION, true);
+        position.status = PositionStatus::Closed;
+        assert!(position.increase(PRICE_PRECISION, 0, 100 * PRICE_PRECISION).is_err());
+    }
+
+    #[test]
+    fn test_withdrawable_collateral_keeps_min_ratio_on_current_notional() {
+        // 10 units at 100 = 1_000 notional backed by 1_000 collateral; 10% min ratio
+        let position = open_position(10 * PRICE_PRECISION, 100 * PRICE_PRECISION, true);
+        let withdrawable = |price: u64| position.withdrawable_collateral(price, 0, 1_000).unwrap();
+
+        assert_eq!(withdrawable(100 * PRICE_PRECISION), 900 * PRICE_PRECISION);
+        // Losses reduce equity and notional: 200 equity against 20 required
+        assert_eq!(withdrawable(20 * PRICE_PRECISION), 180 * PRICE_PRECISION);
+        // Profit frees margin but never more than the escrowed collateral
+        assert_eq!(withdrawable(200 * PRICE_PRECISION), 1_000 * PRICE_PRECISION);
+
+        // Below the min ratio there is nothing to withdraw
+        let short = open_position(10 * PRICE_PRECISION, 100 * PRICE_PRECISION, false);
+        assert_eq!(short.withdrawable_collateral(190 * PRICE_PRECISION, 0, 1_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_adl_score_ranks_profit_and_leverage() {
+        // 1 unit long at 100 with 1_000 collateral
+        let position = open_position(PRICE_PRECISION, 100 * PRICE_PRECISION, true);
+
+        // No profit, no score
+        assert_eq!(position.adl_score(100 * PRICE_PRECISION, 0).unwrap(), (0, 0));
+        assert_eq!(position.adl_score(90 * PRICE_PRECISION, 0).unwrap(), (0, 0));
+
+        // At 1_100: profit 1_000 (100% of collateral), notional 1_100, equity 2_000
+        // => 10_000 bps * 0.55 leverage
+        let (score, profit) = position.adl_score(1_100 * PRICE_PRECISION, 0).unwrap();
+        assert_eq!(profit, 1_000 * PRICE_PRECISION);
+        assert_eq!(score, 5_500);
+
+        // Same profit on a larger, more leveraged position ranks higher
+        let larger = open_position(2 * PRICE_PRECISION, 100 * PRICE_PRECISION, true);
+        let (larger_score, _) = larger.adl_score(600 * PRICE_PRECISION, 0).unwrap();
+        assert!(larger_score > score);
+    }
+
+    #[test]
+    fn test_remove_collateral_rejects_overdraw() {
+        let mut position = open_position(PRICE_PRECISION, 100 * PRICE_PRECISION, true);
+        assert!(position.remove_collateral(0).is_err());
+        assert!(position.remove_collateral(1_001 * PRICE_PRECISION).is_err());
+
+        position.remove_collateral(400 * PRICE_PRECISION).unwrap();
+        assert_eq!(position.collateral, 600 * PRICE_PRECISION);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::constants::PRICE_MESSAGE_PREFIX;
+use crate::error::PerpetualsError;
+use anchor_lang::prelude::*;
+
+/// Protocol-owned price for an asset without a Pyth feed.
+/// Written by `Role::Oracle` holders, either directly or via an ed25519-signed message.
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    pub asset: Pubkey,      // SyntheticAsset this feed prices
+    pub price: u64,         // PRICE_PRECISION
+    pub conf: u64,          // Confidence interval, PRICE_PRECISION
+    pub publish_time: i64,  // Unix timestamp the price was observed at
+    pub last_signer: Pubkey, // Oracle key that produced the current price
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    /// Store a new observation. Observations must be strictly newer than the stored one
+    /// (which also makes signed messages non-replayable) and not from the future.
+    pub fn update(
+        &mut self,
+        price: u64,
+        conf: u64,
+        publish_time: i64,
+        signer: Pubkey,
+        now: i64,
+    ) -> Result<()> {
+        require!(price > 0, PerpetualsError::InvalidOraclePrice);
+        require!(publish_time <= now, PerpetualsError::InvalidOraclePrice);
+        require!(
+            publish_time > self.publish_time,
+            PerpetualsError::PriceUpdateOutOfOrder
+        );
+
+        self.price = price;
+        self.conf = conf;
+        self.publish_time = publish_time;
+        self.last_signer = signer;
+        Ok(())
+    }
+
+    /// Bytes an oracle key signs to publish a price off-chain:
+    /// `PRICE_MESSAGE_PREFIX || price_feed || price (u64 LE) || conf (u64 LE) || publish_time (i64 LE)`
+    pub fn signed_message(price_feed: &Pubkey, price: u64, conf: u64, publish_time: i64) -> Vec<u8> {
+        let mut message = Vec::with_capacity(PRICE_MESSAGE_PREFIX.len() + 32 + 24);
+        message.extend_from_slice(PRICE_MESSAGE_PREFIX);
+        message.extend_from_slice(price_feed.as_ref());
+        message.extend_from_slice(&price.to_le_bytes());
+        message.extend_from_slice(&conf.to_le_bytes());
+        message.extend_from_slice(&publish_time.to_le_bytes());
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed() -> PriceFeed {
+        PriceFeed {
+            asset: Pubkey::new_unique(),
+            price: 0,
+            conf: 0,
+            publish_time: 0,
+            last_signer: Pubkey::default(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_update_ordering() {
+        let mut feed = feed();
+        let signer = Pubkey::new_unique();
+
+        assert!(feed.update(1_000_000, 1_000, 100, signer, 100).is_ok());
+        assert_eq!(feed.price, 1_000_000);
+        assert_eq!(feed.last_signer, signer);
+
+        // Replays and older observations are rejected
+        assert!(feed.update(2_000_000, 0, 100, signer, 200).is_err());
+        assert!(feed.update(2_000_000, 0, 99, signer, 200).is_err());
+        // Future observations and zero prices are rejected
+        assert!(feed.update(2_000_000, 0, 201, signer, 200).is_err());
+        assert!(feed.update(0, 0, 150, signer, 200).is_err());
+
+        assert!(feed.update(2_000_000, 0, 150, signer, 200).is_ok());
+        assert_eq!(feed.publish_time, 150);
+    }
+
+    #[test]
+    fn test_signed_message_layout() {
+        let key = Pubkey::new_unique();
+        let message = PriceFeed::signed_message(&key, 1, 2, -3);
+        let prefix = PRICE_MESSAGE_PREFIX.len();
+        assert_eq!(message.len(), prefix + 56);
+        assert_eq!(&message[..prefix], PRICE_MESSAGE_PREFIX);
+        assert_eq!(&message[prefix..prefix + 32], key.as_ref());
+        assert_eq!(&message[prefix + 32..prefix + 40], &1u64.to_le_bytes());
+        assert_eq!(&message[prefix + 40..prefix + 48], &2u64.to_le_bytes());
+        assert_eq!(&message[prefix + 48..], &(-3i64).to_le_bytes());
+    }
+}
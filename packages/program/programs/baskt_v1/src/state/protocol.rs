@@ -23,6 +23,8 @@ pub enum Role {
     ConfigManager,
     /// Keeper role with permission to process the withdrawal queue
     Keeper,
+    /// Oracle role with permission to publish prices to protocol-owned price feeds
+    Oracle,
 }
 
 /// Access control entry for a specific account
@@ -119,6 +121,8 @@ pub struct ProtocolConfig {
     /// Fee split parameters (in basis points)
     pub treasury_cut_bps: u64,
     pub funding_cut_bps: u64,
+    /// Share of closing and liquidation fees routed to the insurance fund
+    pub insurance_cut_bps: u64,
 
     /// Funding parameters
     pub max_funding_rate_bps: u64,
@@ -131,6 +135,18 @@ pub struct ProtocolConfig {
     /// Liquidity parameters
     pub min_liquidity: u64,
 
+    /// Max deviation of a submitted fill price from the oracle NAV (in basis points)
+    pub max_price_deviation_bps: u64,
+
+    /// Partial liquidation parameters (in basis points): margin ratio a partial liquidation
+    /// restores (0 = disabled) and the ratio below which a full liquidation is allowed
+    pub partial_liquidation_target_bps: u64,
+    pub full_liquidation_threshold_bps: u64,
+
+    /// Ranked unrealized profit of a baskt, as a share of effective pool liquidity, above
+    /// which its top-ranked positions may be auto-deleveraged (in basis points, 0 = disabled)
+    pub adl_pnl_to_pool_bps: u64,
+
     /// Rebalance request fee in lamports (SOL)
     pub rebalance_request_fee_lamports: u64,
 
@@ -150,11 +166,16 @@ impl ProtocolConfig {
             liquidation_fee_bps: LIQUIDATION_FEE_BPS,
             treasury_cut_bps: DEFAULT_TREASURY_CUT_BPS,
             funding_cut_bps: DEFAULT_FUNDING_CUT_BPS,
+            insurance_cut_bps: 0, // Default to 0
             max_funding_rate_bps: MAX_FUNDING_RATE_BPS,
             funding_interval_seconds: FUNDING_INTERVAL_SECONDS,
             min_collateral_ratio_bps: MIN_COLLATERAL_RATIO_BPS,
             liquidation_threshold_bps: LIQUIDATION_THRESHOLD_BPS,
             min_liquidity: MIN_LIQUIDITY,
+            max_price_deviation_bps: DEFAULT_PRICE_DEVIATION_BPS,
+            partial_liquidation_target_bps: 0, // Disabled by default
+            full_liquidation_threshold_bps: 0,
+            adl_pnl_to_pool_bps: 0, // Disabled by default
             rebalance_request_fee_lamports: 0, // Default to 0
             baskt_creation_fee_lamports: 0, // Default to 0
             last_updated: 0,
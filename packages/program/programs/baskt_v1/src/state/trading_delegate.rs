@@ -0,0 +1,155 @@
+use crate::error::PerpetualsError;
+use anchor_lang::prelude::*;
+
+//----------------------------------------------------------------------------
+// STATE STRUCTURES: TRADING DELEGATE
+//----------------------------------------------------------------------------
+
+/// Lets `delegate` create and cancel orders and add collateral on behalf of `owner`.
+/// Funds leaving the protocol always go to the owner's accounts.
+/// Terms of a delegation, set by the owner
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TradingDelegateConfig {
+    pub expires_at: i64,
+    pub baskt: Option<Pubkey>,
+    pub max_notional: Option<u64>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TradingDelegate {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub expires_at: i64,           // Delegation is void from this timestamp on
+    pub baskt: Option<Pubkey>,     // Restrict the delegate to a single baskt
+    pub max_notional: Option<u64>, // Cap on the notional of each open or increase order
+    pub bump: u8,
+}
+
+impl TradingDelegate {
+    pub fn configure(
+        &mut self,
+        owner: Pubkey,
+        delegate: Pubkey,
+        config: TradingDelegateConfig,
+        bump: u8,
+        now: i64,
+    ) -> Result<()> {
+        require_keys_neq!(owner, delegate, PerpetualsError::InvalidInput);
+        require!(config.expires_at > now, PerpetualsError::InvalidInput);
+        require!(config.max_notional != Some(0), PerpetualsError::InvalidInput);
+
+        self.owner = owner;
+        self.delegate = delegate;
+        self.expires_at = config.expires_at;
+        self.baskt = config.baskt;
+        self.max_notional = config.max_notional;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Check that the delegate may act on `baskt` for an order of `notional` (0 when
+    /// the action adds no exposure) at `now`
+    pub fn authorize(&self, baskt: Pubkey, notional: u64, now: i64) -> Result<()> {
+        require!(now < self.expires_at, PerpetualsError::TradingDelegateExpired);
+        if let Some(scope) = self.baskt {
+            require_keys_eq!(scope, baskt, PerpetualsError::TradingDelegateScopeViolation);
+        }
+        if let Some(max_notional) = self.max_notional {
+            require!(
+                notional <= max_notional,
+                PerpetualsError::TradingDelegateNotionalExceeded
+            );
+        }
+        Ok(())
+    }
+
+    /// `authority` acts for `owner` if it is the owner, or the delegate of a matching,
+    /// active `TradingDelegate` that covers the action
+    pub fn check_authority(
+        trading_delegate: Option<&TradingDelegate>,
+        owner: Pubkey,
+        authority: Pubkey,
+        baskt: Pubkey,
+        notional: u64,
+        now: i64,
+    ) -> Result<()> {
+        if authority == owner {
+            return Ok(());
+        }
+        let trading_delegate = trading_delegate.ok_or(PerpetualsError::Unauthorized)?;
+        require_keys_eq!(trading_delegate.owner, owner, PerpetualsError::Unauthorized);
+        require_keys_eq!(trading_delegate.delegate, authority, PerpetualsError::Unauthorized);
+        trading_delegate.authorize(baskt, notional, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(expires_at: i64, baskt: Option<Pubkey>, max_notional: Option<u64>) -> TradingDelegateConfig {
+        TradingDelegateConfig { expires_at, baskt, max_notional }
+    }
+
+    fn delegate(baskt: Option<Pubkey>, max_notional: Option<u64>) -> TradingDelegate {
+        let mut trading_delegate = TradingDelegate {
+            owner: Pubkey::default(),
+            delegate: Pubkey::default(),
+            expires_at: 0,
+            baskt: None,
+            max_notional: None,
+            bump: 0,
+        };
+        trading_delegate
+            .configure(Pubkey::new_unique(), Pubkey::new_unique(), config(100, baskt, max_notional), 0, 0)
+            .unwrap();
+        trading_delegate
+    }
+
+    #[test]
+    fn test_owner_needs_no_delegate() {
+        let owner = Pubkey::new_unique();
+        assert!(TradingDelegate::check_authority(None, owner, owner, Pubkey::new_unique(), u64::MAX, 0).is_ok());
+        assert!(TradingDelegate::check_authority(None, owner, Pubkey::new_unique(), Pubkey::new_unique(), 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_delegate_expiry_scope_and_cap() {
+        let baskt = Pubkey::new_unique();
+        let scoped = delegate(Some(baskt), Some(1_000));
+        let (owner, authority) = (scoped.owner, scoped.delegate);
+
+        assert!(TradingDelegate::check_authority(Some(&scoped), owner, authority, baskt, 1_000, 99).is_ok());
+        // Expired
+        assert!(TradingDelegate::check_authority(Some(&scoped), owner, authority, baskt, 0, 100).is_err());
+        // Other baskt
+        assert!(TradingDelegate::check_authority(Some(&scoped), owner, authority, Pubkey::new_unique(), 0, 0).is_err());
+        // Over the cap
+        assert!(TradingDelegate::check_authority(Some(&scoped), owner, authority, baskt, 1_001, 0).is_err());
+        // Someone else's delegation
+        assert!(TradingDelegate::check_authority(Some(&scoped), Pubkey::new_unique(), authority, baskt, 0, 0).is_err());
+
+        let unscoped = delegate(None, None);
+        assert!(TradingDelegate::check_authority(
+            Some(&unscoped),
+            unscoped.owner,
+            unscoped.delegate,
+            Pubkey::new_unique(),
+            u64::MAX,
+            0
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_configure_validation() {
+        let mut trading_delegate = delegate(None, None);
+        let owner = Pubkey::new_unique();
+        assert!(trading_delegate.configure(owner, owner, config(100, None, None), 0, 0).is_err());
+        assert!(trading_delegate.configure(owner, Pubkey::new_unique(), config(0, None, None), 0, 0).is_err());
+        assert!(trading_delegate
+            .configure(owner, Pubkey::new_unique(), config(100, None, Some(0)), 0, 0)
+            .is_err());
+    }
+}
@@ -1,6 +1,10 @@
-use crate::constants::{BPS_DIVISOR, MAX_FEE_BPS, MIN_COLLATERAL_RATIO_BPS};
+use crate::constants::{
+    BPS_DIVISOR, MAX_CIRCUIT_BREAKER_WINDOW_SECONDS, MAX_CONFIGURABLE_PRICE_DEVIATION_BPS,
+    MAX_FEE_BPS, MAX_TWAP_WINDOW_SECONDS, MIN_COLLATERAL_RATIO_BPS,
+};
 use crate::error::PerpetualsError;
-use crate::state::baskt::BasktConfig;
+use crate::state::baskt::{BasktConfig, BasktRiskConfig, CircuitBreakerConfig};
+use crate::state::protocol::ProtocolConfig;
 use crate::utils::validate_bps;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak;
@@ -20,6 +24,43 @@ pub fn validate_baskt_fee_bps(fee_bps: Option<u64>) -> Result<()> {
     Ok(())
 }
 
+/// Validates a baskt max price deviation BPS value (optional)
+/// Used by baskt max price deviation setter
+pub fn validate_baskt_price_deviation_bps(deviation_bps: Option<u64>) -> Result<()> {
+    if let Some(deviation) = deviation_bps {
+        validate_bps(deviation, MAX_CONFIGURABLE_PRICE_DEVIATION_BPS)?;
+    }
+    Ok(())
+}
+
+/// Validates a baskt liquidation TWAP window (optional)
+/// Used by baskt liquidation TWAP window setter
+pub fn validate_baskt_twap_window(window_seconds: Option<u64>) -> Result<()> {
+    if let Some(window) = window_seconds {
+        require!(
+            window > 0 && window <= MAX_TWAP_WINDOW_SECONDS,
+            PerpetualsError::InvalidInput
+        );
+    }
+    Ok(())
+}
+
+/// Validates a baskt circuit breaker (optional)
+/// Used by baskt circuit breaker setter
+pub fn validate_baskt_circuit_breaker(circuit_breaker: Option<CircuitBreakerConfig>) -> Result<()> {
+    if let Some(breaker) = circuit_breaker {
+        require!(
+            breaker.move_bps > 0 && breaker.move_bps <= BPS_DIVISOR,
+            PerpetualsError::InvalidInput
+        );
+        require!(
+            breaker.window_seconds > 0 && breaker.window_seconds <= MAX_CIRCUIT_BREAKER_WINDOW_SECONDS,
+            PerpetualsError::InvalidInput
+        );
+    }
+    Ok(())
+}
+
 /// Validates a baskt min collateral ratio BPS value (optional)
 /// Used by baskt min collateral ratio setter
 pub fn validate_baskt_min_collateral_ratio_bps(
@@ -63,6 +104,46 @@ pub fn validate_baskt_liquidation_threshold_bps(
     Ok(())
 }
 
+/// Validates the partial-liquidation target against the effective liquidation thresholds.
+/// A target of 0 disables partial mode; otherwise it must sit above the liquidation
+/// threshold and no higher than the min collateral ratio, with the full-liquidation
+/// threshold below the liquidation threshold.
+pub fn validate_partial_liquidation_target(
+    target_bps: u64,
+    full_liquidation_threshold_bps: u64,
+    liquidation_threshold_bps: u64,
+    min_collateral_ratio_bps: u64,
+) -> Result<()> {
+    require!(
+        target_bps == 0
+            || (target_bps > liquidation_threshold_bps
+                && target_bps <= min_collateral_ratio_bps
+                && full_liquidation_threshold_bps < liquidation_threshold_bps),
+        PerpetualsError::InvalidCollateralRatio
+    );
+    Ok(())
+}
+
+/// Validates the protocol's partial-liquidation target against the thresholds a baskt
+/// config makes effective. Used by the baskt threshold setters.
+pub fn validate_baskt_partial_liquidation_target(
+    config: &BasktConfig,
+    protocol_config: &ProtocolConfig,
+) -> Result<()> {
+    validate_partial_liquidation_target(
+        protocol_config.partial_liquidation_target_bps,
+        protocol_config.full_liquidation_threshold_bps,
+        effective_u64(
+            config.get_liquidation_threshold_bps(),
+            protocol_config.liquidation_threshold_bps,
+        ),
+        effective_u64(
+            config.get_min_collateral_ratio_bps(),
+            protocol_config.min_collateral_ratio_bps,
+        ),
+    )
+}
+
 /// Validates the baskt-level configuration using the same primitive checks as individual setters.
 /// Used by the bulk update setter.
 pub fn validate_baskt_config(config: &BasktConfig) -> Result<()> {
@@ -85,3 +166,12 @@ pub fn validate_baskt_config(config: &BasktConfig) -> Result<()> {
 
     Ok(())
 }
+
+/// Validates the baskt-level risk overrides. Used by the bulk update setter.
+pub fn validate_baskt_risk_config(config: &BasktRiskConfig) -> Result<()> {
+    validate_baskt_price_deviation_bps(config.get_max_price_deviation_bps())?;
+    validate_baskt_twap_window(config.get_liquidation_twap_window_seconds())?;
+    validate_baskt_circuit_breaker(config.get_circuit_breaker())?;
+
+    Ok(())
+}
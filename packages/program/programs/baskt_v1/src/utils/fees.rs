@@ -37,6 +37,20 @@ pub fn split_fee(amount: u64, treasury_cut_bps: u64) -> Result<(u64, u64)> {
     Ok((to_treasury, to_blp))
 }
 
+/// Split a settlement fee between treasury, insurance fund and BLP.
+/// Both cuts are taken from the full amount; BLP receives the remainder.
+/// Returns (to_treasury, to_insurance, to_blp).
+pub fn split_fee_with_insurance(
+    amount: u64,
+    treasury_cut_bps: u64,
+    insurance_cut_bps: u64,
+) -> Result<(u64, u64, u64)> {
+    let (to_treasury, remainder) = split_fee(amount, treasury_cut_bps)?;
+    let to_insurance = std::cmp::min(calc_fee(amount, insurance_cut_bps)?, remainder);
+    let to_blp = checked_sub(remainder, to_insurance)?;
+    Ok((to_treasury, to_insurance, to_blp))
+}
+
 /// Validate a basis-points value (u16) does not exceed the supplied maximum.
 /// Primarily used in configuration setter instructions.
 pub fn validate_bps(value: u64, max: u64) -> Result<()> {
@@ -91,4 +105,14 @@ mod tests {
         .unwrap();
         assert_eq!(result, 0);
     }
+
+    #[test]
+    fn test_split_fee_with_insurance() {
+        // 10% treasury, 20% insurance, remainder to BLP
+        assert_eq!(split_fee_with_insurance(1_000, 1_000, 2_000).unwrap(), (100, 200, 700));
+        // No insurance cut matches split_fee
+        assert_eq!(split_fee_with_insurance(1_000, 1_000, 0).unwrap(), (100, 0, 900));
+        // Insurance can never take more than is left after the treasury cut
+        assert_eq!(split_fee_with_insurance(1_000, 6_000, 5_000).unwrap(), (600, 400, 0));
+    }
 }
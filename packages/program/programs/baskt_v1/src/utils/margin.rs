@@ -0,0 +1,307 @@
+use crate::constants::{
+    AUTHORITY_SEED, MARGIN_ESCROW_SEED, MAX_MARGIN_PRICE_AGE_SECONDS, PRICE_PRECISION,
+};
+use crate::error::PerpetualsError;
+use crate::math::mul_div_u64;
+use crate::state::baskt::Baskt;
+use crate::state::margin_account::{MarginAccount, MarginPositionValue};
+use crate::state::position::Position;
+use crate::state::protocol::Protocol;
+use crate::utils::{calc_min_collateral_from_notional, effective_u64, split_fee_with_insurance};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Collateral ratio applied to each member position of a margin account
+#[derive(Clone, Copy, PartialEq)]
+pub enum MarginRequirement {
+    /// Effective min collateral ratio; must hold after withdrawals and detaches
+    Initial,
+    /// Effective liquidation threshold; breaching it makes the account liquidatable
+    Maintenance,
+}
+
+/// Value every member position of a margin account at its baskt mark price.
+///
+/// `remaining_accounts` must contain, for each entry of `margin_account.positions` and in
+/// the same order, `accounts_per_member` accounts: the `Position`, its `Baskt`, then any
+/// accounts the caller needs per member. Unsettled funding and borrow are accrued on a
+/// copy of each position; baskt mark prices older than MAX_MARGIN_PRICE_AGE_SECONDS
+/// are rejected.
+pub fn value_margin_positions<'info>(
+    margin_account: &Account<'info, MarginAccount>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    accounts_per_member: usize,
+    protocol: &Protocol,
+    requirement: MarginRequirement,
+    now: i64,
+) -> Result<Vec<MarginPositionValue>> {
+    let member_count = margin_account.positions.len();
+    require!(
+        remaining_accounts.len() >= member_count * accounts_per_member,
+        PerpetualsError::InvalidMarginPositions
+    );
+
+    let mut values = Vec::with_capacity(member_count);
+    for (member, accounts) in margin_account
+        .positions
+        .iter()
+        .zip(remaining_accounts.chunks(accounts_per_member))
+    {
+        require_keys_eq!(accounts[0].key(), *member, PerpetualsError::InvalidMarginPositions);
+        let position = Account::<Position>::try_from(&accounts[0])?;
+        require!(
+            position.margin_account == Some(margin_account.key()),
+            PerpetualsError::InvalidMarginPositions
+        );
+
+        require_keys_eq!(accounts[1].key(), position.baskt_id, PerpetualsError::InvalidBaskt);
+        let baskt = Account::<Baskt>::try_from(&accounts[1])?;
+
+        values.push(value_margin_position(&position, &baskt, protocol, requirement, now)?);
+    }
+
+    Ok(values)
+}
+
+/// Value one position at its baskt mark price, which must be recent
+pub fn value_margin_position(
+    position: &Position,
+    baskt: &Baskt,
+    protocol: &Protocol,
+    requirement: MarginRequirement,
+    now: i64,
+) -> Result<MarginPositionValue> {
+    require!(
+        baskt.mark_price > 0
+            && now.saturating_sub(baskt.price_updated_at) <= MAX_MARGIN_PRICE_AGE_SECONDS,
+        PerpetualsError::StaleBasktPrice
+    );
+
+    let mut settled = position.clone();
+    settled.update_market_indices(
+        baskt.market_indices.cumulative_funding_index,
+        baskt.market_indices.cumulative_borrow_index,
+        baskt.mark_price,
+    )?;
+
+    let collateral_ratio_bps = match requirement {
+        MarginRequirement::Initial => effective_u64(
+            baskt.config.get_min_collateral_ratio_bps(),
+            protocol.config.min_collateral_ratio_bps,
+        ),
+        MarginRequirement::Maintenance => effective_u64(
+            baskt.config.get_liquidation_threshold_bps(),
+            protocol.config.liquidation_threshold_bps,
+        ),
+    };
+    let notional = mul_div_u64(settled.size, baskt.mark_price, PRICE_PRECISION)?;
+
+    Ok(MarginPositionValue {
+        equity: settled.calculate_equity(baskt.mark_price, baskt.rebalance_fee_index.cumulative_index)?,
+        required_margin: calc_min_collateral_from_notional(notional, collateral_ratio_bps)?,
+    })
+}
+
+/// Margin account and shared-collateral escrow backing a cross-margined position while it
+/// settles. The shared collateral is staged into the position escrow so the member settles
+/// like an isolated position, and whatever is left afterwards returns to the account.
+pub struct MarginCollateral<'a, 'info> {
+    pub margin_account: &'a mut Account<'info, MarginAccount>,
+    pub margin_escrow: &'a Account<'info, TokenAccount>,
+}
+
+impl<'a, 'info> MarginCollateral<'a, 'info> {
+    /// Margin accounts for `position`: required when it is cross-margined, rejected otherwise
+    pub fn for_position(
+        position: &Position,
+        margin_account: Option<&'a mut Account<'info, MarginAccount>>,
+        margin_escrow: Option<&'a Account<'info, TokenAccount>>,
+    ) -> Result<Option<Self>> {
+        match (position.margin_account, margin_account, margin_escrow) {
+            (None, None, None) => Ok(None),
+            (Some(key), Some(margin_account), Some(margin_escrow)) => {
+                require_keys_eq!(margin_account.key(), key, PerpetualsError::InvalidMarginPositions);
+                let (escrow_key, _) = Pubkey::find_program_address(
+                    &[MARGIN_ESCROW_SEED, key.as_ref()],
+                    &crate::ID,
+                );
+                require_keys_eq!(
+                    margin_escrow.key(),
+                    escrow_key,
+                    PerpetualsError::InvalidMarginPositions
+                );
+                Ok(Some(Self {
+                    margin_account,
+                    margin_escrow,
+                }))
+            }
+            _ => err!(PerpetualsError::InvalidMarginPositions),
+        }
+    }
+
+    /// Move the whole shared collateral into the position escrow and onto the position
+    pub fn stage(
+        &mut self,
+        token_program: &Program<'info, Token>,
+        position: &mut Position,
+        position_escrow: &Account<'info, TokenAccount>,
+        program_authority: &AccountInfo<'info>,
+        authority_bump: u8,
+    ) -> Result<()> {
+        let collateral = self.margin_account.collateral;
+        self.margin_account.collateral = 0;
+        position.collateral = collateral;
+
+        if collateral > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: self.margin_escrow.to_account_info(),
+                        to: position_escrow.to_account_info(),
+                        authority: program_authority.clone(),
+                    },
+                    &[&[AUTHORITY_SEED, &[authority_bump]]],
+                ),
+                collateral,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Return the collateral left on the position, plus the settlement payout already
+    /// transferred to the margin escrow, to the shared collateral. A fully closed
+    /// position also leaves the account.
+    pub fn unstage(
+        &mut self,
+        token_program: &Program<'info, Token>,
+        position: &mut Account<'info, Position>,
+        position_escrow: &Account<'info, TokenAccount>,
+        program_authority: &AccountInfo<'info>,
+        authority_bump: u8,
+        payout: u64,
+    ) -> Result<()> {
+        let remaining = position.collateral;
+        position.collateral = 0;
+        self.margin_account.collateral = self
+            .margin_account
+            .collateral
+            .checked_add(payout)
+            .and_then(|collateral| collateral.checked_add(remaining))
+            .ok_or(PerpetualsError::CollateralOverflow)?;
+        if position.size == 0 {
+            self.margin_account.remove_position(position.key())?;
+        }
+
+        if remaining > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: position_escrow.to_account_info(),
+                        to: self.margin_escrow.to_account_info(),
+                        authority: program_authority.clone(),
+                    },
+                    &[&[AUTHORITY_SEED, &[authority_bump]]],
+                ),
+                remaining,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// One member position closed by a margin account liquidation
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MarginLiquidationLeg {
+    /// Realized PnL plus settled funding and borrow
+    pub equity: i128,
+    /// Liquidation fee plus rebalance fee owed
+    pub fees: u64,
+}
+
+/// Settlement of a margin account liquidation against its shared collateral
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MarginLiquidationSettlement {
+    /// Shared collateral plus the equity of every leg
+    pub total_equity: i128,
+    pub escrow_to_treasury: u64,
+    pub escrow_to_insurance: u64,
+    pub escrow_to_pool: u64,
+    pub fee_to_blp: u64,
+    /// Loss beyond the shared collateral, absorbed by the pool
+    pub pool_shortfall: u64,
+}
+
+/// Net every leg against the shared collateral. As in an isolated liquidation the owner
+/// receives nothing: collectible fees are split and the rest of the collateral goes to the
+/// pool, while negative account equity is the pool's shortfall.
+pub fn calculate_margin_liquidation_settlement(
+    collateral: u64,
+    legs: &[MarginLiquidationLeg],
+    treasury_cut_bps: u64,
+    insurance_cut_bps: u64,
+) -> Result<MarginLiquidationSettlement> {
+    let mut total_equity = collateral as i128;
+    let mut total_fees: u64 = 0;
+    for leg in legs {
+        total_equity = total_equity
+            .checked_add(leg.equity)
+            .ok_or(PerpetualsError::MathOverflow)?;
+        total_fees = total_fees
+            .checked_add(leg.fees)
+            .ok_or(PerpetualsError::MathOverflow)?;
+    }
+
+    if total_equity <= 0 {
+        return Ok(MarginLiquidationSettlement {
+            total_equity,
+            escrow_to_pool: collateral,
+            pool_shortfall: u64::try_from(total_equity.unsigned_abs()).unwrap_or(u64::MAX),
+            ..Default::default()
+        });
+    }
+
+    let collectible_fee = std::cmp::min(total_fees, u64::try_from(total_equity).unwrap_or(u64::MAX));
+    let (fee_to_treasury, fee_to_insurance, fee_to_blp) =
+        split_fee_with_insurance(collectible_fee, treasury_cut_bps, insurance_cut_bps)?;
+    let escrow_to_treasury = std::cmp::min(fee_to_treasury, collateral);
+    let escrow_to_insurance = std::cmp::min(fee_to_insurance, collateral - escrow_to_treasury);
+
+    Ok(MarginLiquidationSettlement {
+        total_equity,
+        escrow_to_treasury,
+        escrow_to_insurance,
+        escrow_to_pool: collateral - escrow_to_treasury - escrow_to_insurance,
+        fee_to_blp,
+        pool_shortfall: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_margin_liquidation_nets_legs_against_collateral() {
+        // A losing leg partly offset by a winning one leaves 20 of equity for 30 of fees
+        let legs = [
+            MarginLiquidationLeg { equity: -500, fees: 20 },
+            MarginLiquidationLeg { equity: 420, fees: 10 },
+        ];
+        let settlement = calculate_margin_liquidation_settlement(100, &legs, 1_000, 1_000).unwrap();
+        assert_eq!(settlement.total_equity, 20);
+        assert_eq!(settlement.escrow_to_treasury, 2);
+        assert_eq!(settlement.escrow_to_insurance, 2);
+        assert_eq!(settlement.escrow_to_pool, 96);
+        assert_eq!(settlement.pool_shortfall, 0);
+
+        // Underwater accounts hand all collateral to the pool; only the loss beyond it is
+        // a shortfall
+        let legs = [MarginLiquidationLeg { equity: -150, fees: 10 }];
+        let settlement = calculate_margin_liquidation_settlement(100, &legs, 1_000, 1_000).unwrap();
+        assert_eq!(settlement.escrow_to_pool, 100);
+        assert_eq!(settlement.escrow_to_treasury, 0);
+        assert_eq!(settlement.pool_shortfall, 50);
+    }
+}
@@ -1,15 +1,23 @@
 pub mod config;
 
 pub mod fees;
+pub mod margin;
 pub mod macros;
 pub mod position_utils;
 pub mod validation;
 pub mod account;
 pub mod sol_transfer;
+pub mod oracle;
+pub mod price_aggregation;
+pub mod signature;
 
 pub use config::*;
 pub use fees::*;
+pub use margin::*;
 pub use position_utils::*;
 pub use validation::*;
 pub use account::*;
-pub use sol_transfer::*;
\ No newline at end of file
+pub use sol_transfer::*;
+pub use oracle::*;
+pub use price_aggregation::*;
+pub use signature::*;
\ No newline at end of file
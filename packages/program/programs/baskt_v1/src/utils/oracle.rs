@@ -0,0 +1,412 @@
+use crate::constants::{
+    BPS_DIVISOR, MAX_ORACLE_MAX_AGE_SECONDS, MAX_ORACLE_MAX_CONF_BPS, MAX_ORACLE_MAX_SPREAD_BPS,
+    MAX_ORACLE_SOURCES, PRICE_EXPONENT,
+};
+use crate::error::PerpetualsError;
+use crate::math::{checked_div, checked_mul, checked_pow, mul_div_u64};
+use crate::state::asset::{OracleConfig, OracleSource, SyntheticAsset};
+use crate::state::baskt::Baskt;
+use crate::state::nav_history::NavHistory;
+use crate::state::price_feed::PriceFeed;
+use crate::utils::{aggregate_prices, AggregatedPrice};
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::error::GetPriceError;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+/// Load the aggregated price of every asset in the baskt from its oracle sources.
+///
+/// `remaining_accounts` must contain, for each entry of `baskt.current_asset_configs`
+/// and in the same order, the `SyntheticAsset` account followed by one account per
+/// configured source that needs one (see `OracleSource::needs_account`), in the order of
+/// `OracleConfig::sources`: the Pyth `PriceUpdateV2` or the asset's `PriceFeed`.
+/// Prices are scaled to PRICE_PRECISION.
+pub fn load_baskt_oracle_prices<'info>(
+    baskt: &Baskt,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    clock: &Clock,
+) -> Result<Vec<AggregatedPrice>> {
+    let mut accounts = remaining_accounts.iter();
+    let mut prices = Vec::with_capacity(baskt.current_asset_configs.len());
+    for asset_config in baskt.current_asset_configs.iter() {
+        let asset_info = accounts.next().ok_or(PerpetualsError::InvalidOracleAccount)?;
+        require_keys_eq!(
+            asset_info.key(),
+            asset_config.asset_id,
+            PerpetualsError::InvalidAssetAccount
+        );
+        let asset = Account::<SyntheticAsset>::try_from(asset_info)
+            .map_err(|_| PerpetualsError::InvalidAssetAccount)?;
+
+        let mut source_infos = Vec::with_capacity(asset.oracle_config.sources.len());
+        for source in asset.oracle_config.sources.iter() {
+            source_infos.push(match source.needs_account() {
+                true => Some(accounts.next().ok_or(PerpetualsError::InvalidOracleAccount)?),
+                false => None,
+            });
+        }
+
+        prices.push(read_asset_price(&asset, asset_info.key(), &source_infos, clock)?);
+    }
+
+    Ok(prices)
+}
+
+/// Read every configured source of an asset and aggregate the usable ones.
+///
+/// Sources that are stale, too uncertain or report an invalid price are skipped, so a
+/// single feed outage falls back to the remaining sources. Account mismatches are
+/// always fatal. If no source is usable the last source error is returned.
+pub fn read_asset_price<'info>(
+    asset: &SyntheticAsset,
+    asset_key: Pubkey,
+    source_infos: &[Option<&'info AccountInfo<'info>>],
+    clock: &Clock,
+) -> Result<AggregatedPrice> {
+    let policy = &asset.oracle_config;
+    require!(
+        source_infos.len() == policy.sources.len(),
+        PerpetualsError::InvalidOracleAccount
+    );
+
+    let mut usable = Vec::with_capacity(policy.sources.len());
+    let mut last_error = PerpetualsError::InsufficientOracleSources;
+    for (source, info) in policy.sources.iter().zip(source_infos) {
+        let price = match (source, info) {
+            (OracleSource::Pyth { feed_id }, Some(info)) => {
+                read_pyth_price(info, feed_id, policy, clock)
+            }
+            (OracleSource::PriceFeed, Some(info)) => {
+                read_price_feed(info, asset_key, policy, clock)
+            }
+            (OracleSource::Manual, None) => read_manual_price(asset, policy, clock),
+            _ => Err(PerpetualsError::InvalidOracleAccount),
+        };
+        match price {
+            Ok(price) => usable.push(price),
+            Err(
+                e @ (PerpetualsError::StaleOraclePrice
+                | PerpetualsError::OracleConfidenceTooLow
+                | PerpetualsError::InvalidOraclePrice),
+            ) => last_error = e,
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    if usable.is_empty() {
+        return Err(last_error.into());
+    }
+    aggregate_prices(&usable, policy.min_sources, policy.max_spread_bps)
+}
+
+/// Read a fully verified Pyth price for `feed_id`, scaled to PRICE_PRECISION, rejecting
+/// updates that are older or less confident than `policy` allows
+pub fn read_pyth_price<'info>(
+    price_update_info: &'info AccountInfo<'info>,
+    feed_id: &[u8; 32],
+    policy: &OracleConfig,
+    clock: &Clock,
+) -> std::result::Result<u64, PerpetualsError> {
+    let price_update = Account::<PriceUpdateV2>::try_from(price_update_info)
+        .map_err(|_| PerpetualsError::InvalidOracleAccount)?;
+
+    let price = price_update
+        .get_price_no_older_than(clock, policy.max_price_age_seconds, feed_id)
+        .map_err(|e| match e {
+            GetPriceError::PriceTooOld => PerpetualsError::StaleOraclePrice,
+            GetPriceError::MismatchedFeedId => PerpetualsError::OracleFeedMismatch,
+            _ => PerpetualsError::InvalidOraclePrice,
+        })?;
+
+    if price.price <= 0 {
+        return Err(PerpetualsError::InvalidOraclePrice);
+    }
+    validate_price_confidence(price.price as u64, price.conf, policy.max_conf_bps)?;
+
+    let scaled = scale_to_price_precision(price.price as u64, price.exponent)
+        .map_err(|_| PerpetualsError::MathOverflow)?;
+    if scaled == 0 {
+        return Err(PerpetualsError::InvalidOraclePrice);
+    }
+    Ok(scaled)
+}
+
+/// Read a protocol-owned `PriceFeed` for `asset_key`, rejecting observations that are
+/// older or less confident than `policy` allows
+pub fn read_price_feed<'info>(
+    price_feed_info: &'info AccountInfo<'info>,
+    asset_key: Pubkey,
+    policy: &OracleConfig,
+    clock: &Clock,
+) -> std::result::Result<u64, PerpetualsError> {
+    let price_feed = Account::<PriceFeed>::try_from(price_feed_info)
+        .map_err(|_| PerpetualsError::InvalidOracleAccount)?;
+    if price_feed.asset != asset_key {
+        return Err(PerpetualsError::OracleFeedMismatch);
+    }
+    if price_feed.price == 0 {
+        return Err(PerpetualsError::InvalidOraclePrice);
+    }
+
+    let age = clock.unix_timestamp.saturating_sub(price_feed.publish_time);
+    if age < 0 || age as u64 > policy.max_price_age_seconds {
+        return Err(PerpetualsError::StaleOraclePrice);
+    }
+    validate_price_confidence(price_feed.price, price_feed.conf, policy.max_conf_bps)?;
+
+    Ok(price_feed.price)
+}
+
+/// Read the admin-set manual price, subject to the same staleness rule as other sources
+pub fn read_manual_price(
+    asset: &SyntheticAsset,
+    policy: &OracleConfig,
+    clock: &Clock,
+) -> std::result::Result<u64, PerpetualsError> {
+    if asset.manual_price.price == 0 {
+        return Err(PerpetualsError::InvalidOraclePrice);
+    }
+    let age = clock.unix_timestamp.saturating_sub(asset.manual_price.publish_time);
+    if age < 0 || age as u64 > policy.max_price_age_seconds {
+        return Err(PerpetualsError::StaleOraclePrice);
+    }
+    Ok(asset.manual_price.price)
+}
+
+/// Ensure the confidence interval is at most `max_conf_bps` of the price.
+/// Both values share the same exponent so no rescaling is needed.
+pub fn validate_price_confidence(
+    price: u64,
+    conf: u64,
+    max_conf_bps: u64,
+) -> std::result::Result<(), PerpetualsError> {
+    let lhs = (conf as u128) * (BPS_DIVISOR as u128);
+    let rhs = (price as u128) * (max_conf_bps as u128);
+    if lhs > rhs {
+        return Err(PerpetualsError::OracleConfidenceTooLow);
+    }
+    Ok(())
+}
+
+/// Validate an asset oracle policy before it is stored
+pub fn validate_oracle_config(config: &OracleConfig) -> Result<()> {
+    require!(
+        !config.sources.is_empty() && config.sources.len() <= MAX_ORACLE_SOURCES,
+        PerpetualsError::InvalidOracleConfig
+    );
+    require!(
+        config.min_sources > 0 && config.min_sources as usize <= config.sources.len(),
+        PerpetualsError::InvalidOracleConfig
+    );
+    for (i, source) in config.sources.iter().enumerate() {
+        if let OracleSource::Pyth { feed_id } = source {
+            require!(*feed_id != [0u8; 32], PerpetualsError::InvalidOracleConfig);
+        }
+        require!(
+            !config.sources[..i].contains(source),
+            PerpetualsError::InvalidOracleConfig
+        );
+    }
+    require!(
+        config.max_price_age_seconds > 0
+            && config.max_price_age_seconds <= MAX_ORACLE_MAX_AGE_SECONDS,
+        PerpetualsError::InvalidOracleConfig
+    );
+    require!(
+        config.max_conf_bps > 0 && config.max_conf_bps <= MAX_ORACLE_MAX_CONF_BPS,
+        PerpetualsError::InvalidOracleConfig
+    );
+    require!(
+        config.max_spread_bps <= MAX_ORACLE_MAX_SPREAD_BPS,
+        PerpetualsError::InvalidOracleConfig
+    );
+    Ok(())
+}
+
+/// Rescale a `value * 10^exponent` quantity to PRICE_PRECISION
+pub fn scale_to_price_precision(value: u64, exponent: i32) -> Result<u64> {
+    let shift = exponent
+        .checked_sub(PRICE_EXPONENT)
+        .ok_or(PerpetualsError::MathOverflow)?;
+    if shift >= 0 {
+        checked_mul(value, checked_pow(10, shift as u32)?)
+    } else {
+        checked_div(value, checked_pow(10, shift.unsigned_abs())?)
+    }
+}
+
+/// Ensure a submitted price is within `max_deviation_bps` of the oracle reference price
+pub fn validate_price_deviation(
+    submitted_price: u64,
+    oracle_price: u64,
+    max_deviation_bps: u64,
+) -> Result<()> {
+    require!(oracle_price > 0, PerpetualsError::InvalidOraclePrice);
+    let max_deviation = mul_div_u64(oracle_price, max_deviation_bps, BPS_DIVISOR)?;
+    require!(
+        submitted_price.abs_diff(oracle_price) <= max_deviation,
+        PerpetualsError::PriceDeviationTooHigh
+    );
+    Ok(())
+}
+
+/// Baskt NAV implied by the aggregated oracle prices
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OracleNav {
+    /// NAV (PRICE_PRECISION)
+    pub nav: u64,
+    /// Confidence interval (PRICE_PRECISION): NAV times the weight-averaged
+    /// cross-source spread of the baskt's assets
+    pub conf: u64,
+    /// Whether the sources of any asset disagree beyond their configured spread
+    pub disagreement: bool,
+}
+
+/// Compute the baskt NAV from aggregated oracle prices
+pub fn load_baskt_oracle_nav<'info>(
+    baskt: &Baskt,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    clock: &Clock,
+) -> Result<OracleNav> {
+    let aggregated = load_baskt_oracle_prices(baskt, remaining_accounts, clock)?;
+    let prices: Vec<u64> = aggregated.iter().map(|p| p.price).collect();
+    let nav = baskt.compute_nav(&prices)?;
+
+    let mut weighted_spread: u64 = 0;
+    for (price, config) in aggregated.iter().zip(baskt.current_asset_configs.iter()) {
+        weighted_spread = weighted_spread
+            .checked_add(mul_div_u64(price.spread_bps, config.weight, BPS_DIVISOR)?)
+            .ok_or(PerpetualsError::MathOverflow)?;
+    }
+
+    Ok(OracleNav {
+        nav,
+        conf: mul_div_u64(nav, weighted_spread, BPS_DIVISOR)?,
+        disagreement: aggregated.iter().any(|p| p.disagreement),
+    })
+}
+
+/// Load oracle prices for the baskt and check `submitted_price` against the implied NAV.
+/// `max_deviation_bps` is the effective baskt/protocol `max_price_deviation_bps`.
+pub fn verify_baskt_price<'info>(
+    baskt: &Baskt,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    submitted_price: u64,
+    max_deviation_bps: u64,
+) -> Result<OracleNav> {
+    let clock = Clock::get()?;
+    let oracle_nav = load_baskt_oracle_nav(baskt, remaining_accounts, &clock)?;
+    validate_price_deviation(submitted_price, oracle_nav.nav, max_deviation_bps)?;
+    Ok(oracle_nav)
+}
+
+/// Refuse to act on a NAV that moved more than the baskt's circuit breaker allows over its
+/// window. Only `record_nav` halts the baskt; every other handler reading the NAV rejects
+/// the move instead, as a failed transaction cannot persist the halt.
+pub fn check_circuit_breaker(
+    baskt: &Baskt,
+    nav_history: Option<&NavHistory>,
+    now: i64,
+    nav: u64,
+) -> Result<()> {
+    if let Some(breaker) = baskt.risk_config.get_circuit_breaker() {
+        let nav_history = nav_history.ok_or(PerpetualsError::NavHistoryRequired)?;
+        let move_bps = nav_history.max_move_bps(now, breaker.window_seconds as i64, nav)?;
+        require!(
+            move_bps <= breaker.move_bps,
+            PerpetualsError::CircuitBreakerTripped
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_to_price_precision() {
+        // Pyth prices typically use exponent -8
+        assert_eq!(scale_to_price_precision(6_512_345_678, -8).unwrap(), 65_123_456);
+        // Same exponent as PRICE_PRECISION is a no-op
+        assert_eq!(scale_to_price_precision(1_000_000, -6).unwrap(), 1_000_000);
+        // Coarser exponents are scaled up
+        assert_eq!(scale_to_price_precision(42, -2).unwrap(), 420_000);
+        // Overflow is reported instead of wrapping
+        assert!(scale_to_price_precision(u64::MAX, 0).is_err());
+    }
+
+    #[test]
+    fn test_validate_price_confidence() {
+        // 2% limit on a 100.00 price (exponent -2)
+        assert!(validate_price_confidence(10_000, 200, 200).is_ok());
+        assert!(validate_price_confidence(10_000, 0, 200).is_ok());
+        assert!(matches!(
+            validate_price_confidence(10_000, 201, 200),
+            Err(PerpetualsError::OracleConfidenceTooLow)
+        ));
+        // No overflow for extreme values
+        assert!(validate_price_confidence(u64::MAX, u64::MAX, MAX_ORACLE_MAX_CONF_BPS).is_err());
+    }
+
+    #[test]
+    fn test_validate_oracle_config() {
+        let pyth = |b: u8| OracleSource::Pyth { feed_id: [b; 32] };
+        let with_sources = |sources: Vec<OracleSource>| OracleConfig {
+            sources,
+            ..OracleConfig::default()
+        };
+
+        assert!(validate_oracle_config(&with_sources(vec![pyth(1)])).is_ok());
+        assert!(validate_oracle_config(&with_sources(vec![
+            pyth(1),
+            pyth(2),
+            OracleSource::PriceFeed,
+            OracleSource::Manual,
+        ]))
+        .is_ok());
+
+        // Source list must be non-empty, bounded, duplicate-free and have real feed ids
+        assert!(validate_oracle_config(&with_sources(vec![])).is_err());
+        assert!(validate_oracle_config(&with_sources(vec![pyth(1), pyth(1)])).is_err());
+        assert!(validate_oracle_config(&with_sources(vec![pyth(0)])).is_err());
+        assert!(validate_oracle_config(&with_sources(vec![
+            pyth(1),
+            pyth(2),
+            pyth(3),
+            OracleSource::PriceFeed,
+            OracleSource::Manual,
+        ]))
+        .is_err());
+
+        let mut config = with_sources(vec![pyth(1)]);
+        config.min_sources = 2;
+        assert!(validate_oracle_config(&config).is_err());
+        config.min_sources = 0;
+        assert!(validate_oracle_config(&config).is_err());
+
+        let mut config = with_sources(vec![pyth(1)]);
+        config.max_price_age_seconds = 0;
+        assert!(validate_oracle_config(&config).is_err());
+        config.max_price_age_seconds = MAX_ORACLE_MAX_AGE_SECONDS + 1;
+        assert!(validate_oracle_config(&config).is_err());
+
+        let mut config = with_sources(vec![pyth(1)]);
+        config.max_conf_bps = MAX_ORACLE_MAX_CONF_BPS + 1;
+        assert!(validate_oracle_config(&config).is_err());
+
+        let mut config = with_sources(vec![pyth(1)]);
+        config.max_spread_bps = MAX_ORACLE_MAX_SPREAD_BPS + 1;
+        assert!(validate_oracle_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_validate_price_deviation() {
+        // 1% band around 100.000000
+        assert!(validate_price_deviation(101_000_000, 100_000_000, 100).is_ok());
+        assert!(validate_price_deviation(99_000_000, 100_000_000, 100).is_ok());
+        assert!(validate_price_deviation(101_000_001, 100_000_000, 100).is_err());
+        assert!(validate_price_deviation(98_999_999, 100_000_000, 100).is_err());
+        // Zero oracle price is never valid
+        assert!(validate_price_deviation(1, 0, 100).is_err());
+    }
+}
@@ -1,7 +1,8 @@
-use super::{calc_fee, split_fee};
-use crate::constants::{AUTHORITY_SEED, POOL_AUTHORITY_SEED, PRICE_PRECISION};
+use super::{calc_fee, split_fee_with_insurance};
+use crate::constants::{AUTHORITY_SEED, BPS_DIVISOR, POOL_AUTHORITY_SEED, PRICE_PRECISION};
 use crate::error::PerpetualsError;
 use crate::math::mul_div_u64;
+use crate::state::insurance_fund::InsuranceSettlement;
 use crate::state::liquidity::LiquidityPool;
 use crate::state::position::Position;
 use anchor_lang::prelude::*;
@@ -24,12 +25,14 @@ pub struct SettlementDetails {
     pub user_payout_u64: u64,    // escrow_to_user + pool_to_user
     pub fee_to_treasury: u64,
     pub fee_to_blp: u64,
+    pub fee_to_insurance: u64,   // Paid from escrow to the insurance vault
     pub base_fee: u64,
     pub rebalance_fee: u64,
     pub funding_accumulated: i128,
     pub borrow_accumulated: i128,  // Borrow fee (negative from user perspective)
     pub pnl: i128,
     pub bad_debt_amount: u64,
+    pub pool_shortfall: u64,     // Loss beyond the closed collateral, absorbed by the pool
     pub collateral_to_release: u64,
 }
 
@@ -167,6 +170,54 @@ pub fn execute_settlement_transfers<'info>(
     Ok(())
 }
 
+/// Execute the insurance fund side of a settlement: move the fee cut from escrow into
+/// the insurance vault, then pay covered bad debt from the vault into the pool vault.
+/// Both accounts are controlled by the program authority.
+pub fn execute_insurance_transfers<'info>(
+    token_program: &Program<'info, Token>,
+    owner_collateral_escrow_account: &Account<'info, TokenAccount>,
+    insurance_vault: &Account<'info, TokenAccount>,
+    pool_vault: &AccountInfo<'info>,
+    program_authority: &AccountInfo<'info>,
+    authority_bump: u8,
+    settlement: &InsuranceSettlement,
+) -> Result<()> {
+    let authority_signer_seeds = [AUTHORITY_SEED, &[authority_bump]];
+    let authority_signer = &[&authority_signer_seeds[..]];
+
+    if settlement.fee_received > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: owner_collateral_escrow_account.to_account_info(),
+                    to: insurance_vault.to_account_info(),
+                    authority: program_authority.clone(),
+                },
+                authority_signer,
+            ),
+            settlement.fee_received,
+        )?;
+    }
+
+    if settlement.bad_debt_covered > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: insurance_vault.to_account_info(),
+                    to: pool_vault.clone(),
+                    authority: program_authority.clone(),
+                },
+                authority_signer,
+            ),
+            settlement.bad_debt_covered,
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Update liquidity pool state based on settlement using actual transferred amounts
 pub fn update_pool_state(
     liquidity_pool: &mut LiquidityPool,
@@ -186,15 +237,27 @@ pub fn update_pool_state(
         .ok_or(PerpetualsError::MathOverflow)?;
 
 
+    let amount = u64::try_from(net_change.unsigned_abs()).map_err(|_| PerpetualsError::MathOverflow)?;
     if net_change > 0 {
-        liquidity_pool.increase_liquidity(net_change as u64)?;
+        liquidity_pool.increase_liquidity(amount)?;
     } else if net_change < 0 {
-        liquidity_pool.decrease_liquidity(net_change.abs() as u64)?;
+        liquidity_pool.decrease_liquidity(amount)?;
     }
 
     Ok(())
 }
 
+/// Cap the pool's share of a payout at what the pool can release, after netting what the
+/// settlement pays into it, and return the profit haircut. Used by auto-deleveraging,
+/// which runs precisely when the pool may not cover every profit in full.
+pub fn cap_pool_payout(details: &mut SettlementDetails, effective_liquidity: u64) -> u64 {
+    let max_pool_payout = effective_liquidity.saturating_add(details.escrow_to_pool);
+    let haircut = details.pool_to_user.saturating_sub(max_pool_payout);
+    details.pool_to_user -= haircut;
+    details.user_payout_u64 -= haircut;
+    haircut
+}
+
 /// Calculate settlement for closing a position (full or partial)
 /// Policy: funding and borrow are fully settled on every settlement.
 /// - We always apply the entire `position.funding_accumulated` and `position.borrow_accumulated`
@@ -209,6 +272,7 @@ pub fn calculate_position_settlement(
     exit_price: u64,
     closing_type: ClosingType,
     treasury_cut_bps: u64,
+    insurance_cut_bps: u64,
     rebalance_fee_owed: u64,
 ) -> Result<SettlementDetails> {
     if size_to_close == 0 || size_to_close > position.size {
@@ -258,6 +322,9 @@ pub fn calculate_position_settlement(
         // Bad debt = losses that exceed available collateral + uncollected fees
         let bad_debt_from_losses = loss_amount.saturating_sub(available_collateral.into());
         let bad_debt_amount = bad_debt_from_losses.saturating_add(total_fees_u64.into());
+        // Negative equity is already net of the closed collateral: it is exactly what the
+        // pool is owed beyond the collateral it receives
+        let pool_shortfall: u64 = loss_amount.try_into().unwrap_or(u64::MAX);
         
         return Ok(SettlementDetails {
             escrow_to_treasury: 0,
@@ -266,12 +333,14 @@ pub fn calculate_position_settlement(
             pool_to_user: 0,
             fee_to_treasury: 0,
             fee_to_blp: 0,
+            fee_to_insurance: 0,
             base_fee: 0,
             rebalance_fee: 0,
             pnl: realized_pnl_i128,
             funding_accumulated: funding_closed_i128,
             borrow_accumulated: borrow_closed_i128,
             bad_debt_amount: bad_debt_amount.try_into().unwrap_or(u64::MAX),
+            pool_shortfall,
             user_payout_u64: 0,
             collateral_to_release: collateral_closed,
         });
@@ -280,9 +349,9 @@ pub fn calculate_position_settlement(
     // 6. Collect fees from positive equity
     let collectible_fee = core::cmp::min(total_fees_u64, equity_i128 as u64);
     let uncollected_fee = total_fees_u64.saturating_sub(collectible_fee);
-    let (fee_to_treasury, fee_to_blp) = if collectible_fee > 0 {
-        split_fee(collectible_fee, treasury_cut_bps)?
-    } else { (0, 0) };
+    let (fee_to_treasury, fee_to_insurance, fee_to_blp) = if collectible_fee > 0 {
+        split_fee_with_insurance(collectible_fee, treasury_cut_bps, insurance_cut_bps)?
+    } else { (0, 0, 0) };
 
     // 7. Calculate user payout after fees
     let user_total_payout = (equity_i128 as u64).saturating_sub(collectible_fee);
@@ -290,7 +359,9 @@ pub fn calculate_position_settlement(
     // 8. Handle liquidation vs normal close
     if matches!(closing_type, ClosingType::Liquidation { .. }) {
         // In liquidation: user gets nothing, pool gets remainder after fees
-        let escrow_to_pool = collateral_closed.saturating_sub(fee_to_treasury);
+        let escrow_to_pool = collateral_closed
+            .saturating_sub(fee_to_treasury)
+            .saturating_sub(fee_to_insurance);
         
         return Ok(SettlementDetails {
             escrow_to_treasury: fee_to_treasury,
@@ -299,19 +370,23 @@ pub fn calculate_position_settlement(
             pool_to_user: 0,
             fee_to_treasury,
             fee_to_blp,
+            fee_to_insurance,
             base_fee,
             rebalance_fee: rebalance_fee_owed,
             pnl: realized_pnl_i128,
             funding_accumulated: funding_closed_i128,
             borrow_accumulated: borrow_closed_i128,
             bad_debt_amount: uncollected_fee,
+            pool_shortfall: 0,
             user_payout_u64: 0,
             collateral_to_release: collateral_closed,
         });
     }
 
     // 9. Normal close: split payout between escrow and pool
-    let net_collateral = collateral_closed.saturating_sub(fee_to_treasury);
+    let net_collateral = collateral_closed
+        .saturating_sub(fee_to_treasury)
+        .saturating_sub(fee_to_insurance);
     let escrow_to_user = core::cmp::min(net_collateral, user_total_payout);
     let pool_to_user = user_total_payout.saturating_sub(escrow_to_user);
     
@@ -324,12 +399,14 @@ pub fn calculate_position_settlement(
         pool_to_user,
         fee_to_treasury,
         fee_to_blp,
+        fee_to_insurance,
         base_fee,
         rebalance_fee: rebalance_fee_owed,
         pnl: realized_pnl_i128,
         funding_accumulated: funding_closed_i128,
         borrow_accumulated: borrow_closed_i128,
         bad_debt_amount: uncollected_fee,
+        pool_shortfall: 0,
         user_payout_u64: escrow_to_user + pool_to_user,
         collateral_to_release: collateral_closed,
     })
@@ -337,6 +414,131 @@ pub fn calculate_position_settlement(
 
 
 
+/// Settlement of a partial liquidation that keeps the remaining position open
+pub struct PartialLiquidationSettlement {
+    /// `pool_to_user` is credited to the position escrow, not paid out to the user
+    pub details: SettlementDetails,
+    /// Collateral left backing the remaining size
+    pub remaining_collateral: u64,
+}
+
+/// Calculate settlement for a partial liquidation.
+///
+/// Unlike `calculate_position_settlement`, collateral is not released pro rata: the
+/// realized PnL of the closed slice, the full funding/borrow accumulators and all fees are
+/// settled against the whole collateral, and what is left stays with the remaining size.
+/// This is what lets a partial cut restore the position's margin ratio.
+pub fn calculate_partial_liquidation_settlement(
+    position: &Position,
+    size_to_close: u64,
+    exit_price: u64,
+    liquidation_fee_bps: u64,
+    treasury_cut_bps: u64,
+    insurance_cut_bps: u64,
+    rebalance_fee_owed: u64,
+) -> Result<PartialLiquidationSettlement> {
+    require!(
+        size_to_close > 0 && size_to_close < position.size,
+        PerpetualsError::InvalidPositionSize
+    );
+
+    let realized_pnl_i128 = calculate_pnl(
+        position.is_long,
+        position.entry_price,
+        size_to_close,
+        exit_price,
+    )? as i128;
+    let realized_i128 = realized_pnl_i128
+        .checked_add(position.funding_accumulated).ok_or(PerpetualsError::MathOverflow)?
+        .checked_add(position.borrow_accumulated).ok_or(PerpetualsError::MathOverflow)?;
+
+    let exit_notional_u64 = mul_div_u64(size_to_close, exit_price, PRICE_PRECISION)?;
+    let base_fee = calc_fee(exit_notional_u64, liquidation_fee_bps)?;
+    let total_fees_u64 = base_fee.checked_add(rebalance_fee_owed).ok_or(PerpetualsError::MathOverflow)?;
+    let (fee_to_treasury, fee_to_insurance, fee_to_blp) =
+        split_fee_with_insurance(total_fees_u64, treasury_cut_bps, insurance_cut_bps)?;
+
+    // The remaining position must still be backed by collateral, otherwise liquidate fully
+    let remaining_collateral_i128 = (position.collateral as i128)
+        .checked_add(realized_i128).ok_or(PerpetualsError::MathOverflow)?
+        .checked_sub(total_fees_u64 as i128).ok_or(PerpetualsError::MathOverflow)?;
+    require!(remaining_collateral_i128 > 0, PerpetualsError::InsufficientCollateral);
+
+    // Net the pool's side: BLP fee and realized losses in, realized gains out
+    let pool_net_i128 = (fee_to_blp as i128)
+        .checked_sub(realized_i128).ok_or(PerpetualsError::MathOverflow)?;
+    let pool_net = u64::try_from(pool_net_i128.unsigned_abs()).map_err(|_| PerpetualsError::MathOverflow)?;
+    let (escrow_to_pool, pool_to_escrow) = if pool_net_i128 >= 0 {
+        (pool_net, 0)
+    } else {
+        (0, pool_net)
+    };
+
+    Ok(PartialLiquidationSettlement {
+        details: SettlementDetails {
+            escrow_to_treasury: fee_to_treasury,
+            escrow_to_pool,
+            escrow_to_user: 0,
+            pool_to_user: pool_to_escrow,
+            user_payout_u64: 0,
+            fee_to_treasury,
+            fee_to_blp,
+            fee_to_insurance,
+            base_fee,
+            rebalance_fee: rebalance_fee_owed,
+            funding_accumulated: position.funding_accumulated,
+            borrow_accumulated: position.borrow_accumulated,
+            pnl: realized_pnl_i128,
+            bad_debt_amount: 0,
+            pool_shortfall: 0,
+            collateral_to_release: 0,
+        },
+        remaining_collateral: remaining_collateral_i128 as u64,
+    })
+}
+
+/// Smallest size to liquidate so the remaining position is back at `target_margin_bps`.
+///
+/// Closing a fraction `f` of the position costs the liquidation fee on that slice and
+/// leaves equity `E - fee * f * N` against notional `(1 - f) * N`, so the minimal fraction
+/// is `(target * N - E) / ((target - fee) * N)`, rounded up and capped at the full size.
+/// `equity` must already be net of every fee owed except the liquidation fee.
+pub fn calc_partial_liquidation_size(
+    size: u64,
+    equity: i128,
+    notional: u64,
+    target_margin_bps: u64,
+    liquidation_fee_bps: u64,
+) -> Result<u64> {
+    if target_margin_bps <= liquidation_fee_bps || notional == 0 {
+        return Ok(size);
+    }
+
+    // Both sides scaled by BPS_DIVISOR
+    let required = (notional as i128)
+        .checked_mul(target_margin_bps as i128)
+        .ok_or(PerpetualsError::MathOverflow)?;
+    let shortfall = required
+        .checked_sub(equity.checked_mul(BPS_DIVISOR as i128).ok_or(PerpetualsError::MathOverflow)?)
+        .ok_or(PerpetualsError::MathOverflow)?;
+    if shortfall <= 0 {
+        return Ok(0);
+    }
+
+    let denominator = (notional as i128)
+        .checked_mul((target_margin_bps - liquidation_fee_bps) as i128)
+        .ok_or(PerpetualsError::MathOverflow)?;
+    let numerator = shortfall
+        .checked_mul(size as i128)
+        .ok_or(PerpetualsError::MathOverflow)?;
+    let min_size = numerator
+        .checked_add(denominator - 1)
+        .ok_or(PerpetualsError::MathOverflow)?
+        / denominator;
+
+    Ok(std::cmp::min(min_size, size as i128) as u64)
+}
+
 pub fn calculate_pnl(
     is_long: bool,
     entry_price: u64,
@@ -395,7 +597,7 @@ pub fn close_escrow_account<'info>(
     program_authority: &AccountInfo<'info>,
     authority_bump: u8,
 ) -> Result<()> {
-    let authority_signer_seeds = [AUTHORITY_SEED.as_ref(), &[authority_bump]];
+    let authority_signer_seeds = [AUTHORITY_SEED, &[authority_bump]];
     let authority_signer = &[&authority_signer_seeds[..]];
 
     token::close_account(CpiContext::new_with_signer(
@@ -410,3 +612,137 @@ pub fn close_escrow_account<'info>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::position::{ExitInfo, PositionStatus};
+
+    fn position(size: u64, collateral: u64, entry_price: u64) -> Position {
+        Position {
+            owner: Pubkey::default(),
+            position_id: 0,
+            baskt_id: Pubkey::default(),
+            size,
+            collateral,
+            is_long: true,
+            entry_price,
+            exit_info: ExitInfo::None,
+            last_funding_index: 0,
+            funding_accumulated: 0,
+            last_borrow_index: 0,
+            borrow_accumulated: 0,
+            last_rebalance_fee_index: 0,
+            status: PositionStatus::Open,
+            timestamp_open: 0,
+            bump: 0,
+            margin_account: None,
+            extra_space: [0; 87],
+        }
+    }
+
+    #[test]
+    fn test_partial_liquidation_size_restores_target() {
+        // 1_000 notional with 40 equity; 10% target, 1% fee
+        // f = (100 - 40) / (1_000 * 0.09) = 2/3
+        let size = calc_partial_liquidation_size(900, 40, 1_000, 1_000, 100).unwrap();
+        assert_eq!(size, 600);
+        // Remaining equity after the slice's fee still covers 10% of the remaining notional
+        let remaining_equity = 40 - 1_000 * 600 / 900 / 100;
+        let remaining_notional = 1_000 - 1_000 * 600 / 900;
+        assert!(remaining_equity * 10 >= remaining_notional);
+
+        // Already at target: nothing to close
+        assert_eq!(calc_partial_liquidation_size(900, 100, 1_000, 1_000, 100).unwrap(), 0);
+        // Negative equity or a target at or below the fee requires a full liquidation
+        assert_eq!(calc_partial_liquidation_size(900, -10, 1_000, 1_000, 100).unwrap(), 900);
+        assert_eq!(calc_partial_liquidation_size(900, 40, 1_000, 100, 100).unwrap(), 900);
+    }
+
+    #[test]
+    fn test_partial_liquidation_settlement_keeps_remaining_collateral() {
+        // Long 10 units from 100 marked at 95: 50 loss on 100 collateral
+        let position = position(10 * PRICE_PRECISION, 100 * PRICE_PRECISION, 100 * PRICE_PRECISION);
+        let settlement = calculate_partial_liquidation_settlement(
+            &position,
+            5 * PRICE_PRECISION,
+            95 * PRICE_PRECISION,
+            100, // 1% of 475 = 4.75
+            1_000,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let details = &settlement.details;
+        assert_eq!(details.pnl, -25 * PRICE_PRECISION as i128);
+        assert_eq!(details.base_fee, 4_750_000);
+        assert_eq!(details.escrow_to_treasury, 475_000);
+        assert_eq!(details.escrow_to_pool, 25 * PRICE_PRECISION + 4_275_000);
+        // The slice's loss and fee come out of the whole collateral
+        assert_eq!(settlement.remaining_collateral, 100 * PRICE_PRECISION - 25 * PRICE_PRECISION - 4_750_000);
+
+        // Full-size or underwater cuts are not partial liquidations
+        assert!(calculate_partial_liquidation_settlement(&position, 10 * PRICE_PRECISION, 95 * PRICE_PRECISION, 100, 1_000, 0, 0).is_err());
+        assert!(calculate_partial_liquidation_settlement(&position, 5 * PRICE_PRECISION, 70 * PRICE_PRECISION, 100, 1_000, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_cap_pool_payout_haircuts_profit_beyond_liquidity() {
+        // Long 10 units from 100 closed at 130 without fees: 300 profit on 100 collateral
+        let position = position(10 * PRICE_PRECISION, 100 * PRICE_PRECISION, 100 * PRICE_PRECISION);
+        let mut details = calculate_position_settlement(
+            &position,
+            10 * PRICE_PRECISION,
+            130 * PRICE_PRECISION,
+            ClosingType::ForceClose { closing_fee_bps: 0 },
+            1_000,
+            0,
+            0,
+        )
+        .unwrap();
+        assert_eq!(details.pool_to_user, 300 * PRICE_PRECISION);
+
+        // Enough liquidity: nothing changes
+        assert_eq!(cap_pool_payout(&mut details, 500 * PRICE_PRECISION), 0);
+        assert_eq!(details.pool_to_user, 300 * PRICE_PRECISION);
+
+        // Only 120 available: the remaining 180 of profit is cut
+        assert_eq!(cap_pool_payout(&mut details, 120 * PRICE_PRECISION), 180 * PRICE_PRECISION);
+        assert_eq!(details.pool_to_user, 120 * PRICE_PRECISION);
+        assert_eq!(details.user_payout_u64, 220 * PRICE_PRECISION);
+    }
+
+    #[test]
+    fn test_pool_shortfall_excludes_uncollected_fees() {
+        // Long 10 units from 100 liquidated at 80: 200 loss on 100 collateral
+        let position = position(10 * PRICE_PRECISION, 100 * PRICE_PRECISION, 100 * PRICE_PRECISION);
+        let details = calculate_position_settlement(
+            &position,
+            10 * PRICE_PRECISION,
+            80 * PRICE_PRECISION,
+            ClosingType::Liquidation { liquidation_fee_bps: 100 }, // 1% of 800 = 8
+            1_000,
+            0,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(details.escrow_to_pool, 100 * PRICE_PRECISION);
+        assert_eq!(details.pool_shortfall, 100 * PRICE_PRECISION);
+
+        // Positive equity that cannot cover the fee leaves no pool shortfall
+        let details = calculate_position_settlement(
+            &position,
+            10 * PRICE_PRECISION,
+            90 * PRICE_PRECISION + 5 * PRICE_PRECISION / 10, // equity 5, fee 9.05
+            ClosingType::Liquidation { liquidation_fee_bps: 100 },
+            1_000,
+            0,
+            0,
+        )
+        .unwrap();
+        assert!(details.bad_debt_amount > 0);
+        assert_eq!(details.pool_shortfall, 0);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::constants::BPS_DIVISOR;
+use crate::error::PerpetualsError;
+use crate::math::mul_div_u64;
+use anchor_lang::prelude::*;
+
+/// Median of the usable source prices for one asset
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AggregatedPrice {
+    /// Median price (PRICE_PRECISION)
+    pub price: u64,
+    /// Number of sources that contributed
+    pub sources_used: u8,
+    /// (max - min) / median, in basis points
+    pub spread_bps: u64,
+    /// Whether `spread_bps` exceeds the configured maximum
+    pub disagreement: bool,
+}
+
+/// Aggregate source prices into a median.
+///
+/// With an even number of sources the median is the floored mean of the two middle
+/// prices. Fails when fewer than `min_sources` prices are available; sources that
+/// disagree beyond `max_spread_bps` are flagged rather than rejected so callers can
+/// decide how strict to be.
+pub fn aggregate_prices(
+    prices: &[u64],
+    min_sources: u8,
+    max_spread_bps: u64,
+) -> Result<AggregatedPrice> {
+    require!(
+        !prices.is_empty() && prices.len() >= min_sources as usize,
+        PerpetualsError::InsufficientOracleSources
+    );
+
+    let mut sorted = prices.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    // `usize::is_multiple_of` is newer than the SBF toolchain's rustc
+    #[allow(clippy::manual_is_multiple_of)]
+    let median = if sorted.len() % 2 == 0 {
+        // Average without overflowing
+        sorted[mid - 1] / 2 + sorted[mid] / 2 + (sorted[mid - 1] % 2 + sorted[mid] % 2) / 2
+    } else {
+        sorted[mid]
+    };
+    require!(median > 0, PerpetualsError::InvalidOraclePrice);
+
+    let spread = sorted[sorted.len() - 1] - sorted[0];
+    let spread_bps = mul_div_u64(spread, BPS_DIVISOR, median)?;
+
+    Ok(AggregatedPrice {
+        price: median,
+        sources_used: sorted.len() as u8,
+        spread_bps,
+        disagreement: spread_bps > max_spread_bps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median() {
+        assert_eq!(aggregate_prices(&[100], 1, 100).unwrap().price, 100);
+        assert_eq!(aggregate_prices(&[300, 100, 200], 1, 10_000).unwrap().price, 200);
+        assert_eq!(aggregate_prices(&[100, 201], 1, 10_000).unwrap().price, 150);
+        assert_eq!(aggregate_prices(&[u64::MAX, u64::MAX], 1, 10_000).unwrap().price, u64::MAX);
+    }
+
+    #[test]
+    fn test_single_outlier_does_not_move_median() {
+        // One glitched feed reporting 10x the price
+        let aggregated = aggregate_prices(&[1_000_000, 1_001_000, 10_000_000], 2, 100).unwrap();
+        assert_eq!(aggregated.price, 1_001_000);
+        assert_eq!(aggregated.sources_used, 3);
+        assert!(aggregated.disagreement);
+    }
+
+    #[test]
+    fn test_spread_flag() {
+        // 1% apart around a 100.5 median: 99.5bps is within a 100bps limit
+        let aggregated = aggregate_prices(&[100_000_000, 101_000_000], 1, 100).unwrap();
+        assert_eq!(aggregated.spread_bps, 99);
+        assert!(!aggregated.disagreement);
+
+        let aggregated = aggregate_prices(&[100_000_000, 102_000_000], 1, 100).unwrap();
+        assert!(aggregated.disagreement);
+    }
+
+    #[test]
+    fn test_min_sources() {
+        assert!(aggregate_prices(&[], 1, 100).is_err());
+        assert!(aggregate_prices(&[100], 2, 100).is_err());
+        assert!(aggregate_prices(&[100, 100], 2, 100).is_ok());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::error::PerpetualsError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::instruction::Instruction;
+
+/// Size of the ed25519 instruction header: signature count + padding
+const ED25519_HEADER_LEN: usize = 2;
+/// Size of one `Ed25519SignatureOffsets` entry (7 x u16)
+const ED25519_OFFSETS_LEN: usize = 14;
+/// Instruction index value meaning "data lives in the ed25519 instruction itself"
+const ED25519_SELF_INDEX: u16 = u16::MAX;
+
+fn read_u16(data: &[u8], at: usize) -> std::result::Result<u16, PerpetualsError> {
+    data.get(at..at + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or(PerpetualsError::InvalidPriceSignature)
+}
+
+/// Extract the signer and message from an ed25519 precompile instruction.
+///
+/// The precompile has already verified the signature by the time our program runs, so
+/// this only checks that the instruction carries exactly one signature whose public key,
+/// signature and message are all embedded in the instruction itself.
+pub fn parse_ed25519_instruction(ix: &Instruction) -> Result<(Pubkey, Vec<u8>)> {
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        PerpetualsError::InvalidPriceSignature
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        PerpetualsError::InvalidPriceSignature
+    );
+
+    let offsets = ED25519_HEADER_LEN;
+    let signature_ix_index = read_u16(data, offsets + 2)?;
+    let public_key_offset = read_u16(data, offsets + 4)? as usize;
+    let public_key_ix_index = read_u16(data, offsets + 6)?;
+    let message_offset = read_u16(data, offsets + 8)? as usize;
+    let message_size = read_u16(data, offsets + 10)? as usize;
+    let message_ix_index = read_u16(data, offsets + 12)?;
+
+    require!(
+        signature_ix_index == ED25519_SELF_INDEX
+            && public_key_ix_index == ED25519_SELF_INDEX
+            && message_ix_index == ED25519_SELF_INDEX,
+        PerpetualsError::InvalidPriceSignature
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(PerpetualsError::InvalidPriceSignature)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(PerpetualsError::InvalidPriceSignature)?;
+
+    let signer = Pubkey::try_from(public_key).map_err(|_| PerpetualsError::InvalidPriceSignature)?;
+    Ok((signer, message.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build ed25519 instruction data the way `new_ed25519_instruction` lays it out
+    fn ed25519_data(signer: &Pubkey, message: &[u8], ix_index: u16) -> Vec<u8> {
+        let public_key_offset = (ED25519_HEADER_LEN + ED25519_OFFSETS_LEN) as u16;
+        let signature_offset = public_key_offset + 32;
+        let message_offset = signature_offset + 64;
+
+        let mut data = vec![1u8, 0u8];
+        for value in [
+            signature_offset,
+            ix_index,
+            public_key_offset,
+            ix_index,
+            message_offset,
+            message.len() as u16,
+            ix_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[7u8; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    fn ed25519_ix(data: Vec<u8>) -> Instruction {
+        Instruction {
+            program_id: ed25519_program::ID,
+            accounts: vec![],
+            data,
+        }
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let signer = Pubkey::new_unique();
+        let ix = ed25519_ix(ed25519_data(&signer, b"price", ED25519_SELF_INDEX));
+        let (parsed_signer, message) = parse_ed25519_instruction(&ix).unwrap();
+        assert_eq!(parsed_signer, signer);
+        assert_eq!(message, b"price");
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction_rejects_foreign_data() {
+        let signer = Pubkey::new_unique();
+
+        // Data referenced from another instruction could be swapped after signing
+        let ix = ed25519_ix(ed25519_data(&signer, b"price", 0));
+        assert!(parse_ed25519_instruction(&ix).is_err());
+
+        // Wrong program
+        let mut ix = ed25519_ix(ed25519_data(&signer, b"price", ED25519_SELF_INDEX));
+        ix.program_id = Pubkey::new_unique();
+        assert!(parse_ed25519_instruction(&ix).is_err());
+
+        // Truncated message
+        let mut data = ed25519_data(&signer, b"price", ED25519_SELF_INDEX);
+        data.truncate(data.len() - 1);
+        assert!(parse_ed25519_instruction(&ed25519_ix(data)).is_err());
+
+        // More than one signature
+        let mut data = ed25519_data(&signer, b"price", ED25519_SELF_INDEX);
+        data[0] = 2;
+        assert!(parse_ed25519_instruction(&ed25519_ix(data)).is_err());
+    }
+}
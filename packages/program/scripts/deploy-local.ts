@@ -161,7 +161,9 @@ async function main() {
   const assetsWithAddress = [];
 
   for (const asset of assetConfig) {
-    const { assetAddress } = await client.addAsset(asset.ticker);
+    // Assets are priced by the off-chain price service through their protocol price feed
+    const { assetAddress } = await client.addAsset(asset.ticker, undefined, [{ priceFeed: {} }]);
+    await client.initializePriceFeed(assetAddress);
     assetsWithAddress.push({
       ...asset,
       address: assetAddress.toString(),
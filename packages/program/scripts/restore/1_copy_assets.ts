@@ -73,7 +73,9 @@ async function migrateAssetMetadata() {
         console.log(`Asset ${asset.ticker} ${asset.assetAddress} ${assetPDA.toBase58()} already exists on chain. will add to DB`);
         continue;
       } catch (error) {
-        const addAssetTx = await client.addAsset(asset.ticker); 
+        // Assets are priced by the off-chain price service through their protocol price feed
+        const addAssetTx = await client.addAsset(asset.ticker, undefined, [{ priceFeed: {} }]);
+        await client.initializePriceFeed(assetPDA);
         console.log(`Added asset ${asset.ticker} ${asset.assetAddress} to blockchain`, addAssetTx);
       }
     }
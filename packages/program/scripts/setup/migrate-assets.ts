@@ -123,7 +123,9 @@ async function addAssetToBlockchain(client: AssetMigrationClient, asset: AssetMe
       allowShorts: true,
     };
 
-    const result = await client.addAsset(asset.ticker, permissions);
+    // Assets are priced by the off-chain price service through their protocol price feed
+    const result = await client.addAsset(asset.ticker, permissions, [{ priceFeed: {} }]);
+    await client.initializePriceFeed(result.assetAddress);
     console.log(`✅ Added asset ${asset.ticker} to blockchain. TX: ${result.txSignature}`);
     return true;
   } catch (error) {
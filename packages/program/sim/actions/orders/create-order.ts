@@ -35,10 +35,10 @@ const createOrderOpen = async (args: string[]) => {
       isLong,
       leverageBps,
       limitPrice,
-      maxSlippageBps,
       ownerTokenAccount,
     });
   } else {
+    // For market orders a limit price is the slippage reference price
     orderTx = await client.createMarketOpenOrder({
       orderId,
       basktId,
@@ -47,6 +47,8 @@ const createOrderOpen = async (args: string[]) => {
       ownerTokenAccount,
       collateral: collateralRequired,
       isLong,
+      referencePrice: limitPrice,
+      maxSlippageBps,
     });
   }
 
@@ -58,6 +60,8 @@ const createOrderOpen = async (args: string[]) => {
   console.log('Collateral Required:', collateralRequired.toString());
   if (orderType === 'limit') {
     console.log('Limit Price:', limitPrice.toString());
+  } else if (!limitPrice.isZero()) {
+    console.log('Reference Price:', limitPrice.toString());
     console.log('Max Slippage BPS:', maxSlippageBps.toString());
   }
   console.log('Order ID:', orderId.toString());
@@ -119,16 +123,18 @@ const createOrderClose = async (args: string[]) => {
       sizeAsContracts: closeSize,
       targetPosition: positionPDA,
       limitPrice,
-      maxSlippageBps,
       ownerTokenAccount,
     });
   } else {
+    // For market orders a limit price is the slippage reference price
     orderTx = await client.createMarketCloseOrder({
       orderId,
       basktId: positionAccount.basktId,
       sizeAsContracts: closeSize,
       targetPosition: positionPDA,
       ownerTokenAccount,
+      referencePrice: limitPrice,
+      maxSlippageBps,
     });
   }
 
@@ -140,6 +146,8 @@ const createOrderClose = async (args: string[]) => {
   console.log('Size to Close:', closeSize.toString());
   if (orderType === 'limit') {
     console.log('Limit Price:', limitPrice.toString());
+  } else if (!limitPrice.isZero()) {
+    console.log('Reference Price:', limitPrice.toString());
     console.log('Max Slippage (BPS):', maxSlippageBps.toString());
   }
   console.log('Order ID:', orderId.toString());
@@ -155,8 +163,8 @@ const createOrder = async (args: string[]) => {
       console.log('');
       console.log('Parameters:');
       console.log('  orderType: "market" or "limit" (default: market)');
-      console.log('  limitPrice: Price in USD (e.g., 100.5)');
-      console.log('  maxSlippageBps: Max slippage in basis points (default: 100 = 1%)');
+      console.log('  limitPrice: Price in USD (e.g., 100.5); for market orders, the slippage reference price');
+      console.log('  maxSlippageBps: Market order max slippage from limitPrice in basis points (default: 100 = 1%)');
       console.log('  leverageBps: Leverage in basis points (default: 10000 = 1x)');
       console.log('  sizeToClose: Amount to close - use percentage (e.g., "50%") or absolute size in USD (e.g., "1000")');
       throw new Error('Invalid arguments');
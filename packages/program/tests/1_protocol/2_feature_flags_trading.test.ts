@@ -188,7 +188,6 @@ describe('protocol feature flags - trading operations', () => {
         isLong: true,
         ownerTokenAccount: userTokenAccount,
         limitPrice: BASELINE_PRICE,
-        maxSlippageBps: new BN(500),
         basktId: basktId,
         leverageBps: new BN(10000),
       });
@@ -252,7 +251,6 @@ describe('protocol feature flags - trading operations', () => {
           isLong: true,
           ownerTokenAccount: userTokenAccount,
           limitPrice: BASELINE_PRICE,
-          maxSlippageBps: new BN(500),
           basktId: basktId,
           leverageBps: new BN(10000),
         });
@@ -0,0 +1,103 @@
+import { expect } from 'chai';
+import { describe, it, before, after } from 'mocha';
+import { Keypair, PublicKey, SYSVAR_CLOCK_PUBKEY } from '@solana/web3.js';
+import BN from 'bn.js';
+import { TestClient } from '../utils/test-client';
+import { AccessControlRole } from '@baskt/types';
+
+describe('price feed', () => {
+  // Get the test client instance
+  const client = TestClient.getInstance();
+
+  // Key signing prices off-chain; it never pays for or signs a transaction
+  const oracle = Keypair.generate();
+
+  const PRICE = new BN(50_000_000_000); // 50,000 with PRICE_PRECISION
+  const CONF = new BN(5_000_000); // 5 with PRICE_PRECISION
+
+  let priceFeed: PublicKey;
+
+  // Current validator time, read from the Clock sysvar (unix_timestamp at offset 32)
+  const getChainTime = async (): Promise<BN> => {
+    const clock = await client.connection.getAccountInfo(SYSVAR_CLOCK_PUBKEY, 'confirmed');
+    return new BN(clock!.data.readBigInt64LE(32).toString());
+  };
+
+  before(async () => {
+    await TestClient.initializeProtocolAndRoles(client);
+
+    // Asset priced only by its protocol-owned price feed
+    const { assetAddress } = await client.addAsset('FEED', undefined, [{ priceFeed: {} }]);
+    ({ priceFeed } = await client.initializePriceFeed(assetAddress));
+
+    await client.addRole(oracle.publicKey, AccessControlRole.Oracle);
+  });
+
+  after(async () => {
+    // Free the access control entry for later test files
+    await client.removeRole(oracle.publicKey, AccessControlRole.Oracle);
+  });
+
+  it('Stores a price signed by an Oracle role holder', async () => {
+    const publishTime = (await getChainTime()).subn(1);
+
+    await client.postSignedPrice(priceFeed, oracle, { price: PRICE, conf: CONF, publishTime });
+
+    const feed = await client.getPriceFeed(priceFeed);
+    expect(feed.price.toString()).to.equal(PRICE.toString());
+    expect(feed.conf.toString()).to.equal(CONF.toString());
+    expect(feed.publishTime.toString()).to.equal(publishTime.toString());
+    expect(feed.lastSigner.toString()).to.equal(oracle.publicKey.toString());
+  });
+
+  it('Rejects a signed price that is not newer than the stored one', async () => {
+    const stored = await client.getPriceFeed(priceFeed);
+
+    try {
+      // A replay of the stored observation, re-signed with a different price
+      await client.postSignedPrice(priceFeed, oracle, {
+        price: PRICE.muln(2),
+        conf: CONF,
+        publishTime: stored.publishTime,
+      });
+      expect.fail('Expected transaction to fail with PriceUpdateOutOfOrder error');
+    } catch (error) {
+      const err = error as { message: string };
+      expect(err.message).to.include('PriceUpdateOutOfOrder');
+    }
+
+    const feed = await client.getPriceFeed(priceFeed);
+    expect(feed.price.toString()).to.equal(PRICE.toString());
+  });
+
+  it('Rejects a signed price published in the future', async () => {
+    const publishTime = (await getChainTime()).addn(3600);
+
+    try {
+      await client.postSignedPrice(priceFeed, oracle, { price: PRICE, conf: CONF, publishTime });
+      expect.fail('Expected transaction to fail with InvalidOraclePrice error');
+    } catch (error) {
+      const err = error as { message: string };
+      expect(err.message).to.include('InvalidOraclePrice');
+    }
+
+    const feed = await client.getPriceFeed(priceFeed);
+    expect(feed.publishTime.lt(publishTime)).to.be.true;
+  });
+
+  it('Rejects a price signed by a key without the Oracle role', async () => {
+    const publishTime = await getChainTime();
+
+    try {
+      await client.postSignedPrice(priceFeed, Keypair.generate(), {
+        price: PRICE,
+        conf: CONF,
+        publishTime,
+      });
+      expect.fail('Expected transaction to fail with UnauthorizedRole error');
+    } catch (error) {
+      const err = error as { message: string };
+      expect(err.message).to.include('UnauthorizedRole');
+    }
+  });
+});
@@ -333,7 +333,6 @@ describe('Order Creation', () => {
         isLong: true,
         leverageBps: new BN(10000), // 1x leverage
         limitPrice: LIMIT_PRICE, // $50,000 limit price
-        maxSlippageBps: new BN(100), // 1% slippage
         ownerTokenAccount: userTokenAccount,
       });
 
@@ -361,7 +360,6 @@ describe('Order Creation', () => {
       expect(orderAccount.limitParams).to.not.be.null;
       if (orderAccount.limitParams) {
         expect(orderAccount.limitParams.limitPrice.toString()).to.equal(LIMIT_PRICE.toString());
-        expect(orderAccount.limitParams.maxSlippageBps.toString()).to.equal('100');
       }
 
       // Verify collateral was transferred
@@ -395,7 +393,6 @@ describe('Order Creation', () => {
         isLong: false,
         leverageBps: new BN(10000), // 1x leverage
         limitPrice: new BN(45000 * 1000000), // $45,000 limit price
-        maxSlippageBps: new BN(200), // 2% slippage
         ownerTokenAccount: userTokenAccount,
       });
 
@@ -422,7 +419,6 @@ describe('Order Creation', () => {
       expect(orderAccount.limitParams).to.not.be.null;
       if (orderAccount.limitParams) {
         expect(orderAccount.limitParams.limitPrice.toString()).to.equal(new BN(45000 * 1000000).toString());
-        expect(orderAccount.limitParams.maxSlippageBps.toString()).to.equal('200');
       }
 
       // Verify collateral was transferred
@@ -454,7 +450,6 @@ describe('Order Creation', () => {
           isLong: true,
           leverageBps: new BN(10000),
           limitPrice: new BN(0), // Zero limit price - should fail
-          maxSlippageBps: new BN(100),
           ownerTokenAccount: userTokenAccount,
         });
         // Should not reach here
@@ -479,7 +474,6 @@ describe('Order Creation', () => {
           isLong: true,
           leverageBps: new BN(10000),
           limitPrice: LIMIT_PRICE,
-          maxSlippageBps: new BN(100),
           ownerTokenAccount: userTokenAccount,
         });
         // Should not reach here
@@ -606,7 +600,6 @@ describe('Order Creation', () => {
           sizeAsContracts: NOTIONAL_ORDER_VALUE,
           targetPosition: positionId,
           limitPrice: new BN(48000 * 1000000), // $48,000 limit price
-          maxSlippageBps: new BN(150), // 1.5% slippage
           ownerTokenAccount: userTokenAccount,
         });
 
@@ -632,7 +625,6 @@ describe('Order Creation', () => {
         expect(orderAccount.limitParams).to.not.be.null;
         if (orderAccount.limitParams) {
           expect(orderAccount.limitParams.limitPrice.toString()).to.equal(new BN(48000 * 1000000).toString());
-          expect(orderAccount.limitParams.maxSlippageBps.toString()).to.equal('150');
         }
 
         // Verify NO collateral was transferred for close orders
@@ -664,7 +656,6 @@ describe('Order Creation', () => {
           sizeAsContracts: NOTIONAL_ORDER_VALUE,
           targetPosition: positionId || PublicKey.default,
           limitPrice: new BN(0), 
-          maxSlippageBps: new BN(100),
           ownerTokenAccount: userTokenAccount,
         });
         // Should not reach here
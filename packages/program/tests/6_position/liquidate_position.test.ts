@@ -257,6 +257,64 @@ describe('Liquidate Position', () => {
        
   });
 
+  it('Partially liquidates a position and leaves it open with reduced size and collateral', async () => {
+    const orderId = client.newUID();
+    const positionId = client.newUID();
+    const positionPDA = await client.getPositionPDA(user.publicKey, positionId);
+
+    const protocolConfig = await client.getProtocolAccount();
+    const liquidationThreshold = protocolConfig.config.liquidationThresholdBps;
+
+    // Create and open SHORT position with minimal collateral
+    await matcherClient.createAndOpenMarketPosition({
+      userClient,
+      orderId,
+      positionId,
+      basktId,
+      notionalValue: NOTIONAL_ORDER_VALUE,
+      collateral: COLLATERAL,
+      isLong: false, // SHORT position - will lose money when price increases
+      entryPrice: ENTRY_PRICE,
+      ownerTokenAccount: userTokenAccount,
+      leverageBps: new BN(10000), // 1x leverage
+    });
+
+    const positionBefore = await client.program.account.position.fetch(positionPDA);
+
+    // Just past the liquidation price, so the position is liquidatable but still solvent
+    const exitPrice = calculateLiquidationPriceInternal(
+      positionBefore.entryPrice,
+      positionBefore.collateral,
+      positionBefore.size,
+      new BN(liquidationThreshold),
+      false,
+      new BN(0),
+      new BN(50) // 0.5% closing fee
+    ).add(new BN(0.1 * 1e6));
+    const sizeToClose = positionBefore.size.divn(2);
+
+    await liquidatorClient.liquidatePosition({
+      position: positionPDA,
+      exitPrice,
+      baskt: basktId,
+      ownerTokenAccount: userTokenAccount,
+      treasury: treasury.publicKey,
+      treasuryTokenAccount: treasuryTokenAccount,
+      sizeToClose,
+    });
+
+    // The position account survives a partial liquidation with the remaining size
+    const positionAfter = await client.program.account.position.fetch(positionPDA);
+    expect(positionAfter.status).to.deep.equal({ open: {} });
+    expect(positionAfter.size.toString()).to.equal(positionBefore.size.sub(sizeToClose).toString());
+    expect(positionAfter.collateral.lt(positionBefore.collateral)).to.be.true;
+    expect(positionAfter.collateral.gtn(0)).to.be.true;
+
+    // The escrow still holds the remaining collateral
+    const escrow = await getAccount(client.connection, client.getPositionEscrowPDA(positionPDA));
+    expect(new BN(escrow.amount.toString()).gtn(0)).to.be.true;
+  });
+
   it('Fails to liquidate a position that does not meet liquidation criteria', async () => {
     // This test demonstrates that well-collateralized positions cannot be liquidated
     // even when the liquidator has the proper role
@@ -628,7 +628,6 @@ describe('Position Opening', () => {
       isLong: true,
       leverageBps: new BN(10000),
       limitPrice: LIMIT_PRICE,
-      maxSlippageBps: new BN(100), // 1% slippage
       ownerTokenAccount: userTokenAccount,
     });
 
@@ -661,7 +660,6 @@ describe('Position Opening', () => {
       isLong: true,
       leverageBps: new BN(10000),
       limitPrice: LIMIT_PRICE,
-      maxSlippageBps: new BN(100), // 1% slippage
       ownerTokenAccount: userTokenAccount,
     });
 
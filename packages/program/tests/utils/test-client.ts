@@ -1,4 +1,4 @@
-import { BaseClient, BPS_DIVISOR, PRICE_PRECISION, USDC_MINT } from '@baskt/sdk';
+import { BaseClient, BPS_DIVISOR, OracleSource, PRICE_PRECISION, USDC_MINT } from '@baskt/sdk';
 import { AccessControlRole, OnchainAssetPermissions, OnchainBasktAccount, OnchainPosition } from '@baskt/types';
 import * as anchor from '@coral-xyz/anchor';
 import { Program } from '@coral-xyz/anchor';
@@ -247,6 +247,11 @@ export class TestClient extends BaseClient {
     // Ensure the treasury has an associated USDC token account for fee transfers
     await client.getOrCreateUSDCAccountKey(client.treasury.publicKey);
 
+    // Close, liquidation and force close settle bad debt through the insurance fund
+    if (!(await client.getOptionalAccount(client.insuranceFundPDA))) {
+      await client.initializeInsuranceFund();
+    }
+
     // Get current protocol state to check entry count
     let protocolAccount;
     try {
@@ -580,11 +585,14 @@ export class TestClient extends BaseClient {
   public async addAsset(
     ticker: string,
     permissions?: OnchainAssetPermissions,
+    oracleSources?: OracleSource[],
   ): Promise<{ txSignature: string; assetAddress: PublicKey }> {
     if (this.storedAssets.has(ticker)) {
       return this.storedAssets.get(ticker) as { txSignature: string; assetAddress: PublicKey };
     }
-    const assetInfo = await super.addAsset(ticker, permissions);
+    // Unless a test asks otherwise, assets read the admin-set manual price, which
+    // syncManualPrices moves to the NAV each fill is made at
+    const assetInfo = await super.addAsset(ticker, permissions, oracleSources ?? [{ manual: {} }]);
     this.storedAssets.set(ticker, {
       txSignature: assetInfo.txSignature,
       assetAddress: assetInfo.assetAddress,
@@ -1010,7 +1018,6 @@ export class TestClient extends BaseClient {
     isLong: boolean;
     entryPrice: BN;
     limitPrice: BN;
-    maxSlippageBps: BN;
     ownerTokenAccount: PublicKey;
     leverageBps: BN;
   }): Promise<{
@@ -1027,7 +1034,6 @@ export class TestClient extends BaseClient {
       isLong: params.isLong,
       leverageBps: params.leverageBps,
       limitPrice: params.limitPrice,
-      maxSlippageBps: params.maxSlippageBps,
       ownerTokenAccount: params.ownerTokenAccount,
     });
 
@@ -1110,6 +1116,7 @@ export class TestClient extends BaseClient {
     const treasury = (await this.getProtocolAccount()).treasury;
     const treasuryTokenAccount = await this.getOrCreateUSDCAccountKey(treasury);
 
+    await this.syncManualPrices(params.baskt, params.closePrice);
     return await super.forceClosePosition({
       position: params.position,
       closePrice: params.closePrice,
@@ -1121,6 +1128,47 @@ export class TestClient extends BaseClient {
     });
   }
 
+  /**
+   * Move the manual oracle prices of a baskt's assets so the oracle NAV equals `nav`.
+   * Every asset moves by the same fraction of its baseline price, signed by its direction,
+   * so the weighted NAV change matches the target. Signed by the owner singleton.
+   */
+  public async syncManualPrices(basktId: PublicKey, nav: BN): Promise<void> {
+    const baskt = await this.getBasktRaw(basktId);
+    if (baskt.baselineNav.isZero()) {
+      return;
+    }
+    const owner = TestClient.getInstance();
+    for (const config of baskt.currentAssetConfigs) {
+      const asset = await this.getAssetRaw(config.assetId);
+      if (!asset.oracleConfig.sources.some((source) => 'manual' in source)) {
+        continue;
+      }
+      const move = config.baselinePrice.mul(nav.sub(baskt.baselineNav)).div(baskt.baselineNav);
+      const price = config.direction
+        ? config.baselinePrice.add(move)
+        : config.baselinePrice.sub(move);
+      await owner.setManualPrice(config.assetId, BN.max(price, new BN(1)));
+    }
+  }
+
+  public async openPosition(params: Parameters<BaseClient['openPosition']>[0]): Promise<string> {
+    await this.syncManualPrices(params.baskt, params.entryPrice);
+    return await super.openPosition(params);
+  }
+
+  public async closePosition(params: Parameters<BaseClient['closePosition']>[0]): Promise<string> {
+    await this.syncManualPrices(params.baskt, params.exitPrice);
+    return await super.closePosition(params);
+  }
+
+  public async liquidatePosition(
+    params: Parameters<BaseClient['liquidatePosition']>[0],
+  ): Promise<string> {
+    await this.syncManualPrices(params.baskt, params.exitPrice);
+    return await super.liquidatePosition(params);
+  }
+
   public async initializeProtocol(treasury: PublicKey) {
     // await requestAirdrop(this.publicKey, this.connection);
     return await super.initializeProtocol(treasury);
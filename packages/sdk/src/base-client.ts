@@ -1,8 +1,11 @@
 import * as anchor from '@coral-xyz/anchor';
 import {
+  AccountMeta,
   Commitment,
   ComputeBudgetProgram,
   Connection,
+  Ed25519Program,
+  Keypair,
   PublicKey,
   Transaction,
   TransactionInstruction,
@@ -36,6 +39,12 @@ import { Baskt } from './program/types';
 import { stringToRole, toRoleString } from './utils/acl-helper';
 import { createLookupTableInstructions, extendLookupTable } from './utils/lookup-table-helper';
 
+export type OracleSource = anchor.IdlTypes<Baskt>['oracleSource'];
+export type OracleConfig = anchor.IdlTypes<Baskt>['oracleConfig'];
+
+/** Prefix of the message an oracle key signs to publish a price (see `PriceFeed::signed_message`) */
+export const PRICE_MESSAGE_PREFIX = Buffer.from('baskt:price_feed:v1');
+
 /**
  * Abstract base client for Solana programs
  * Provides common functionality for program interaction
@@ -50,6 +59,8 @@ export abstract class BaseClient {
   public poolAuthorityPDA: PublicKey;
   public programAuthorityPDA: PublicKey;
   public liquidityPoolPDA: PublicKey;
+  public insuranceFundPDA: PublicKey;
+  public insuranceVaultPDA: PublicKey;
 
   // Helpers
 
@@ -98,7 +109,16 @@ export abstract class BaseClient {
       [Buffer.from('pool_authority'), this.liquidityPoolPDA.toBuffer(), this.protocolPDA.toBuffer()],
       this.program.programId,
     );
-    
+
+    // Derive insurance fund and vault PDAs
+    [this.insuranceFundPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('insurance_fund')],
+      this.program.programId,
+    );
+    [this.insuranceVaultPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('insurance_vault')],
+      this.program.programId,
+    );
   }
 
   abstract getPublicKey(): PublicKey;
@@ -349,6 +369,7 @@ export abstract class BaseClient {
    * Add a synthetic asset
    * @param ticker Asset ticker symbol
    * @param permissions Permissions for the asset
+   * @param oracleSources Oracle sources aggregated into the asset price
    * @returns Transaction signature and asset PDA
    */
   public async addAsset(
@@ -357,6 +378,7 @@ export abstract class BaseClient {
       allowLongs: true,
       allowShorts: true,
     },
+    oracleSources: OracleSource[],
   ): Promise<{ txSignature: string; assetAddress: PublicKey }> {
     // Find the asset PDA
     const assetAddress = this.getAssetPDA(ticker);
@@ -385,6 +407,7 @@ export abstract class BaseClient {
       .addAsset({
         ticker,
         permissions: optimizedPermissions,
+        oracleSources,
       })
       .accounts({
         admin: this.getPublicKey(),
@@ -395,6 +418,228 @@ export abstract class BaseClient {
     return { txSignature: await this.sendAndConfirmLegacy(tx), assetAddress };
   }
 
+  /**
+   * Grow an asset created before oracle configs existed to the current account layout
+   * @param ticker Asset ticker symbol
+   * @param oracleConfig Oracle sources and policy for the asset
+   * @returns Transaction signature
+   */
+  public async migrateAsset(ticker: string, oracleConfig: OracleConfig): Promise<string> {
+    return await this.sendAndConfirmRpc(
+      this.program.methods.migrateAsset({ ticker, oracleConfig }).accountsPartial({
+        authority: this.getPublicKey(),
+        asset: this.getAssetPDA(ticker),
+        protocol: this.protocolPDA,
+      }),
+    );
+  }
+
+  /**
+   * Set the admin price read by an asset's manual oracle source
+   * @param asset Asset PDA
+   * @param price Price with PRICE_PRECISION
+   * @returns Transaction signature
+   */
+  public async setManualPrice(asset: PublicKey, price: BN): Promise<string> {
+    return await this.sendAndConfirmRpc(
+      this.program.methods.setManualPrice(price).accountsPartial({
+        authority: this.getPublicKey(),
+        asset,
+        protocol: this.protocolPDA,
+      }),
+    );
+  }
+
+  public getPriceFeedPDA(asset: PublicKey): PublicKey {
+    const [priceFeedPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('price_feed'), asset.toBuffer()],
+      this.program.programId,
+    );
+    return priceFeedPDA;
+  }
+
+  public async getPriceFeed(priceFeed: PublicKey, commitment: Commitment = 'confirmed') {
+    return await this.program.account.priceFeed.fetch(priceFeed, commitment);
+  }
+
+  /**
+   * Create the protocol-owned price feed of an asset
+   * @param asset Asset PDA
+   * @returns Transaction signature and price feed PDA
+   */
+  public async initializePriceFeed(
+    asset: PublicKey,
+  ): Promise<{ txSignature: string; priceFeed: PublicKey }> {
+    const priceFeed = this.getPriceFeedPDA(asset);
+    const txSignature = await this.sendAndConfirmRpc(
+      this.program.methods.initializePriceFeed().accountsPartial({
+        authority: this.getPublicKey(),
+        asset,
+        priceFeed,
+        protocol: this.protocolPDA,
+      }),
+    );
+    return { txSignature, priceFeed };
+  }
+
+  /**
+   * Publish a price directly as an Oracle role holder
+   * @param priceFeed Price feed PDA
+   * @param params Price and confidence with PRICE_PRECISION, publish time in unix seconds
+   * @returns Transaction signature
+   */
+  public async updatePriceFeed(
+    priceFeed: PublicKey,
+    params: { price: BN; conf: BN; publishTime: BN },
+  ): Promise<string> {
+    return await this.sendAndConfirmRpc(
+      this.program.methods.updatePriceFeed(params).accountsPartial({
+        authority: this.getPublicKey(),
+        priceFeed,
+        protocol: this.protocolPDA,
+      }),
+    );
+  }
+
+  /**
+   * Bytes an oracle key signs to publish a price off-chain:
+   * prefix || price feed || price (u64 LE) || conf (u64 LE) || publish time (i64 LE)
+   */
+  public getPriceFeedSignedMessage(
+    priceFeed: PublicKey,
+    params: { price: BN; conf: BN; publishTime: BN },
+  ): Buffer {
+    return Buffer.concat([
+      PRICE_MESSAGE_PREFIX,
+      priceFeed.toBuffer(),
+      params.price.toArrayLike(Buffer, 'le', 8),
+      params.conf.toArrayLike(Buffer, 'le', 8),
+      params.publishTime.toTwos(64).toArrayLike(Buffer, 'le', 8),
+    ]);
+  }
+
+  /**
+   * Publish a price signed off-chain by an Oracle role holder. The signature is verified by
+   * the ed25519 precompile instruction placed right before `post_signed_price`, and any
+   * account can pay for the transaction.
+   * @param priceFeed Price feed PDA
+   * @param oracle Oracle role holder signing the price
+   * @param params Price and confidence with PRICE_PRECISION, publish time in unix seconds
+   * @returns Transaction signature
+   */
+  public async postSignedPrice(
+    priceFeed: PublicKey,
+    oracle: Keypair,
+    params: { price: BN; conf: BN; publishTime: BN },
+  ): Promise<string> {
+    const ed25519Instruction = Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: oracle.secretKey,
+      message: this.getPriceFeedSignedMessage(priceFeed, params),
+    });
+
+    return await this.sendAndConfirmRpc(
+      this.program.methods
+        .postSignedPrice(params)
+        .accountsPartial({
+          payer: this.getPublicKey(),
+          priceFeed,
+          protocol: this.protocolPDA,
+        })
+        .preInstructions([ed25519Instruction]),
+    );
+  }
+
+  /**
+   * Accounts the program reads the baskt NAV from, passed as `remaining_accounts`: for
+   * every asset of the baskt, the asset followed by the account of each oracle source
+   * that needs one (the protocol price feed, or the Pyth price update given in
+   * `pythPriceUpdates` keyed by hex feed id)
+   */
+  public async getOracleAccounts(
+    basktId: PublicKey,
+    pythPriceUpdates: Map<string, PublicKey> = new Map(),
+  ): Promise<AccountMeta[]> {
+    const baskt = await this.getBasktRaw(basktId);
+    const accounts: AccountMeta[] = [];
+    for (const config of baskt.currentAssetConfigs) {
+      accounts.push({ pubkey: config.assetId, isSigner: false, isWritable: false });
+      const asset = await this.getAssetRaw(config.assetId);
+      for (const source of asset.oracleConfig.sources) {
+        if ('priceFeed' in source) {
+          accounts.push({
+            pubkey: this.getPriceFeedPDA(config.assetId),
+            isSigner: false,
+            isWritable: false,
+          });
+        } else if ('pyth' in source) {
+          accounts.push(this.getPythPriceUpdate(pythPriceUpdates, source.pyth.feedId));
+        }
+      }
+    }
+    return accounts;
+  }
+
+  private getPythPriceUpdate(
+    pythPriceUpdates: Map<string, PublicKey>,
+    feedId: number[],
+  ): AccountMeta {
+    const key = Buffer.from(feedId).toString('hex');
+    const priceUpdate = pythPriceUpdates.get(key);
+    if (!priceUpdate) {
+      throw new Error(`Missing Pyth price update for feed ${key}`);
+    }
+    return { pubkey: priceUpdate, isSigner: false, isWritable: false };
+  }
+
+  /**
+   * Resolve an optional account: the address if the account exists, otherwise null so the
+   * program receives `None`
+   */
+  public async getOptionalAccount(address: PublicKey): Promise<PublicKey | null> {
+    const info = await this.connection.getAccountInfo(address, 'confirmed');
+    return info ? address : null;
+  }
+
+  public getNavHistoryPDA(baskt: PublicKey): PublicKey {
+    const [navHistoryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('nav_history'), baskt.toBuffer()],
+      this.program.programId,
+    );
+    return navHistoryPDA;
+  }
+
+  public getBasktPricePDA(baskt: PublicKey): PublicKey {
+    const [basktPricePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('baskt_price'), baskt.toBuffer()],
+      this.program.programId,
+    );
+    return basktPricePDA;
+  }
+
+  public getAdlRankingPDA(baskt: PublicKey): PublicKey {
+    const [adlRankingPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('adl_ranking'), baskt.toBuffer()],
+      this.program.programId,
+    );
+    return adlRankingPDA;
+  }
+
+  public getMarginAccountPDA(owner: PublicKey): PublicKey {
+    const [marginAccountPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('margin_account'), owner.toBuffer()],
+      this.program.programId,
+    );
+    return marginAccountPDA;
+  }
+
+  public getMarginEscrowPDA(marginAccount: PublicKey): PublicKey {
+    const [marginEscrowPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('margin_escrow'), marginAccount.toBuffer()],
+      this.program.programId,
+    );
+    return marginEscrowPDA;
+  }
+
   /**
    * Implementation of the abstract getProtocolAddress method from BaseClient
    * @returns The protocol PDA public key
@@ -515,13 +760,16 @@ export abstract class BaseClient {
     
     // Check if orderTypeParams exists and has the correct structure
     if (order.orderTypeParams && typeof order.orderTypeParams === 'object') {
-      if ('market' in order.orderTypeParams) {
-        marketParams = {};
+      if ('market' in order.orderTypeParams && order.orderTypeParams.market['0']) {
+        // The slippage guard lives outside the enum so earlier market orders keep their layout
+        marketParams = {
+          referencePrice: order.slippageGuard.referencePrice,
+          maxSlippageBps: order.slippageGuard.maxSlippageBps,
+        };
       } else if ('limit' in order.orderTypeParams && order.orderTypeParams.limit['0']) {
         const limitData = order.orderTypeParams.limit['0'];
         limitParams = {
           limitPrice: limitData.limitPrice,
-          maxSlippageBps: limitData.maxSlippageBps,
         };
       }
     }
@@ -933,6 +1181,7 @@ export abstract class BaseClient {
         baskt: basktId,
         payer: this.getPublicKey(),
         protocol: this.protocolPDA,
+        basktPrice: await this.getOptionalAccount(this.getBasktPricePDA(basktId)),
       });
 
     const itx = await txBuilder.instruction();
@@ -1239,6 +1488,27 @@ export abstract class BaseClient {
     };
   }
 
+  /**
+   * Initialize the insurance fund and its vault (Owner only)
+   * @returns Transaction signature
+   */
+  public async initializeInsuranceFund(): Promise<string> {
+    return await this.sendAndConfirmRpc(
+      this.program.methods.initializeInsuranceFund().accountsPartial({
+        admin: this.getPublicKey(),
+        protocol: this.protocolPDA,
+        insuranceFund: this.insuranceFundPDA,
+        insuranceVault: this.insuranceVaultPDA,
+        collateralMint: USDC_MINT,
+        programAuthority: this.programAuthorityPDA,
+      }),
+    );
+  }
+
+  public async getInsuranceFund(commitment: Commitment = 'confirmed') {
+    return await this.program.account.insuranceFund.fetch(this.insuranceFundPDA, commitment);
+  }
+
 
 
   /**
@@ -1258,9 +1528,13 @@ export abstract class BaseClient {
     // Close order parameters
     sizeAsContracts?: BN;
     targetPosition?: PublicKey;
-    // Limit order parameters
+    reduceOnly?: boolean;
+    // Limit price for limit orders; optional slippage reference price for market orders
     limitPrice?: BN;
+    // Market order slippage guard around limitPrice
     maxSlippageBps?: BN;
+    // Unix timestamp after which the order can no longer be filled
+    expiresAt?: BN;
     // Order type
     action: OrderAction ;
     orderType: OrderType;
@@ -1285,9 +1559,19 @@ export abstract class BaseClient {
         leverageBps: params.leverageBps || new BN(10000),
         orderType: params.orderType === OrderType.Market ? { market: {} } : { limit: {} },
         sizeAsContracts: params.sizeAsContracts || null,
+        triggerPrice: new BN(0),
+        triggerDirection: { above: {} },
+        expiresAt: params.expiresAt || null,
+        reduceOnly: params.reduceOnly || false,
+        trailBps: new BN(0),
+        trancheCount: 0,
+        trancheIntervalSeconds: 0,
       })
       .accountsPartial({
+        authority: owner,
         owner,
+        tradingDelegate: null,
+        targetPosition: params.targetPosition || null,
         baskt: params.basktId,
         ownerCollateralAccount: params.ownerTokenAccount,
         collateralMint: USDC_MINT,
@@ -1313,6 +1597,9 @@ export abstract class BaseClient {
     isLong: boolean;
     leverageBps: BN;
     ownerTokenAccount: PublicKey;
+    // Optional slippage guard: fills must stay within maxSlippageBps of referencePrice
+    referencePrice?: BN;
+    maxSlippageBps?: BN;
   }): Promise<string> {
     return await this.createOrder({
       orderId: params.orderId,
@@ -1322,6 +1609,8 @@ export abstract class BaseClient {
       collateral: params.collateral,
       isLong: params.isLong,
       leverageBps: params.leverageBps,
+      limitPrice: params.referencePrice,
+      maxSlippageBps: params.maxSlippageBps,
       action: OrderAction.Open,
       orderType: OrderType.Market,
     });
@@ -1340,7 +1629,6 @@ export abstract class BaseClient {
     isLong: boolean;
     leverageBps: BN;
     limitPrice: BN;
-    maxSlippageBps: BN;
     ownerTokenAccount: PublicKey;
   }): Promise<string> {
     return await this.createOrder({
@@ -1352,7 +1640,6 @@ export abstract class BaseClient {
       isLong: params.isLong,
       leverageBps: params.leverageBps,
       limitPrice: params.limitPrice,
-      maxSlippageBps: params.maxSlippageBps,
       action: OrderAction.Open,
       orderType: OrderType.Limit,
     });
@@ -1369,6 +1656,9 @@ export abstract class BaseClient {
     sizeAsContracts: BN;
     targetPosition: PublicKey;
     ownerTokenAccount: PublicKey;
+    reduceOnly?: boolean;
+    referencePrice?: BN;
+    maxSlippageBps?: BN;
   }): Promise<string> {
     return await this.createOrder({
       orderId: params.orderId,
@@ -1376,6 +1666,9 @@ export abstract class BaseClient {
       ownerTokenAccount: params.ownerTokenAccount,
       sizeAsContracts: params.sizeAsContracts,
       targetPosition: params.targetPosition,
+      reduceOnly: params.reduceOnly,
+      limitPrice: params.referencePrice,
+      maxSlippageBps: params.maxSlippageBps,
       action: OrderAction.Close,
       orderType: OrderType.Market,
     });
@@ -1392,8 +1685,8 @@ export abstract class BaseClient {
     sizeAsContracts: BN;
     targetPosition: PublicKey;
     limitPrice: BN;
-    maxSlippageBps: BN;
     ownerTokenAccount: PublicKey;
+    reduceOnly?: boolean;
   }): Promise<string> {
     return await this.createOrder({
       orderId: params.orderId,
@@ -1402,8 +1695,8 @@ export abstract class BaseClient {
       sizeAsContracts: params.sizeAsContracts,
       targetPosition: params.targetPosition,
       limitPrice: params.limitPrice,
-      maxSlippageBps: params.maxSlippageBps,
-        action: OrderAction.Close,
+      reduceOnly: params.reduceOnly,
+      action: OrderAction.Close,
       orderType: OrderType.Limit,
     });
   }
@@ -1419,7 +1712,9 @@ export abstract class BaseClient {
     const tx = await this.program.methods
       .cancelOrder()
       .accountsPartial({
+        authority: owner,
         owner,
+        tradingDelegate: null,
         order: orderPDA,
         ownerCollateralAccount: ownerTokenAccount,        
         protocol: this.protocolPDA,
@@ -1735,7 +2030,9 @@ export abstract class BaseClient {
           orderEscrow: orderEscrow,
           treasuryToken: treasuryTokenAccount,
           usdcVault: tokenVault,
+          navHistory: await this.getOptionalAccount(this.getNavHistoryPDA(params.baskt)),
         })
+        .remainingAccounts(await this.getOracleAccounts(params.baskt))
         .preInstructions(params.preInstructions || []),
     );
   }
@@ -1765,6 +2062,19 @@ export abstract class BaseClient {
     return positionEscrowPDA;
   }
 
+  /**
+   * Margin account and escrow a position settles against, or nulls for an isolated position
+   */
+  private getPositionMarginAccounts(position: { marginAccount?: PublicKey | null }) {
+    if (!position.marginAccount) {
+      return { marginAccount: null, marginEscrow: null };
+    }
+    return {
+      marginAccount: position.marginAccount,
+      marginEscrow: this.getMarginEscrowPDA(position.marginAccount),
+    };
+  }
+
   public async addCollateral(params: {
     position: PublicKey;
     additionalCollateral: BN;
@@ -1777,7 +2087,9 @@ export abstract class BaseClient {
       this.program.methods
         .addCollateral({ additionalCollateral: params.additionalCollateral })
         .accountsPartial({
+          authority: this.getPublicKey(),
           owner: this.getPublicKey(),
+          tradingDelegate: null,
           ownerCollateralAccount: params.ownerTokenAccount,
           position: params.position,
           protocol: protocol, // Explicitly provide protocol for constraint checking
@@ -1801,6 +2113,20 @@ export abstract class BaseClient {
     // Get token vault for validation
     const tokenVault = (await this.getUsdcVaultPda())[0];
     const orderOwner = params.orderOwner || this.getPublicKey();
+    const positionAccount = await this.program.account.position.fetch(params.position, 'confirmed');
+    const marginAccounts = this.getPositionMarginAccounts(positionAccount);
+
+    // A grouped order cancels its siblings, which follow the oracle accounts
+    const order = await this.program.account.order.fetch(params.orderPDA, 'confirmed');
+    const remainingAccounts = await this.getOracleAccounts(params.baskt);
+    if (order.orderGroup) {
+      const group = await this.program.account.orderGroup.fetch(order.orderGroup, 'confirmed');
+      for (const member of group.orders) {
+        if (!member.equals(params.orderPDA)) {
+          remainingAccounts.push({ pubkey: member, isSigner: false, isWritable: true });
+        }
+      }
+    }
 
     return await this.sendAndConfirmRpc(
       this.program.methods
@@ -1815,7 +2141,13 @@ export abstract class BaseClient {
           ownerCollateralAccount: params.ownerTokenAccount,
           treasuryToken: params.treasuryTokenAccount,
           usdcVault: tokenVault,
-        }),
+          insuranceFund: this.insuranceFundPDA,
+          insuranceVault: this.insuranceVaultPDA,
+          ...marginAccounts,
+          orderGroup: order.orderGroup ?? null,
+          navHistory: await this.getOptionalAccount(this.getNavHistoryPDA(params.baskt)),
+        })
+        .remainingAccounts(remainingAccounts),
     );
   }
   public async liquidatePosition(params: {
@@ -1853,9 +2185,13 @@ export abstract class BaseClient {
           ownerCollateralAccount: params.ownerTokenAccount,
           treasuryToken: params.treasuryTokenAccount,
           usdcVault: tokenVault,
+          insuranceFund: this.insuranceFundPDA,
+          insuranceVault: this.insuranceVaultPDA,
           programAuthority: programAuthorityPDA,
           poolAuthority: poolAuthorityPDA,
-        }),
+          navHistory: await this.getOptionalAccount(this.getNavHistoryPDA(params.baskt)),
+        })
+        .remainingAccounts(await this.getOracleAccounts(params.baskt)),
     );
   }
 
@@ -1873,6 +2209,7 @@ export abstract class BaseClient {
     // Find liquidity pool and get token vault
     const liquidityPool = await this.getLiquidityPool();
     const tokenVault = liquidityPool.usdcVault;
+    const positionAccount = await this.program.account.position.fetch(params.position, 'confirmed');
 
     return await this.program.methods
       .forceClosePosition({
@@ -1887,7 +2224,11 @@ export abstract class BaseClient {
         ownerCollateralAccount: params.ownerTokenAccount,
         treasuryToken: params.treasuryTokenAccount,
         usdcVault: tokenVault,
+        insuranceFund: this.insuranceFundPDA,
+        insuranceVault: this.insuranceVaultPDA,
+        ...this.getPositionMarginAccounts(positionAccount),
       })
+      .remainingAccounts(await this.getOracleAccounts(params.baskt))
       .rpc();
   }
 
@@ -1976,11 +2317,18 @@ export abstract class BaseClient {
       liquidationFeeBps?: number | null;
       minCollateralRatioBps?: number | null;
       liquidationThresholdBps?: number | null;
+      maxPriceDeviationBps?: number | null;
+      liquidationTwapWindowSeconds?: number | null;
+      circuitBreaker?: anchor.IdlTypes<Baskt>['circuitBreakerConfig'] | null;
     },
   ): Promise<string> {
     // First, get the current config to preserve unspecified fields
     const currentBaskt = await this.getBasktRaw(baskt, 'confirmed');
     const currentConfig = currentBaskt.config;
+    // Risk overrides are only set when their flag bit is
+    const currentRisk = currentBaskt.riskConfig;
+    const riskOverride = <T>(bit: number, value: T): T | null =>
+      currentRisk.flags & bit ? value : null;
 
     // Build the update parameters, preserving existing values for unspecified fields
     const updateParams = {
@@ -2014,6 +2362,22 @@ export abstract class BaseClient {
             ? new BN(params.liquidationThresholdBps)
             : null
           : currentConfig.liquidationThresholdBps,
+      maxPriceDeviationBps:
+        params.maxPriceDeviationBps !== undefined
+          ? params.maxPriceDeviationBps !== null
+            ? new BN(params.maxPriceDeviationBps)
+            : null
+          : riskOverride(0x01, currentRisk.maxPriceDeviationBps),
+      liquidationTwapWindowSeconds:
+        params.liquidationTwapWindowSeconds !== undefined
+          ? params.liquidationTwapWindowSeconds !== null
+            ? new BN(params.liquidationTwapWindowSeconds)
+            : null
+          : riskOverride(0x02, currentRisk.liquidationTwapWindowSeconds),
+      circuitBreaker:
+        params.circuitBreaker !== undefined
+          ? params.circuitBreaker
+          : riskOverride(0x04, currentRisk.circuitBreaker),
     };
 
     return await this.sendAndConfirmRpc(
@@ -1,5 +1,6 @@
 // Export the clients
-export { BaseClient } from './base-client';
+export { BaseClient, PRICE_MESSAGE_PREFIX } from './base-client';
+export type { OracleSource } from './base-client';
 
 // Export utility modules
 export * from './utils/';
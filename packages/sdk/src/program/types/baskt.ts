@@ -179,10 +179,56 @@ export type Baskt = {
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "authority",
+          "docs": [
+            "Owner, or a trading delegate of the owner; funds the collateral"
+          ],
           "writable": true,
           "signer": true
         },
+        {
+          "name": "owner"
+        },
+        {
+          "name": "tradingDelegate",
+          "docs": [
+            "Required when the authority is not the owner"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
         {
           "name": "position",
           "writable": true,
@@ -215,6 +261,9 @@ export type Baskt = {
         },
         {
           "name": "ownerCollateralAccount",
+          "docs": [
+            "Token account funding the collateral, held by the authority"
+          ],
           "writable": true
         },
         {
@@ -546,21 +595,20 @@ export type Baskt = {
       ]
     },
     {
-      "name": "cancelOrder",
+      "name": "amendOrder",
       "discriminator": [
-        95,
-        129,
-        237,
-        240,
-        8,
-        49,
-        223,
-        132
+        159,
+        216,
+        157,
+        142,
+        199,
+        245,
+        224,
+        180
       ],
       "accounts": [
         {
           "name": "owner",
-          "writable": true,
           "signer": true
         },
         {
@@ -590,6 +638,12 @@ export type Baskt = {
             ]
           }
         },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt the order trades, for its collateral and fee configuration"
+          ]
+        },
         {
           "name": "ownerCollateralAccount",
           "writable": true
@@ -671,209 +725,185 @@ export type Baskt = {
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "amendOrderParams"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "closeBaskt",
+      "name": "attachMarginPosition",
       "discriminator": [
-        179,
-        197,
-        59,
-        154,
-        196,
-        169,
-        95,
-        50
+        96,
+        84,
+        119,
+        116,
+        254,
+        228,
+        76,
+        4
       ],
       "accounts": [
         {
-          "name": "baskt",
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "marginAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
+                  109,
                   97,
-                  115,
-                  107,
+                  114,
+                  103,
+                  105,
+                  110,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "baskt.uid",
-                "account": "baskt"
+                "path": "owner"
               }
             ]
           }
         },
         {
-          "name": "creator",
-          "docs": [
-            "Creator of the baskt - receives rent when closed"
-          ],
-          "writable": true
-        },
-        {
-          "name": "authority",
-          "docs": [
-            "Authority that can close baskt (BasktManager)"
-          ],
-          "signer": true
-        },
-        {
-          "name": "protocol",
+          "name": "position",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   112,
-                  114,
                   111,
+                  115,
+                  105,
                   116,
+                  105,
                   111,
-                  99,
-                  111,
-                  108
+                  110
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "position.position_id",
+                "account": "position"
               }
             ]
           }
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "closePosition",
-      "discriminator": [
-        123,
-        134,
-        81,
-        0,
-        49,
-        68,
-        98,
-        98
-      ],
-      "accounts": [
-        {
-          "name": "matcher",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "order",
+          "name": "positionEscrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  111,
-                  114,
-                  100,
                   101,
-                  114
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "order.owner",
-                "account": "order"
-              },
-              {
-                "kind": "account",
-                "path": "order.order_id",
-                "account": "order"
+                "path": "position"
               }
             ]
           }
         },
         {
-          "name": "orderOwner",
-          "writable": true
-        },
-        {
-          "name": "position",
+          "name": "marginEscrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  115,
-                  105,
-                  116,
+                  109,
+                  97,
+                  114,
+                  103,
                   105,
+                  110,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
                   111,
-                  110
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "position.owner",
-                "account": "position"
-              },
-              {
-                "kind": "account",
-                "path": "position.position_id",
-                "account": "position"
+                "path": "marginAccount"
               }
             ]
           }
         },
         {
-          "name": "baskt",
-          "writable": true
-        },
-        {
-          "name": "protocol",
+          "name": "programAuthority",
           "docs": [
-            "Protocol for permission checks"
+            "PDA used for token authority over escrow"
           ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  97,
+                  117,
                   116,
+                  104,
                   111,
-                  99,
-                  111,
-                  108
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               }
             ]
           }
         },
         {
-          "name": "liquidityPool",
-          "writable": true,
+          "name": "protocol",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  105,
-                  113,
-                  117,
-                  105,
-                  100,
-                  105,
-                  116,
-                  121,
-                  95,
                   112,
+                  114,
+                  111,
+                  116,
                   111,
+                  99,
                   111,
                   108
                 ]
@@ -882,18 +912,169 @@ export type Baskt = {
           }
         },
         {
-          "name": "treasury"
-        },
-        {
-          "name": "ownerCollateralEscrowAccount",
-          "docs": [
-            "Position escrow token account"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "autoDeleveragePosition",
+      "discriminator": [
+        162,
+        29,
+        95,
+        34,
+        208,
+        172,
+        89,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "keeper",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position.owner",
+                "account": "position"
+              },
+              {
+                "kind": "account",
+                "path": "position.position_id",
+                "account": "position"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baskt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "adlRanking",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  108,
+                  95,
+                  114,
+                  97,
+                  110,
+                  107,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "liquidityPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "ownerCollateralEscrowAccount",
+          "docs": [
+            "Position escrow token account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
                 "value": [
                   101,
                   115,
@@ -913,28 +1094,28 @@ export type Baskt = {
         {
           "name": "ownerCollateralAccount",
           "docs": [
-            "User's collateral token account to receive settlement"
+            "User's collateral token account to receive the payout"
           ],
           "writable": true
         },
         {
-          "name": "treasuryToken",
+          "name": "usdcVault",
           "docs": [
-            "Protocol treasury token account for fee collection"
+            "BLP token vault for liquidity pool"
           ],
           "writable": true
         },
         {
-          "name": "usdcVault",
+          "name": "treasuryToken",
           "docs": [
-            "BLP token vault for liquidity pool fees"
+            "Protocol treasury token account for the settled rebalance fee"
           ],
           "writable": true
         },
         {
           "name": "programAuthority",
           "docs": [
-            "PDA used for token authority over escrow - still needed for CPI signing"
+            "Program authority PDA"
           ],
           "pda": {
             "seeds": [
@@ -989,6 +1170,53 @@ export type Baskt = {
             ]
           }
         },
+        {
+          "name": "marginAccount",
+          "docs": [
+            "Margin account of a cross-margined position, whose shared collateral backs the close"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "marginEscrow",
+          "docs": [
+            "Token account holding the margin account's shared collateral"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "navHistory",
+          "docs": [
+            "NAV history, required when the baskt has a circuit breaker configured"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  97,
+                  118,
+                  95,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -999,116 +1227,76 @@ export type Baskt = {
           "name": "params",
           "type": {
             "defined": {
-              "name": "closePositionParams"
+              "name": "autoDeleverageParams"
             }
           }
         }
       ]
     },
     {
-      "name": "createBaskt",
+      "name": "cancelOrder",
       "discriminator": [
-        179,
-        137,
-        71,
+        95,
+        129,
+        237,
+        240,
         8,
-        178,
-        191,
-        25,
-        167
+        49,
+        223,
+        132
       ],
       "accounts": [
         {
-          "name": "baskt",
-          "writable": true,
+          "name": "authority",
+          "docs": [
+            "Owner, or a trading delegate of the owner"
+          ],
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "writable": true
+        },
+        {
+          "name": "tradingDelegate",
+          "docs": [
+            "Required when the authority is not the owner"
+          ],
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
+                  116,
+                  114,
                   97,
-                  115,
-                  107,
-                  116
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
                 ]
               },
               {
-                "kind": "arg",
-                "path": "params.uid"
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
               }
             ]
           }
         },
-        {
-          "name": "creator",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "protocol",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
-                  108
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "treasury",
-          "docs": [
-            "Treasury account to receive the fee"
-          ],
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "docs": [
-            "System program for SOL transfers"
-          ],
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "createBasktParams"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "createOrder",
-      "discriminator": [
-        141,
-        54,
-        37,
-        207,
-        237,
-        210,
-        250,
-        215
-      ],
-      "accounts": [
-        {
-          "name": "owner",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "order",
           "writable": true,
@@ -1129,25 +1317,17 @@ export type Baskt = {
                 "path": "owner"
               },
               {
-                "kind": "arg",
-                "path": "params.order_id"
+                "kind": "account",
+                "path": "order.order_id",
+                "account": "order"
               }
             ]
           }
         },
-        {
-          "name": "baskt",
-          "docs": [
-            "Baskt account"
-          ]
-        },
         {
           "name": "ownerCollateralAccount",
           "writable": true
         },
-        {
-          "name": "collateralMint"
-        },
         {
           "name": "ownerCollateralEscrowAccount",
           "writable": true,
@@ -1220,41 +1400,24 @@ export type Baskt = {
             ]
           }
         },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "createOrderParams"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "decommissionBaskt",
+      "name": "closeBaskt",
       "discriminator": [
-        18,
-        111,
-        38,
-        100,
-        153,
-        152,
-        218,
-        148
+        179,
+        197,
+        59,
+        154,
+        196,
+        169,
+        95,
+        50
       ],
       "accounts": [
         {
@@ -1280,10 +1443,17 @@ export type Baskt = {
             ]
           }
         },
+        {
+          "name": "creator",
+          "docs": [
+            "Creator of the baskt - receives rent when closed"
+          ],
+          "writable": true
+        },
         {
           "name": "authority",
           "docs": [
-            "Authority that can decommission (BasktManager or Owner for emergency)"
+            "Authority that can close baskt (BasktManager)"
           ],
           "signer": true
         },
@@ -1311,78 +1481,95 @@ export type Baskt = {
       "args": []
     },
     {
-      "name": "forceClosePosition",
+      "name": "closePosition",
       "discriminator": [
-        109,
-        177,
-        151,
-        242,
-        227,
-        130,
-        79,
-        37
+        123,
+        134,
+        81,
+        0,
+        49,
+        68,
+        98,
+        98
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "matcher",
+          "writable": true,
           "signer": true
         },
         {
-          "name": "position",
+          "name": "order",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  115,
-                  105,
-                  116,
-                  105,
                   111,
-                  110
+                  114,
+                  100,
+                  101,
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "position.owner",
-                "account": "position"
+                "path": "order.owner",
+                "account": "order"
               },
               {
                 "kind": "account",
-                "path": "position.position_id",
-                "account": "position"
+                "path": "order.order_id",
+                "account": "order"
               }
             ]
           }
         },
         {
-          "name": "baskt",
+          "name": "orderOwner",
+          "writable": true
+        },
+        {
+          "name": "position",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  97,
+                  112,
+                  111,
                   115,
-                  107,
-                  116
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "baskt.uid",
-                "account": "baskt"
+                "path": "position.owner",
+                "account": "position"
+              },
+              {
+                "kind": "account",
+                "path": "position.position_id",
+                "account": "position"
               }
             ]
           }
         },
+        {
+          "name": "baskt",
+          "writable": true
+        },
         {
           "name": "protocol",
+          "docs": [
+            "Protocol for permission checks"
+          ],
           "pda": {
             "seeds": [
               {
@@ -1403,9 +1590,6 @@ export type Baskt = {
         },
         {
           "name": "liquidityPool",
-          "docs": [
-            "Liquidity pool to update state"
-          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -1431,6 +1615,9 @@ export type Baskt = {
             ]
           }
         },
+        {
+          "name": "treasury"
+        },
         {
           "name": "ownerCollateralEscrowAccount",
           "docs": [
@@ -1465,59 +1652,143 @@ export type Baskt = {
           "writable": true
         },
         {
-          "name": "usdcVault",
+          "name": "treasuryToken",
           "docs": [
-            "BLP token vault for liquidity pool"
+            "Protocol treasury token account for fee collection"
           ],
           "writable": true
         },
         {
-          "name": "treasuryToken",
+          "name": "usdcVault",
           "docs": [
-            "Protocol treasury token account for fee collection"
+            "BLP token vault for liquidity pool fees"
           ],
           "writable": true
         },
         {
-          "name": "programAuthority",
+          "name": "insuranceFund",
           "docs": [
-            "Program authority PDA"
+            "Insurance fund absorbing bad debt ahead of the liquidity pool"
           ],
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
+                  105,
+                  110,
+                  115,
                   117,
-                  116,
-                  104,
-                  111,
                   114,
-                  105,
-                  116,
-                  121
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  102,
+                  117,
+                  110,
+                  100
                 ]
               }
             ]
           }
         },
         {
-          "name": "poolAuthority",
+          "name": "insuranceVault",
+          "docs": [
+            "Token vault holding the insurance fund balance"
+          ],
+          "writable": true
+        },
+        {
+          "name": "adlRanking",
+          "docs": [
+            "from it once closed"
+          ],
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  111,
+                  97,
+                  100,
                   108,
                   95,
+                  114,
                   97,
-                  117,
-                  116,
-                  104,
+                  110,
+                  107,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "marginAccount",
+          "docs": [
+            "Margin account of a cross-margined position, whose shared collateral backs the close"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "marginEscrow",
+          "docs": [
+            "Token account holding the margin account's shared collateral"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "programAuthority",
+          "docs": [
+            "PDA used for token authority over escrow - still needed for CPI signing"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
                   111,
                   114,
                   105,
@@ -1536,6 +1807,45 @@ export type Baskt = {
             ]
           }
         },
+        {
+          "name": "orderGroup",
+          "docs": [
+            "One-cancels-other group of the order, required when the order is grouped"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "navHistory",
+          "docs": [
+            "NAV history, required when the baskt has a circuit breaker configured"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  97,
+                  118,
+                  95,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -1546,80 +1856,65 @@ export type Baskt = {
           "name": "params",
           "type": {
             "defined": {
-              "name": "forceClosePositionParams"
+              "name": "closePositionParams"
             }
           }
         }
       ]
     },
     {
-      "name": "initializeLiquidityPool",
+      "name": "createBaskt",
       "discriminator": [
-        155,
-        18,
-        138,
-        107,
-        111,
-        23,
+        179,
+        137,
+        71,
+        8,
         178,
-        178
+        191,
+        25,
+        167
       ],
       "accounts": [
         {
-          "name": "admin",
-          "docs": [
-            "Admin with Owner role who can initialize the pool"
-          ],
+          "name": "baskt",
           "writable": true,
-          "signer": true
-        },
-        {
-          "name": "protocol",
-          "docs": [
-            "Protocol account to verify admin role",
-            "@dev Requires Owner role to initialize liquidity pool"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
-                  108
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.uid"
               }
             ]
           }
         },
         {
-          "name": "liquidityPool",
-          "docs": [
-            "Liquidity pool account to initialize"
-          ],
+          "name": "creator",
           "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  105,
-                  113,
-                  117,
-                  105,
-                  100,
-                  105,
-                  116,
-                  121,
-                  95,
                   112,
+                  114,
+                  111,
+                  116,
                   111,
+                  99,
                   111,
                   108
                 ]
@@ -1628,57 +1923,94 @@ export type Baskt = {
           }
         },
         {
-          "name": "lpMint",
+          "name": "treasury",
           "docs": [
-            "The mint that will be used for LP tokens"
+            "Treasury account to receive the fee"
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "usdcVault",
+          "name": "systemProgram",
           "docs": [
-            "The token account that will hold the pool's assets"
+            "System program for SOL transfers"
           ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "createBasktParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createMarginAccount",
+      "discriminator": [
+        98,
+        114,
+        213,
+        184,
+        129,
+        89,
+        90,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "marginAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  116,
-                  111,
-                  107,
-                  101,
+                  109,
+                  97,
+                  114,
+                  103,
+                  105,
                   110,
                   95,
-                  118,
                   97,
+                  99,
+                  99,
+                  111,
                   117,
-                  108,
+                  110,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "liquidityPool"
+                "path": "owner"
               }
             ]
           }
         },
         {
-          "name": "lpTokenEscrow",
-          "docs": [
-            "The token account that will hold LP tokens during withdrawal queue processing"
-          ],
+          "name": "marginEscrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  112,
+                  109,
+                  97,
+                  114,
+                  103,
+                  105,
+                  110,
                   95,
                   101,
                   115,
@@ -1690,29 +2022,24 @@ export type Baskt = {
               },
               {
                 "kind": "account",
-                "path": "liquidityPool"
+                "path": "marginAccount"
               }
             ]
           }
         },
         {
-          "name": "usdcMint",
-          "docs": [
-            "The mint of the token used for collateral"
-          ]
+          "name": "collateralMint"
         },
         {
-          "name": "poolAuthority",
+          "name": "programAuthority",
+          "docs": [
+            "PDA used for token authority over escrow"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  111,
-                  108,
-                  95,
                   97,
                   117,
                   116,
@@ -1723,63 +2050,12 @@ export type Baskt = {
                   116,
                   121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "liquidityPool"
-              },
-              {
-                "kind": "account",
-                "path": "protocol"
               }
             ]
           }
         },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "depositFeeBps",
-          "type": "u16"
-        },
-        {
-          "name": "withdrawalFeeBps",
-          "type": "u16"
-        }
-      ]
-    },
-    {
-      "name": "initializeProtocol",
-      "discriminator": [
-        188,
-        233,
-        252,
-        106,
-        134,
-        146,
-        202,
-        91
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "protocol",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1799,171 +2075,146 @@ export type Baskt = {
           }
         },
         {
-          "name": "collateralMint",
-          "docs": [
-            "Escrow mint (USDC)"
-          ]
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "programAuthority",
-          "docs": [
-            "Program authority PDA - created during protocol initialization"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              }
-            ]
-          }
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "treasury",
-          "type": "pubkey"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "liquidatePosition",
+      "name": "createOrder",
       "discriminator": [
-        187,
-        74,
-        229,
-        149,
-        102,
-        81,
-        221,
-        68
+        141,
+        54,
+        37,
+        207,
+        237,
+        210,
+        250,
+        215
       ],
       "accounts": [
         {
-          "name": "liquidator",
+          "name": "authority",
+          "docs": [
+            "Owner, or a trading delegate of the owner; pays rent and funds the collateral"
+          ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "position",
-          "writable": true,
+          "name": "owner"
+        },
+        {
+          "name": "tradingDelegate",
+          "docs": [
+            "Required when the authority is not the owner"
+          ],
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  115,
-                  105,
                   116,
+                  114,
+                  97,
+                  100,
                   105,
-                  111,
-                  110
+                  110,
+                  103,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "position.owner",
-                "account": "position"
+                "path": "owner"
               },
               {
                 "kind": "account",
-                "path": "position.position_id",
-                "account": "position"
+                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "baskt",
-          "writable": true
-        },
-        {
-          "name": "protocol",
-          "docs": [
-            "Protocol for permission checks"
-          ],
+          "name": "order",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
                   111,
-                  108
+                  114,
+                  100,
+                  101,
+                  114
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "arg",
+                "path": "params.order_id"
               }
             ]
           }
         },
         {
-          "name": "liquidityPool",
+          "name": "baskt",
           "docs": [
-            "Liquidity pool"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  108,
-                  105,
-                  113,
-                  117,
-                  105,
-                  100,
-                  105,
-                  116,
-                  121,
-                  95,
-                  112,
-                  111,
-                  111,
-                  108
-                ]
-              }
-            ]
-          }
+            "Baskt account"
+          ]
         },
         {
-          "name": "treasury",
+          "name": "targetPosition",
           "docs": [
-            "CHECK Treasury account"
-          ]
+            "Position targeted by close and increase orders"
+          ],
+          "optional": true
         },
         {
-          "name": "ownerCollateralEscrowAccount",
+          "name": "ownerCollateralAccount",
           "docs": [
-            "Position escrow token account"
+            "Token account funding the collateral, held by the authority"
           ],
+          "writable": true
+        },
+        {
+          "name": "collateralMint"
+        },
+        {
+          "name": "ownerCollateralEscrowAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
                   101,
                   115,
                   99,
@@ -1974,37 +2225,13 @@ export type Baskt = {
               },
               {
                 "kind": "account",
-                "path": "position"
+                "path": "owner"
               }
             ]
           }
         },
-        {
-          "name": "ownerCollateralAccount",
-          "docs": [
-            "User's collateral token account to receive settlement"
-          ],
-          "writable": true
-        },
-        {
-          "name": "treasuryToken",
-          "docs": [
-            "Protocol treasury token account for fee collection"
-          ],
-          "writable": true
-        },
-        {
-          "name": "usdcVault",
-          "docs": [
-            "BLP token vault for liquidity pool fees"
-          ],
-          "writable": true
-        },
         {
           "name": "programAuthority",
-          "docs": [
-            "PDA used for token authority over escrow"
-          ],
           "pda": {
             "seeds": [
               {
@@ -2025,42 +2252,39 @@ export type Baskt = {
           }
         },
         {
-          "name": "poolAuthority",
+          "name": "protocol",
+          "docs": [
+            "Protocol account to verify feature flags"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   112,
+                  114,
                   111,
-                  111,
-                  108,
-                  95,
-                  97,
-                  117,
                   116,
-                  104,
                   111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "liquidityPool"
-              },
-              {
-                "kind": "account",
-                "path": "protocol"
+                  99,
+                  111,
+                  108
+                ]
               }
             ]
           }
         },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
       "args": [
@@ -2068,32 +2292,32 @@ export type Baskt = {
           "name": "params",
           "type": {
             "defined": {
-              "name": "liquidatePositionParams"
+              "name": "createOrderParams"
             }
           }
         }
       ]
     },
     {
-      "name": "openPosition",
+      "name": "createOrderGroup",
       "discriminator": [
-        135,
-        128,
-        47,
-        77,
-        15,
-        152,
-        240,
-        49
+        225,
+        6,
+        49,
+        73,
+        26,
+        51,
+        236,
+        20
       ],
       "accounts": [
         {
-          "name": "matcher",
+          "name": "owner",
           "writable": true,
           "signer": true
         },
         {
-          "name": "order",
+          "name": "orderGroup",
           "writable": true,
           "pda": {
             "seeds": [
@@ -2104,69 +2328,83 @@ export type Baskt = {
                   114,
                   100,
                   101,
-                  114
+                  114,
+                  95,
+                  103,
+                  114,
+                  111,
+                  117,
+                  112
                 ]
               },
               {
                 "kind": "account",
-                "path": "order.owner",
-                "account": "order"
+                "path": "owner"
               },
               {
-                "kind": "account",
-                "path": "order.order_id",
-                "account": "order"
+                "kind": "arg",
+                "path": "groupId"
               }
             ]
           }
         },
         {
-          "name": "orderOwner",
-          "writable": true
-        },
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
         {
-          "name": "position",
+          "name": "groupId",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "decommissionBaskt",
+      "discriminator": [
+        18,
+        111,
+        38,
+        100,
+        153,
+        152,
+        218,
+        148
+      ],
+      "accounts": [
+        {
+          "name": "baskt",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
+                  98,
+                  97,
                   115,
-                  105,
-                  116,
-                  105,
-                  111,
-                  110
+                  107,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "order.owner",
-                "account": "order"
-              },
-              {
-                "kind": "arg",
-                "path": "params.position_id"
+                "path": "baskt.uid",
+                "account": "baskt"
               }
             ]
           }
         },
         {
-          "name": "baskt",
+          "name": "authority",
           "docs": [
-            "Baskt account that contains the embedded oracle for price validation"
+            "Authority that can decommission (BasktManager or Owner for emergency)"
           ],
-          "writable": true
+          "signer": true
         },
         {
           "name": "protocol",
-          "docs": [
-            "Protocol account for checking permissions",
-            "@dev Requires Matcher role to open positions"
-          ],
           "pda": {
             "seeds": [
               {
@@ -2184,74 +2422,77 @@ export type Baskt = {
               }
             ]
           }
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "depositMarginCollateral",
+      "discriminator": [
+        246,
+        10,
+        34,
+        47,
+        247,
+        52,
+        134,
+        123
+      ],
+      "accounts": [
         {
-          "name": "liquidityPool",
-          "docs": [
-            "Liquidity pool for account validation and fee accounting"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  108,
-                  105,
-                  113,
-                  117,
-                  105,
-                  100,
-                  105,
-                  116,
-                  121,
-                  95,
-                  112,
-                  111,
-                  111,
-                  108
-                ]
-              }
-            ]
-          }
+          "name": "owner",
+          "signer": true
         },
         {
-          "name": "orderEscrow",
+          "name": "marginAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
-                  101,
+                  109,
+                  97,
                   114,
+                  103,
+                  105,
+                  110,
                   95,
-                  101,
-                  115,
+                  97,
+                  99,
                   99,
-                  114,
                   111,
-                  119
+                  117,
+                  110,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "order.owner",
-                "account": "order"
+                "path": "owner"
               }
             ]
           }
         },
         {
-          "name": "ownerCollateralEscrowAccount",
+          "name": "ownerCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "marginEscrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  109,
+                  97,
+                  114,
+                  103,
+                  105,
+                  110,
+                  95,
                   101,
                   115,
                   99,
@@ -2262,7 +2503,7 @@ export type Baskt = {
               },
               {
                 "kind": "account",
-                "path": "position"
+                "path": "marginAccount"
               }
             ]
           }
@@ -2292,193 +2533,155 @@ export type Baskt = {
           }
         },
         {
-          "name": "collateralMint",
-          "docs": [
-            "Escrow mint (USDC) - validated via protocol"
-          ]
-        },
-        {
-          "name": "treasuryToken",
-          "docs": [
-            "Protocol treasury token account for fee collection"
-          ],
-          "writable": true
-        },
-        {
-          "name": "usdcVault",
-          "docs": [
-            "BLP token vault for liquidity pool fees"
-          ],
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "openPositionParams"
-            }
-          }
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "processWithdrawQueue",
+      "name": "detachMarginPosition",
       "discriminator": [
-        23,
-        100,
-        61,
-        241,
-        134,
-        190,
-        48,
-        53
+        95,
+        140,
+        27,
+        9,
+        131,
+        146,
+        59,
+        140
       ],
       "accounts": [
         {
-          "name": "keeper",
-          "writable": true,
+          "name": "owner",
           "signer": true
         },
         {
-          "name": "liquidityPool",
+          "name": "marginAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  105,
-                  113,
-                  117,
-                  105,
-                  100,
+                  109,
+                  97,
+                  114,
+                  103,
                   105,
-                  116,
-                  121,
+                  110,
                   95,
-                  112,
-                  111,
+                  97,
+                  99,
+                  99,
                   111,
-                  108
+                  117,
+                  110,
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
               }
             ]
           }
         },
         {
-          "name": "protocol",
+          "name": "position",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   112,
-                  114,
                   111,
+                  115,
+                  105,
                   116,
+                  105,
                   111,
-                  99,
-                  111,
-                  108
+                  110
                 ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "usdcVault",
-          "writable": true,
-          "pda": {
-            "seeds": [
+              },
               {
-                "kind": "const",
-                "value": [
-                  116,
-                  111,
-                  107,
-                  101,
-                  110,
-                  95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
-                ]
+                "kind": "account",
+                "path": "owner"
               },
               {
                 "kind": "account",
-                "path": "liquidityPool"
+                "path": "position.position_id",
+                "account": "position"
               }
             ]
           }
         },
         {
-          "name": "poolAuthority",
+          "name": "baskt"
+        },
+        {
+          "name": "positionEscrow",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  111,
-                  108,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
+                  101,
+                  115,
+                  99,
                   114,
-                  105,
-                  116,
-                  121
+                  111,
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "liquidityPool"
-              },
-              {
-                "kind": "account",
-                "path": "protocol"
+                "path": "position"
               }
             ]
           }
         },
         {
-          "name": "treasuryUsdcAccount",
-          "writable": true
-        },
-        {
-          "name": "lpTokenEscrow",
-          "docs": [
-            "LP token escrow account where LP tokens are held during withdrawal queue processing"
-          ],
+          "name": "marginEscrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  112,
+                  109,
+                  97,
+                  114,
+                  103,
+                  105,
+                  110,
                   95,
                   101,
                   115,
@@ -2490,86 +2693,30 @@ export type Baskt = {
               },
               {
                 "kind": "account",
-                "path": "liquidityPool"
+                "path": "marginAccount"
               }
             ]
           }
         },
         {
-          "name": "providerUsdcAccount",
-          "docs": [
-            "USDC account"
-          ],
-          "writable": true
-        },
-        {
-          "name": "withdrawRequest",
-          "writable": true
-        },
-        {
-          "name": "provider",
-          "writable": true
-        },
-        {
-          "name": "lpMint",
-          "docs": [
-            "LP token mint for burning"
-          ],
-          "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "queueWithdrawLiquidity",
-      "discriminator": [
-        180,
-        225,
-        125,
-        70,
-        9,
-        230,
-        128,
-        117
-      ],
-      "accounts": [
-        {
-          "name": "provider",
-          "docs": [
-            "The liquidity provider requesting withdrawal"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "liquidityPool",
+          "name": "programAuthority",
           "docs": [
-            "Liquidity pool state account"
+            "PDA used for token authority over escrow"
           ],
-          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  105,
-                  113,
+                  97,
                   117,
-                  105,
-                  100,
-                  105,
                   116,
-                  121,
-                  95,
-                  112,
-                  111,
+                  104,
                   111,
-                  108
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               }
             ]
@@ -2577,9 +2724,6 @@ export type Baskt = {
         },
         {
           "name": "protocol",
-          "docs": [
-            "Protocol state for feature flags validation"
-          ],
           "pda": {
             "seeds": [
               {
@@ -2599,217 +2743,122 @@ export type Baskt = {
           }
         },
         {
-          "name": "providerLpAccount",
-          "docs": [
-            "Provider's LP token account (source for burn)"
-          ],
-          "writable": true
-        },
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
         {
-          "name": "providerUsdcAccount",
-          "docs": [
-            "USDC account"
-          ]
+          "name": "collateral",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "expireOrder",
+      "discriminator": [
+        174,
+        27,
+        85,
+        247,
+        105,
+        245,
+        220,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "signer": true
         },
         {
-          "name": "lpTokenEscrow",
-          "docs": [
-            "LP token escrow account where LP tokens are held during withdrawal queue processing"
-          ],
+          "name": "order",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  112,
-                  95,
-                  101,
-                  115,
-                  99,
-                  114,
                   111,
-                  119
+                  114,
+                  100,
+                  101,
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "liquidityPool"
+                "path": "order.owner",
+                "account": "order"
+              },
+              {
+                "kind": "account",
+                "path": "order.order_id",
+                "account": "order"
               }
             ]
           }
         },
         {
-          "name": "withdrawRequest",
-          "docs": [
-            "Withdrawal request PDA"
-          ],
+          "name": "orderOwner",
           "writable": true
         },
         {
-          "name": "lpMint",
-          "docs": [
-            "LP token mint for burning"
-          ],
+          "name": "ownerCollateralAccount",
           "writable": true
         },
         {
-          "name": "poolAuthority",
+          "name": "ownerCollateralEscrowAccount",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  111,
-                  108,
-                  95,
-                  97,
                   117,
-                  116,
-                  104,
-                  111,
+                  115,
+                  101,
                   114,
-                  105,
-                  116,
-                  121
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "liquidityPool"
-              },
-              {
-                "kind": "account",
-                "path": "protocol"
+                "path": "order.owner",
+                "account": "order"
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "requestWithdrawParams"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "rebalance",
-      "discriminator": [
-        108,
-        158,
-        77,
-        9,
-        210,
-        52,
-        88,
-        62
-      ],
-      "accounts": [
-        {
-          "name": "baskt",
-          "writable": true
-        },
-        {
-          "name": "payer",
-          "docs": [
-            "@dev Requires Rebalancer role to rebalance"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "protocol",
+          "name": "programAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  97,
+                  117,
                   116,
+                  104,
                   111,
-                  99,
-                  111,
-                  108
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               }
             ]
           }
         },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "assetConfigs",
-          "type": {
-            "vec": {
-              "defined": {
-                "name": "assetConfig"
-              }
-            }
-          }
-        },
-        {
-          "name": "newNav",
-          "type": "u64"
-        },
-        {
-          "name": "rebalanceFeePerUnit",
-          "type": {
-            "option": "u64"
-          }
-        }
-      ]
-    },
-    {
-      "name": "rebalanceRequest",
-      "discriminator": [
-        114,
-        49,
-        98,
-        158,
-        41,
-        46,
-        138,
-        201
-      ],
-      "accounts": [
-        {
-          "name": "baskt"
-        },
-        {
-          "name": "creator",
-          "docs": [
-            "@dev Only the baskt creator can request a rebalance"
-          ],
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "protocol",
-          "docs": [
-            "Protocol account to get fee configuration"
-          ],
           "pda": {
             "seeds": [
               {
@@ -2829,51 +2878,31 @@ export type Baskt = {
           }
         },
         {
-          "name": "treasury",
-          "docs": [
-            "Treasury account to receive the fee"
-          ],
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "docs": [
-            "System program for SOL transfers"
-          ],
-          "address": "11111111111111111111111111111111"
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
       "args": []
     },
     {
-      "name": "removeRole",
+      "name": "forceClosePosition",
       "discriminator": [
-        74,
-        69,
-        168,
-        163,
-        248,
-        3,
+        109,
+        177,
+        151,
+        242,
+        227,
         130,
-        0
+        79,
+        37
       ],
       "accounts": [
         {
-          "name": "owner",
-          "docs": [
-            "@dev Requires Owner role to remove roles from other accounts"
-          ],
-          "writable": true,
+          "name": "authority",
           "signer": true
         },
         {
-          "name": "account",
-          "docs": [
-            "Account to remove the role from"
-          ]
-        },
-        {
-          "name": "protocol",
+          "name": "position",
           "writable": true,
           "pda": {
             "seeds": [
@@ -2881,52 +2910,30 @@ export type Baskt = {
                 "kind": "const",
                 "value": [
                   112,
-                  114,
                   111,
+                  115,
+                  105,
                   116,
+                  105,
                   111,
-                  99,
-                  111,
-                  108
+                  110
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "position.owner",
+                "account": "position"
+              },
+              {
+                "kind": "account",
+                "path": "position.position_id",
+                "account": "position"
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "roleType",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "setBasktClosingFeeBps",
-      "discriminator": [
-        221,
-        101,
-        129,
-        68,
-        37,
-        222,
-        146,
-        84
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "docs": [
-            "Authority that can modify baskt config"
-          ],
-          "writable": true,
-          "signer": true
         },
         {
           "name": "baskt",
-          "docs": [
-            "Baskt account to update"
-          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -2950,9 +2957,6 @@ export type Baskt = {
         },
         {
           "name": "protocol",
-          "docs": [
-            "Protocol account for role checking"
-          ],
           "pda": {
             "seeds": [
               {
@@ -2970,42 +2974,11 @@ export type Baskt = {
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "newClosingFeeBps",
-          "type": {
-            "option": "u64"
-          }
-        }
-      ]
-    },
-    {
-      "name": "setBasktCreationFee",
-      "discriminator": [
-        174,
-        195,
-        87,
-        31,
-        78,
-        125,
-        189,
-        6
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "docs": [
-            "Signer that must have the ConfigManager or Owner role"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "protocol",
+          "name": "liquidityPool",
           "docs": [
-            "Protocol account containing configuration"
+            "Liquidity pool to update state"
           ],
           "writable": true,
           "pda": {
@@ -3013,52 +2986,29 @@ export type Baskt = {
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
                   116,
+                  121,
+                  95,
+                  112,
                   111,
-                  99,
                   111,
                   108
                 ]
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newFeeLamports",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setBasktLiquidationFeeBps",
-      "discriminator": [
-        135,
-        100,
-        171,
-        91,
-        162,
-        158,
-        226,
-        36
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "docs": [
-            "Authority that can modify baskt config"
-          ],
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "baskt",
+          "name": "ownerCollateralEscrowAccount",
           "docs": [
-            "Baskt account to update"
+            "Position escrow token account"
           ],
           "writable": true,
           "pda": {
@@ -3066,79 +3016,76 @@ export type Baskt = {
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  97,
+                  101,
                   115,
-                  107,
-                  116
+                  99,
+                  114,
+                  111,
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "baskt.uid",
-                "account": "baskt"
+                "path": "position"
               }
             ]
           }
         },
         {
-          "name": "protocol",
+          "name": "ownerCollateralAccount",
           "docs": [
-            "Protocol account for role checking"
+            "User's collateral token account to receive settlement"
+          ],
+          "writable": true
+        },
+        {
+          "name": "usdcVault",
+          "docs": [
+            "BLP token vault for liquidity pool"
+          ],
+          "writable": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund absorbing bad debt ahead of the liquidity pool"
           ],
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  105,
+                  110,
+                  115,
+                  117,
                   114,
-                  111,
-                  116,
-                  111,
+                  97,
+                  110,
                   99,
-                  111,
-                  108
+                  101,
+                  95,
+                  102,
+                  117,
+                  110,
+                  100
                 ]
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newLiquidationFeeBps",
-          "type": {
-            "option": "u64"
-          }
-        }
-      ]
-    },
-    {
-      "name": "setBasktLiquidationThresholdBps",
-      "discriminator": [
-        79,
-        130,
-        174,
-        106,
-        60,
-        213,
-        50,
-        73
-      ],
-      "accounts": [
+        },
         {
-          "name": "authority",
+          "name": "insuranceVault",
           "docs": [
-            "Authority that can modify baskt config"
+            "Token vault holding the insurance fund balance"
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "baskt",
+          "name": "adlRanking",
           "docs": [
-            "Baskt account to update"
+            "from it once closed"
           ],
           "writable": true,
           "pda": {
@@ -3146,185 +3093,213 @@ export type Baskt = {
               {
                 "kind": "const",
                 "value": [
-                  98,
                   97,
-                  115,
+                  100,
+                  108,
+                  95,
+                  114,
+                  97,
+                  110,
                   107,
-                  116
+                  105,
+                  110,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "baskt.uid",
-                "account": "baskt"
+                "path": "baskt"
               }
             ]
           }
         },
         {
-          "name": "protocol",
+          "name": "marginAccount",
           "docs": [
-            "Protocol account for role checking"
+            "Margin account of a cross-margined position, whose shared collateral backs the close"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "marginEscrow",
+          "docs": [
+            "Token account holding the margin account's shared collateral"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryToken",
+          "docs": [
+            "Protocol treasury token account for fee collection"
+          ],
+          "writable": true
+        },
+        {
+          "name": "programAuthority",
+          "docs": [
+            "Program authority PDA"
           ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
+                  97,
+                  117,
+                  116,
+                  104,
                   111,
+                  114,
+                  105,
                   116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
                   111,
-                  99,
                   111,
-                  108
+                  108,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityPool"
+              },
+              {
+                "kind": "account",
+                "path": "protocol"
               }
             ]
           }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
       "args": [
         {
-          "name": "newLiquidationThresholdBps",
+          "name": "params",
           "type": {
-            "option": "u64"
+            "defined": {
+              "name": "forceClosePositionParams"
+            }
           }
         }
       ]
     },
     {
-      "name": "setBasktMinCollateralRatioBps",
+      "name": "increasePosition",
       "discriminator": [
-        131,
+        253,
+        234,
+        128,
+        104,
         192,
-        20,
         188,
-        210,
-        35,
-        201,
-        163
+        45,
+        91
       ],
       "accounts": [
         {
-          "name": "authority",
-          "docs": [
-            "Authority that can modify baskt config"
-          ],
+          "name": "matcher",
           "writable": true,
           "signer": true
         },
         {
-          "name": "baskt",
-          "docs": [
-            "Baskt account to update"
-          ],
+          "name": "order",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  97,
-                  115,
-                  107,
-                  116
-                ]
-              },
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
               {
                 "kind": "account",
-                "path": "baskt.uid",
-                "account": "baskt"
+                "path": "order.owner",
+                "account": "order"
+              },
+              {
+                "kind": "account",
+                "path": "order.order_id",
+                "account": "order"
               }
             ]
           }
         },
         {
-          "name": "protocol",
-          "docs": [
-            "Protocol account for role checking"
-          ],
+          "name": "orderOwner",
+          "writable": true
+        },
+        {
+          "name": "position",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   112,
-                  114,
                   111,
+                  115,
+                  105,
                   116,
+                  105,
                   111,
-                  99,
-                  111,
-                  108
+                  110
                 ]
-              }
-            ]
-          }
-        }
-      ],
-      "args": [
-        {
-          "name": "newMinCollateralRatioBps",
-          "type": {
-            "option": "u64"
-          }
-        }
-      ]
-    },
-    {
-      "name": "setBasktOpeningFeeBps",
-      "discriminator": [
-        197,
-        44,
-        153,
-        105,
-        230,
-        244,
-        44,
-        88
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "docs": [
-            "Authority that can modify baskt config"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "baskt",
-          "docs": [
-            "Baskt account to update"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
+              },
               {
-                "kind": "const",
-                "value": [
-                  98,
-                  97,
-                  115,
-                  107,
-                  116
-                ]
+                "kind": "account",
+                "path": "position.owner",
+                "account": "position"
               },
               {
                 "kind": "account",
-                "path": "baskt.uid",
-                "account": "baskt"
+                "path": "position.position_id",
+                "account": "position"
               }
             ]
           }
         },
+        {
+          "name": "baskt",
+          "writable": true
+        },
         {
           "name": "protocol",
           "docs": [
-            "Protocol account for role checking"
+            "Protocol account for checking permissions",
+            "@dev Requires Matcher role to increase positions"
           ],
           "pda": {
             "seeds": [
@@ -3343,391 +3318,272 @@ export type Baskt = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newOpeningFeeBps",
-          "type": {
-            "option": "u64"
-          }
-        }
-      ]
-    },
-    {
-      "name": "setClosingFeeBps",
-      "discriminator": [
-        228,
-        245,
-        238,
-        120,
-        199,
-        91,
-        4,
-        228
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "protocol",
+          "name": "liquidityPool",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
                   116,
+                  121,
+                  95,
+                  112,
                   111,
-                  99,
                   111,
                   108
                 ]
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newClosingFeeBps",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setFundingCutBps",
-      "discriminator": [
-        187,
-        140,
-        188,
-        89,
-        202,
-        49,
-        175,
-        65
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "docs": [
-            "Signer that must have the ConfigManager role"
-          ],
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "protocol",
-          "docs": [
-            "Protocol account containing configuration"
-          ],
+          "name": "orderEscrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  117,
+                  115,
+                  101,
                   114,
-                  111,
-                  116,
-                  111,
+                  95,
+                  101,
+                  115,
                   99,
+                  114,
                   111,
-                  108
+                  119
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "order.owner",
+                "account": "order"
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newFundingCutBps",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setLiquidationFeeBps",
-      "discriminator": [
-        133,
-        242,
-        86,
-        151,
-        4,
-        91,
-        77,
-        161
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "protocol",
+          "name": "ownerCollateralEscrowAccount",
+          "docs": [
+            "Position escrow token account"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
+                  101,
+                  115,
                   99,
+                  114,
                   111,
-                  108
+                  119
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "position"
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "newLiquidationFeeBps",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setLiquidationThresholdBps",
-      "discriminator": [
-        240,
-        171,
-        134,
-        1,
-        231,
-        151,
-        240,
-        201
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "docs": [
-            "Signer that must have the ConfigManager role"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "protocol",
+          "name": "programAuthority",
           "docs": [
-            "Protocol account containing configuration"
+            "PDA used for token authority over escrow"
           ],
-          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  97,
+                  117,
                   116,
+                  104,
                   111,
-                  99,
-                  111,
-                  108
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "newLiquidationThresholdBps",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setMinCollateralRatioBps",
-      "discriminator": [
-        227,
-        204,
-        66,
-        98,
-        143,
-        96,
-        99,
-        122
-      ],
-      "accounts": [
+          "name": "treasuryToken",
+          "docs": [
+            "Protocol treasury token account for fee collection"
+          ],
+          "writable": true
+        },
         {
-          "name": "authority",
+          "name": "usdcVault",
           "docs": [
-            "Signer that must have the ConfigManager role"
+            "BLP token vault for liquidity pool fees"
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "protocol",
+          "name": "navHistory",
           "docs": [
-            "Protocol account containing configuration"
+            "NAV history, required when the baskt has a circuit breaker configured"
           ],
-          "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  110,
+                  97,
+                  118,
+                  95,
+                  104,
+                  105,
+                  115,
                   116,
                   111,
-                  99,
-                  111,
-                  108
+                  114,
+                  121
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
               }
             ]
           }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
       "args": [
         {
-          "name": "newMinCollateralRatioBps",
-          "type": "u64"
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "increasePositionParams"
+            }
+          }
         }
       ]
     },
     {
-      "name": "setMinLiquidity",
+      "name": "initializeBasktPrice",
       "discriminator": [
-        162,
-        147,
-        87,
-        130,
-        193,
-        136,
-        3,
-        58
+        18,
+        6,
+        104,
+        132,
+        211,
+        61,
+        188,
+        198
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "payer",
           "writable": true,
           "signer": true
         },
         {
-          "name": "protocol",
-          "writable": true,
+          "name": "baskt",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
-                  108
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newMinLiquidity",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setOpeningFeeBps",
-      "discriminator": [
-        157,
-        25,
-        242,
-        124,
-        119,
-        236,
-        144,
-        81
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "protocol",
+          "name": "basktPrice",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116,
+                  95,
                   112,
                   114,
-                  111,
-                  116,
-                  111,
+                  105,
                   99,
-                  111,
-                  108
+                  101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "newOpeningFeeBps",
-          "type": "u64"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "setRebalanceRequestFee",
+      "name": "initializeInsuranceFund",
       "discriminator": [
-        243,
-        54,
-        146,
-        34,
-        66,
+        2,
+        239,
+        39,
         87,
+        50,
         28,
-        118
+        108,
+        12
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "admin",
           "docs": [
-            "Signer that must have the ConfigManager role"
+            "Admin with Owner role who can initialize the fund"
           ],
           "writable": true,
           "signer": true
         },
         {
           "name": "protocol",
-          "docs": [
-            "Protocol account containing configuration"
-          ],
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -3745,93 +3601,38 @@ export type Baskt = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newFeeLamports",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setTreasuryCutBps",
-      "discriminator": [
-        127,
-        177,
-        56,
-        177,
-        157,
-        146,
-        191,
-        230
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "docs": [
-            "Signer that must have the ConfigManager role"
-          ],
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "protocol",
-          "docs": [
-            "Protocol account containing configuration"
-          ],
+          "name": "insuranceFund",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  105,
+                  110,
+                  115,
+                  117,
                   114,
-                  111,
-                  116,
-                  111,
+                  97,
+                  110,
                   99,
-                  111,
-                  108
-                ]
-              }
+                  101,
+                  95,
+                  102,
+                  117,
+                  110,
+                  100
+                ]
+              }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newTreasuryCutBps",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "updateBasktConfig",
-      "discriminator": [
-        231,
-        139,
-        249,
-        181,
-        181,
-        140,
-        50,
-        232
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "docs": [
-            "Authority that can modify baskt config"
-          ],
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "baskt",
+          "name": "insuranceVault",
           "docs": [
-            "Baskt account to update"
+            "Vault owned by the program authority so settlements can move funds in and out"
           ],
           "writable": true,
           "pda": {
@@ -3839,80 +3640,95 @@ export type Baskt = {
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  97,
+                  105,
+                  110,
                   115,
-                  107,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
                   116
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "baskt.uid",
-                "account": "baskt"
               }
             ]
           }
         },
         {
-          "name": "protocol",
+          "name": "collateralMint"
+        },
+        {
+          "name": "programAuthority",
           "docs": [
-            "Protocol account for role checking"
+            "PDA used for token authority over escrow and the insurance vault"
           ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  97,
+                  117,
                   116,
+                  104,
                   111,
-                  99,
-                  111,
-                  108
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "updateBasktConfigParams"
-            }
-          }
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "updateFeatureFlags",
+      "name": "initializeLiquidityPool",
       "discriminator": [
-        139,
-        88,
-        184,
-        214,
-        40,
-        6,
-        55,
-        247
+        155,
+        18,
+        138,
+        107,
+        111,
+        23,
+        178,
+        178
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "admin",
           "docs": [
-            "@dev Requires Owner role to update feature flags"
+            "Admin with Owner role who can initialize the pool"
           ],
           "writable": true,
           "signer": true
         },
         {
           "name": "protocol",
-          "writable": true,
+          "docs": [
+            "Protocol account to verify admin role",
+            "@dev Requires Owner role to initialize liquidity pool"
+          ],
           "pda": {
             "seeds": [
               {
@@ -3930,44 +3746,49 @@ export type Baskt = {
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "updateFeatureFlagsParams"
-            }
+          "name": "liquidityPool",
+          "docs": [
+            "Liquidity pool account to initialize"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
           }
-        }
-      ]
-    },
-    {
-      "name": "updateMarketIndices",
-      "discriminator": [
-        131,
-        49,
-        240,
-        128,
-        48,
-        95,
-        38,
-        254
-      ],
-      "accounts": [
+        },
         {
-          "name": "authority",
+          "name": "lpMint",
           "docs": [
-            "@dev Requires FundingManager role to update market indices"
+            "The mint that will be used for LP tokens"
           ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "baskt",
+          "name": "usdcVault",
           "docs": [
-            "Baskt account associated with the market indices. Used only for seed verification."
+            "The token account that will hold the pool's assets"
           ],
           "writable": true,
           "pda": {
@@ -3975,1058 +3796,7925 @@ export type Baskt = {
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  97,
-                  115,
+                  116,
+                  111,
                   107,
+                  101,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "baskt.uid",
-                "account": "baskt"
+                "path": "liquidityPool"
               }
             ]
           }
         },
         {
-          "name": "protocol",
+          "name": "lpTokenEscrow",
+          "docs": [
+            "The token account that will hold LP tokens during withdrawal queue processing"
+          ],
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  108,
                   112,
-                  114,
-                  111,
-                  116,
-                  111,
+                  95,
+                  101,
+                  115,
                   99,
+                  114,
                   111,
-                  108
+                  119
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityPool"
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newFundingRate",
-          "type": "i64"
         },
         {
-          "name": "newBorrowRate",
-          "type": "i64"
-        }
-      ]
-    },
-    {
-      "name": "updateTreasury",
-      "discriminator": [
-        60,
-        16,
-        243,
-        66,
-        96,
-        59,
-        254,
-        131
-      ],
-      "accounts": [
-        {
-          "name": "authority",
+          "name": "usdcMint",
           "docs": [
-            "Signer must be Owner or ConfigManager"
-          ],
-          "writable": true,
-          "signer": true
-        },
+            "The mint of the token used for collateral"
+          ]
+        },
         {
-          "name": "protocol",
-          "docs": [
-            "Protocol account"
-          ],
-          "writable": true,
+          "name": "poolAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   112,
-                  114,
                   111,
-                  116,
                   111,
-                  99,
+                  108,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
                   111,
-                  108
+                  114,
+                  105,
+                  116,
+                  121
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityPool"
+              },
+              {
+                "kind": "account",
+                "path": "protocol"
               }
             ]
           }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "newTreasury",
-          "type": "pubkey"
+          "name": "depositFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "withdrawalFeeBps",
+          "type": "u16"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "baskt",
-      "discriminator": [
-        105,
-        38,
-        119,
-        212,
-        185,
-        63,
-        190,
-        248
-      ]
-    },
-    {
-      "name": "liquidityPool",
-      "discriminator": [
-        66,
-        38,
-        17,
-        64,
-        188,
-        80,
-        68,
-        129
-      ]
     },
     {
-      "name": "order",
+      "name": "initializeNavHistory",
       "discriminator": [
-        134,
-        173,
-        223,
-        185,
-        77,
-        86,
-        28,
-        51
-      ]
+        51,
+        219,
+        78,
+        31,
+        231,
+        179,
+        132,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "navHistory",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  97,
+                  118,
+                  95,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "position",
+      "name": "initializePriceFeed",
       "discriminator": [
-        170,
-        188,
-        143,
-        228,
-        122,
-        64,
-        247,
-        208
-      ]
+        68,
+        180,
+        81,
+        20,
+        102,
+        213,
+        145,
+        233
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  115,
+                  115,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset.ticker",
+                "account": "syntheticAsset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "programAuthority",
+      "name": "initializeProtocol",
       "discriminator": [
-        38,
-        198,
         188,
-        60,
-        171,
-        210,
+        233,
+        252,
+        106,
+        134,
+        146,
+        202,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Escrow mint (USDC)"
+          ]
+        },
+        {
+          "name": "programAuthority",
+          "docs": [
+            "Program authority PDA - created during protocol initialization"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "liquidateMarginAccount",
+      "discriminator": [
+        133,
+        60,
+        254,
+        227,
+        160,
+        58,
+        131,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "liquidator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "marginAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  114,
+                  103,
+                  105,
+                  110,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "margin_account.owner",
+                "account": "marginAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "marginEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  114,
+                  103,
+                  105,
+                  110,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "marginAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "liquidityPool",
+          "docs": [
+            "Liquidity pool"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcVault",
+          "docs": [
+            "BLP token vault receiving the seized collateral"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasuryToken",
+          "docs": [
+            "Protocol treasury token account for fee collection"
+          ],
+          "writable": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund absorbing bad debt ahead of the liquidity pool"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  102,
+                  117,
+                  110,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token vault holding the insurance fund balance"
+          ],
+          "writable": true
+        },
+        {
+          "name": "programAuthority",
+          "docs": [
+            "PDA used for token authority over escrow"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "liquidatePosition",
+      "discriminator": [
+        187,
+        74,
+        229,
+        149,
+        102,
+        81,
+        221,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "liquidator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position.owner",
+                "account": "position"
+              },
+              {
+                "kind": "account",
+                "path": "position.position_id",
+                "account": "position"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baskt",
+          "writable": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol for permission checks"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "liquidityPool",
+          "docs": [
+            "Liquidity pool"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "CHECK Treasury account"
+          ]
+        },
+        {
+          "name": "ownerCollateralEscrowAccount",
+          "docs": [
+            "Position escrow token account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ownerCollateralAccount",
+          "docs": [
+            "User's collateral token account to receive settlement"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasuryToken",
+          "docs": [
+            "Protocol treasury token account for fee collection"
+          ],
+          "writable": true
+        },
+        {
+          "name": "usdcVault",
+          "docs": [
+            "BLP token vault for liquidity pool fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund absorbing bad debt ahead of the liquidity pool"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  102,
+                  117,
+                  110,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token vault holding the insurance fund balance"
+          ],
+          "writable": true
+        },
+        {
+          "name": "adlRanking",
+          "docs": [
+            "from it once liquidated"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  108,
+                  95,
+                  114,
+                  97,
+                  110,
+                  107,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programAuthority",
+          "docs": [
+            "PDA used for token authority over escrow"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityPool"
+              },
+              {
+                "kind": "account",
+                "path": "protocol"
+              }
+            ]
+          }
+        },
+        {
+          "name": "navHistory",
+          "docs": [
+            "NAV history, required when the baskt has a liquidation TWAP window or a circuit",
+            "breaker configured"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  97,
+                  118,
+                  95,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "liquidatePositionParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateAsset",
+      "discriminator": [
+        180,
+        156,
+        159,
+        159,
+        133,
+        15,
+        145,
+        207
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "docs": [
+            "decode; owner, discriminator and size are checked in the handler"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  115,
+                  115,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.ticker"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "migrateAssetParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "openPosition",
+      "discriminator": [
+        135,
+        128,
+        47,
+        77,
+        15,
+        152,
+        240,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "matcher",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "order.owner",
+                "account": "order"
+              },
+              {
+                "kind": "account",
+                "path": "order.order_id",
+                "account": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "orderOwner",
+          "writable": true
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "order.owner",
+                "account": "order"
+              },
+              {
+                "kind": "arg",
+                "path": "params.position_id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account that contains the embedded oracle for price validation"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for checking permissions",
+            "@dev Requires Matcher role to open positions"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "liquidityPool",
+          "docs": [
+            "Liquidity pool for account validation and fee accounting"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "orderEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "order.owner",
+                "account": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ownerCollateralEscrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programAuthority",
+          "docs": [
+            "PDA used for token authority over escrow"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Escrow mint (USDC) - validated via protocol"
+          ]
+        },
+        {
+          "name": "treasuryToken",
+          "docs": [
+            "Protocol treasury token account for fee collection"
+          ],
+          "writable": true
+        },
+        {
+          "name": "usdcVault",
+          "docs": [
+            "BLP token vault for liquidity pool fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "navHistory",
+          "docs": [
+            "NAV history, required when the baskt has a circuit breaker configured"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  97,
+                  118,
+                  95,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "openPositionParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "postSignedPrice",
+      "discriminator": [
+        18,
+        175,
+        228,
+        129,
+        251,
+        205,
+        192,
+        95
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "signer": true
+        },
+        {
+          "name": "priceFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "price_feed.asset",
+                "account": "priceFeed"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "priceFeedUpdateParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "processWithdrawQueue",
+      "discriminator": [
+        23,
+        100,
+        61,
+        241,
+        134,
+        190,
+        48,
+        53
+      ],
+      "accounts": [
+        {
+          "name": "keeper",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "liquidityPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityPool"
+              },
+              {
+                "kind": "account",
+                "path": "protocol"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryUsdcAccount",
+          "writable": true
+        },
+        {
+          "name": "lpTokenEscrow",
+          "docs": [
+            "LP token escrow account where LP tokens are held during withdrawal queue processing"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  112,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "providerUsdcAccount",
+          "docs": [
+            "USDC account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "withdrawRequest",
+          "writable": true
+        },
+        {
+          "name": "provider",
+          "writable": true
+        },
+        {
+          "name": "lpMint",
+          "docs": [
+            "LP token mint for burning"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "queueWithdrawLiquidity",
+      "discriminator": [
+        180,
+        225,
+        125,
+        70,
+        9,
+        230,
+        128,
+        117
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "docs": [
+            "The liquidity provider requesting withdrawal"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "liquidityPool",
+          "docs": [
+            "Liquidity pool state account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol state for feature flags validation"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "providerLpAccount",
+          "docs": [
+            "Provider's LP token account (source for burn)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "providerUsdcAccount",
+          "docs": [
+            "USDC account"
+          ]
+        },
+        {
+          "name": "lpTokenEscrow",
+          "docs": [
+            "LP token escrow account where LP tokens are held during withdrawal queue processing"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  112,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "withdrawRequest",
+          "docs": [
+            "Withdrawal request PDA"
+          ],
+          "writable": true
+        },
+        {
+          "name": "lpMint",
+          "docs": [
+            "LP token mint for burning"
+          ],
+          "writable": true
+        },
+        {
+          "name": "poolAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityPool"
+              },
+              {
+                "kind": "account",
+                "path": "protocol"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "requestWithdrawParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "quoteNav",
+      "discriminator": [
+        32,
+        163,
+        201,
+        87,
+        235,
+        78,
+        162,
+        144
+      ],
+      "accounts": [
+        {
+          "name": "baskt",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "rebalance",
+      "discriminator": [
+        108,
+        158,
+        77,
+        9,
+        210,
+        52,
+        88,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "baskt",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "@dev Requires Rebalancer role to rebalance"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "basktPrice",
+          "docs": [
+            "Published price account, refreshed with the new NAV when provided"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116,
+                  95,
+                  112,
+                  114,
+                  105,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "assetConfigs",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "assetConfig"
+              }
+            }
+          }
+        },
+        {
+          "name": "newNav",
+          "type": "u64"
+        },
+        {
+          "name": "rebalanceFeePerUnit",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rebalanceRequest",
+      "discriminator": [
+        114,
+        49,
+        98,
+        158,
+        41,
+        46,
+        138,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "baskt"
+        },
+        {
+          "name": "creator",
+          "docs": [
+            "@dev Only the baskt creator can request a rebalance"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account to get fee configuration"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury account to receive the fee"
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "System program for SOL transfers"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recordNav",
+      "discriminator": [
+        12,
+        39,
+        195,
+        29,
+        234,
+        54,
+        160,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "baskt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "navHistory",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  97,
+                  118,
+                  95,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "basktPrice",
+          "docs": [
+            "Published price account, refreshed when provided"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116,
+                  95,
+                  112,
+                  114,
+                  105,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeCollateral",
+      "discriminator": [
+        86,
+        222,
+        130,
+        86,
+        92,
+        20,
+        72,
+        65
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "position.position_id",
+                "account": "position"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baskt"
+        },
+        {
+          "name": "ownerCollateralAccount",
+          "docs": [
+            "Token account receiving the withdrawn collateral"
+          ],
+          "writable": true
+        },
+        {
+          "name": "ownerCollateralEscrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programAuthority",
+          "docs": [
+            "PDA used for token authority over escrow"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account - required for validating the feature flag"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "liquidityPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryToken",
+          "docs": [
+            "Protocol treasury token account for rebalance fee collection"
+          ],
+          "writable": true
+        },
+        {
+          "name": "usdcVault",
+          "docs": [
+            "BLP token vault for liquidity pool fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "navHistory",
+          "docs": [
+            "NAV history, required when the baskt has a circuit breaker configured"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  97,
+                  118,
+                  95,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "removeCollateralParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeRole",
+      "discriminator": [
+        74,
+        69,
+        168,
+        163,
+        248,
+        3,
+        130,
+        0
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "@dev Requires Owner role to remove roles from other accounts"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "docs": [
+            "Account to remove the role from"
+          ]
+        },
+        {
+          "name": "protocol",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "roleType",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "resumeBaskt",
+      "discriminator": [
+        83,
+        171,
+        73,
+        138,
+        7,
+        30,
+        133,
+        214
+      ],
+      "accounts": [
+        {
+          "name": "baskt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "navHistory",
+          "docs": [
+            "NAV history, re-seeded with the current NAV"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  97,
+                  118,
+                  95,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "@dev Requires BasktManager role to resume trading"
+          ],
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeTradingDelegate",
+      "discriminator": [
+        43,
+        207,
+        174,
+        48,
+        174,
+        77,
+        188,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tradingDelegate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "trading_delegate.delegate",
+                "account": "tradingDelegate"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setAdlPnlToPoolBps",
+      "discriminator": [
+        111,
+        248,
+        29,
+        130,
+        40,
+        9,
+        165,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer that must have the ConfigManager role"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account containing configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdlPnlToPoolBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setBasktCircuitBreaker",
+      "discriminator": [
+        3,
+        182,
+        115,
+        111,
+        191,
+        198,
+        76,
+        219
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority that can modify baskt config"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for role checking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newCircuitBreaker",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "circuitBreakerConfig"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBasktClosingFeeBps",
+      "discriminator": [
+        221,
+        101,
+        129,
+        68,
+        37,
+        222,
+        146,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority that can modify baskt config"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for role checking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newClosingFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBasktCreationFee",
+      "discriminator": [
+        174,
+        195,
+        87,
+        31,
+        78,
+        125,
+        189,
+        6
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer that must have the ConfigManager or Owner role"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account containing configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newFeeLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setBasktLiquidationFeeBps",
+      "discriminator": [
+        135,
+        100,
+        171,
+        91,
+        162,
+        158,
+        226,
+        36
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority that can modify baskt config"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for role checking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newLiquidationFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBasktLiquidationThresholdBps",
+      "discriminator": [
+        79,
+        130,
+        174,
+        106,
+        60,
+        213,
+        50,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority that can modify baskt config"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for role checking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newLiquidationThresholdBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBasktLiquidationTwapWindow",
+      "discriminator": [
+        177,
+        157,
+        129,
+        108,
+        109,
+        153,
+        185,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority that can modify baskt config"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for role checking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newWindowSeconds",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBasktMaxPriceDeviationBps",
+      "discriminator": [
+        160,
+        109,
+        73,
+        161,
+        159,
+        192,
+        253,
+        111
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority that can modify baskt config"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for role checking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newMaxPriceDeviationBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBasktMinCollateralRatioBps",
+      "discriminator": [
+        131,
+        192,
+        20,
+        188,
+        210,
+        35,
+        201,
+        163
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority that can modify baskt config"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for role checking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newMinCollateralRatioBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBasktOpeningFeeBps",
+      "discriminator": [
+        197,
+        44,
+        153,
+        105,
+        230,
+        244,
+        44,
+        88
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority that can modify baskt config"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for role checking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newOpeningFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setClosingFeeBps",
+      "discriminator": [
+        228,
+        245,
+        238,
+        120,
+        199,
+        91,
+        4,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newClosingFeeBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setFundingCutBps",
+      "discriminator": [
+        187,
+        140,
+        188,
+        89,
+        202,
+        49,
+        175,
+        65
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer that must have the ConfigManager role"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account containing configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newFundingCutBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setInsuranceCutBps",
+      "discriminator": [
+        98,
+        249,
+        177,
+        7,
+        160,
+        49,
+        231,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer that must have the ConfigManager role"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account containing configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newInsuranceCutBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setLiquidationFeeBps",
+      "discriminator": [
+        133,
+        242,
+        86,
+        151,
+        4,
+        91,
+        77,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newLiquidationFeeBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setLiquidationThresholdBps",
+      "discriminator": [
+        240,
+        171,
+        134,
+        1,
+        231,
+        151,
+        240,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer that must have the ConfigManager role"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account containing configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newLiquidationThresholdBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setManualPrice",
+      "discriminator": [
+        6,
+        210,
+        4,
+        51,
+        43,
+        53,
+        139,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  115,
+                  115,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset.ticker",
+                "account": "syntheticAsset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setMaxPriceDeviationBps",
+      "discriminator": [
+        13,
+        166,
+        196,
+        104,
+        129,
+        190,
+        209,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newMaxPriceDeviationBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setMinCollateralRatioBps",
+      "discriminator": [
+        227,
+        204,
+        66,
+        98,
+        143,
+        96,
+        99,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer that must have the ConfigManager role"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account containing configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newMinCollateralRatioBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setMinLiquidity",
+      "discriminator": [
+        162,
+        147,
+        87,
+        130,
+        193,
+        136,
+        3,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newMinLiquidity",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setOpeningFeeBps",
+      "discriminator": [
+        157,
+        25,
+        242,
+        124,
+        119,
+        236,
+        144,
+        81
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newOpeningFeeBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPartialLiquidationConfig",
+      "discriminator": [
+        106,
+        29,
+        254,
+        142,
+        182,
+        4,
+        66,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer that must have the ConfigManager role"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account containing configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "targetBps",
+          "type": "u64"
+        },
+        {
+          "name": "fullLiquidationThresholdBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setRebalanceRequestFee",
+      "discriminator": [
+        243,
+        54,
+        146,
+        34,
+        66,
+        87,
+        28,
+        118
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer that must have the ConfigManager role"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account containing configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newFeeLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setTradingDelegate",
+      "discriminator": [
+        181,
+        195,
+        120,
+        236,
+        254,
+        84,
+        61,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "delegate"
+        },
+        {
+          "name": "tradingDelegate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "delegate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "config",
+          "type": {
+            "defined": {
+              "name": "tradingDelegateConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTreasuryCutBps",
+      "discriminator": [
+        127,
+        177,
+        56,
+        177,
+        157,
+        146,
+        191,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer that must have the ConfigManager role"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account containing configuration"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newTreasuryCutBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateAdlRanking",
+      "discriminator": [
+        138,
+        213,
+        70,
+        166,
+        112,
+        21,
+        43,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "keeper",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "adlRanking",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  108,
+                  95,
+                  114,
+                  97,
+                  110,
+                  107,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateAssetOracleConfig",
+      "discriminator": [
+        3,
+        206,
+        181,
+        108,
+        128,
+        115,
+        248,
+        192
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "asset",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  115,
+                  115,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "asset.ticker",
+                "account": "syntheticAsset"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "oracleConfig",
+          "type": {
+            "defined": {
+              "name": "oracleConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateBasktConfig",
+      "discriminator": [
+        231,
+        139,
+        249,
+        181,
+        181,
+        140,
+        50,
+        232
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority that can modify baskt config"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account for role checking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "updateBasktConfigParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeatureFlags",
+      "discriminator": [
+        139,
+        88,
+        184,
+        214,
+        40,
+        6,
+        55,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "@dev Requires Owner role to update feature flags"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "updateFeatureFlagsParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMarketIndices",
+      "discriminator": [
+        131,
+        49,
+        240,
+        128,
+        48,
+        95,
+        38,
+        254
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "@dev Requires FundingManager role to update market indices"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "baskt",
+          "docs": [
+            "Baskt account associated with the market indices. Used only for seed verification."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baskt.uid",
+                "account": "baskt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newFundingRate",
+          "type": "i64"
+        },
+        {
+          "name": "newBorrowRate",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updatePriceFeed",
+      "discriminator": [
+        28,
+        9,
+        93,
+        150,
+        86,
+        153,
+        188,
+        115
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "priceFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "price_feed.asset",
+                "account": "priceFeed"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "priceFeedUpdateParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTrailingStop",
+      "discriminator": [
+        9,
+        64,
+        172,
+        21,
+        17,
+        22,
+        27,
+        226
+      ],
+      "accounts": [
+        {
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "order.owner",
+                "account": "order"
+              },
+              {
+                "kind": "account",
+                "path": "order.order_id",
+                "account": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baskt"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateTreasury",
+      "discriminator": [
+        60,
+        16,
+        243,
+        66,
+        96,
+        59,
+        254,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Signer must be Owner or ConfigManager"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocol",
+          "docs": [
+            "Protocol account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newTreasury",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "withdrawMarginCollateral",
+      "discriminator": [
+        28,
+        5,
+        66,
+        122,
+        86,
+        154,
+        140,
+        152
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "marginAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  114,
+                  103,
+                  105,
+                  110,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ownerCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "marginEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  114,
+                  103,
+                  105,
+                  110,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "marginAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programAuthority",
+          "docs": [
+            "PDA used for token authority over escrow"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocol",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adlRanking",
+      "discriminator": [
+        20,
+        199,
+        101,
+        25,
+        6,
+        66,
+        172,
+        164
+      ]
+    },
+    {
+      "name": "baskt",
+      "discriminator": [
+        105,
+        38,
+        119,
+        212,
+        185,
+        63,
+        190,
+        248
+      ]
+    },
+    {
+      "name": "basktPriceAccount",
+      "discriminator": [
+        126,
+        106,
+        230,
+        252,
+        227,
+        110,
+        219,
+        138
+      ]
+    },
+    {
+      "name": "insuranceFund",
+      "discriminator": [
+        43,
+        134,
+        170,
+        87,
+        102,
+        16,
+        142,
+        147
+      ]
+    },
+    {
+      "name": "liquidityPool",
+      "discriminator": [
+        66,
+        38,
+        17,
+        64,
+        188,
+        80,
+        68,
+        129
+      ]
+    },
+    {
+      "name": "marginAccount",
+      "discriminator": [
+        133,
+        220,
+        173,
+        213,
+        179,
+        211,
+        43,
+        238
+      ]
+    },
+    {
+      "name": "navHistory",
+      "discriminator": [
+        40,
+        139,
+        233,
+        237,
+        46,
+        197,
+        105,
+        74
+      ]
+    },
+    {
+      "name": "order",
+      "discriminator": [
+        134,
+        173,
+        223,
+        185,
+        77,
+        86,
+        28,
+        51
+      ]
+    },
+    {
+      "name": "orderGroup",
+      "discriminator": [
+        213,
+        61,
+        200,
+        200,
+        23,
+        19,
+        204,
+        45
+      ]
+    },
+    {
+      "name": "position",
+      "discriminator": [
+        170,
+        188,
+        143,
+        228,
+        122,
+        64,
+        247,
+        208
+      ]
+    },
+    {
+      "name": "priceFeed",
+      "discriminator": [
+        189,
+        103,
+        252,
+        23,
+        152,
+        35,
+        243,
+        156
+      ]
+    },
+    {
+      "name": "programAuthority",
+      "discriminator": [
+        38,
+        198,
+        188,
+        60,
+        171,
+        210,
+        169,
+        38
+      ]
+    },
+    {
+      "name": "protocol",
+      "discriminator": [
+        45,
+        39,
+        101,
+        43,
+        115,
+        72,
+        131,
+        40
+      ]
+    },
+    {
+      "name": "syntheticAsset",
+      "discriminator": [
+        106,
+        240,
+        239,
+        114,
+        174,
+        40,
+        189,
+        218
+      ]
+    },
+    {
+      "name": "tradingDelegate",
+      "discriminator": [
+        179,
+        217,
+        13,
+        37,
+        115,
+        95,
+        137,
+        44
+      ]
+    },
+    {
+      "name": "withdrawRequest",
+      "discriminator": [
+        186,
+        239,
+        174,
+        191,
+        189,
+        13,
+        47,
+        196
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "adlRankingUpdatedEvent",
+      "discriminator": [
+        79,
+        185,
+        132,
+        51,
+        54,
+        133,
+        24,
+        52
+      ]
+    },
+    {
+      "name": "basktActivatedEvent",
+      "discriminator": [
+        88,
+        137,
+        206,
+        103,
+        196,
+        4,
+        65,
+        8
+      ]
+    },
+    {
+      "name": "basktClosed",
+      "discriminator": [
+        30,
+        129,
+        31,
+        215,
+        46,
+        231,
+        241,
+        7
+      ]
+    },
+    {
+      "name": "basktConfigUpdatedEvent",
+      "discriminator": [
+        161,
+        255,
+        126,
+        226,
+        10,
+        130,
+        31,
+        137
+      ]
+    },
+    {
+      "name": "basktCreatedEvent",
+      "discriminator": [
+        230,
+        10,
+        18,
+        30,
+        165,
+        178,
+        209,
+        132
+      ]
+    },
+    {
+      "name": "basktDecommissioningInitiated",
+      "discriminator": [
+        41,
+        181,
+        24,
+        72,
+        93,
+        88,
+        141,
+        100
+      ]
+    },
+    {
+      "name": "basktHaltedEvent",
+      "discriminator": [
+        178,
+        141,
+        139,
+        248,
+        87,
+        163,
+        188,
+        239
+      ]
+    },
+    {
+      "name": "basktRebalancedEvent",
+      "discriminator": [
+        201,
+        140,
+        15,
+        4,
+        88,
+        198,
+        74,
+        170
+      ]
+    },
+    {
+      "name": "basktResumedEvent",
+      "discriminator": [
+        100,
+        227,
+        26,
+        27,
+        229,
+        102,
+        170,
+        27
+      ]
+    },
+    {
+      "name": "collateralAddedEvent",
+      "discriminator": [
+        90,
+        251,
+        19,
+        246,
+        134,
+        164,
+        243,
+        177
+      ]
+    },
+    {
+      "name": "collateralRemovedEvent",
+      "discriminator": [
+        142,
+        2,
+        88,
+        145,
+        189,
+        31,
+        80,
+        163
+      ]
+    },
+    {
+      "name": "fundingIndexUpdatedEvent",
+      "discriminator": [
+        142,
+        186,
+        249,
+        164,
+        193,
+        209,
+        84,
+        145
+      ]
+    },
+    {
+      "name": "insuranceFundInitializedEvent",
+      "discriminator": [
+        129,
+        140,
+        184,
+        72,
+        66,
+        41,
+        9,
+        85
+      ]
+    },
+    {
+      "name": "liquidityAddedEvent",
+      "discriminator": [
+        220,
+        104,
+        7,
+        39,
+        147,
+        1,
+        194,
+        142
+      ]
+    },
+    {
+      "name": "liquidityPoolInitializedEvent",
+      "discriminator": [
+        206,
+        100,
+        66,
+        15,
+        113,
+        180,
+        118,
+        59
+      ]
+    },
+    {
+      "name": "marginAccountLiquidatedEvent",
+      "discriminator": [
+        181,
+        71,
+        56,
+        85,
+        162,
+        87,
+        56,
+        70
+      ]
+    },
+    {
+      "name": "marginCollateralDepositedEvent",
+      "discriminator": [
+        117,
+        154,
+        19,
+        181,
+        109,
+        212,
+        7,
+        168
+      ]
+    },
+    {
+      "name": "marginCollateralWithdrawnEvent",
+      "discriminator": [
+        93,
+        105,
+        69,
+        107,
+        1,
+        163,
+        201,
+        22
+      ]
+    },
+    {
+      "name": "marginPositionAttachedEvent",
+      "discriminator": [
+        206,
+        99,
+        88,
+        33,
+        239,
+        141,
+        203,
+        254
+      ]
+    },
+    {
+      "name": "marginPositionDetachedEvent",
+      "discriminator": [
+        142,
+        51,
+        169,
+        1,
+        128,
+        176,
+        126,
+        97
+      ]
+    },
+    {
+      "name": "marketIndexUpdatedEvent",
+      "discriminator": [
+        93,
+        35,
+        103,
+        143,
+        225,
+        46,
+        86,
+        224
+      ]
+    },
+    {
+      "name": "navRecordedEvent",
+      "discriminator": [
+        177,
+        115,
+        10,
+        130,
+        58,
+        26,
+        183,
+        59
+      ]
+    },
+    {
+      "name": "orderAmendedEvent",
+      "discriminator": [
+        54,
+        237,
+        63,
+        43,
+        49,
+        43,
+        244,
+        214
+      ]
+    },
+    {
+      "name": "orderCancelledEvent",
+      "discriminator": [
+        200,
+        73,
+        179,
+        145,
+        247,
+        176,
+        10,
+        101
+      ]
+    },
+    {
+      "name": "orderCreatedEvent",
+      "discriminator": [
+        75,
+        88,
+        56,
+        153,
+        87,
+        22,
+        170,
+        46
+      ]
+    },
+    {
+      "name": "orderExpiredEvent",
+      "discriminator": [
+        150,
+        3,
+        200,
+        97,
+        178,
+        224,
+        156,
+        9
+      ]
+    },
+    {
+      "name": "orderGroupCreatedEvent",
+      "discriminator": [
+        250,
+        199,
+        150,
+        66,
+        17,
+        228,
+        239,
+        97
+      ]
+    },
+    {
+      "name": "positionAutoDeleveragedEvent",
+      "discriminator": [
+        57,
+        71,
+        252,
+        39,
+        66,
+        18,
+        69,
+        205
+      ]
+    },
+    {
+      "name": "positionClosedEvent",
+      "discriminator": [
+        76,
+        129,
+        10,
+        225,
+        238,
+        51,
+        158,
+        126
+      ]
+    },
+    {
+      "name": "positionForceClosed",
+      "discriminator": [
         169,
-        38
+        152,
+        228,
+        17,
+        218,
+        207,
+        1,
+        119
+      ]
+    },
+    {
+      "name": "positionIncreasedEvent",
+      "discriminator": [
+        34,
+        149,
+        64,
+        127,
+        12,
+        211,
+        87,
+        102
+      ]
+    },
+    {
+      "name": "positionLiquidatedEvent",
+      "discriminator": [
+        70,
+        153,
+        226,
+        254,
+        176,
+        139,
+        225,
+        72
+      ]
+    },
+    {
+      "name": "positionOpenedEvent",
+      "discriminator": [
+        163,
+        1,
+        92,
+        149,
+        138,
+        188,
+        177,
+        23
+      ]
+    },
+    {
+      "name": "priceFeedUpdatedEvent",
+      "discriminator": [
+        35,
+        119,
+        75,
+        110,
+        131,
+        24,
+        250,
+        233
+      ]
+    },
+    {
+      "name": "protocolStateUpdatedEvent",
+      "discriminator": [
+        191,
+        17,
+        185,
+        143,
+        32,
+        206,
+        61,
+        29
+      ]
+    },
+    {
+      "name": "rebalanceRequestEvent",
+      "discriminator": [
+        14,
+        9,
+        248,
+        15,
+        162,
+        243,
+        186,
+        161
+      ]
+    },
+    {
+      "name": "tradingDelegateRevokedEvent",
+      "discriminator": [
+        203,
+        204,
+        153,
+        205,
+        100,
+        205,
+        39,
+        219
+      ]
+    },
+    {
+      "name": "tradingDelegateSetEvent",
+      "discriminator": [
+        86,
+        89,
+        149,
+        132,
+        237,
+        39,
+        163,
+        74
+      ]
+    },
+    {
+      "name": "trailingStopUpdatedEvent",
+      "discriminator": [
+        177,
+        199,
+        49,
+        58,
+        85,
+        94,
+        226,
+        171
+      ]
+    },
+    {
+      "name": "twapTrancheFilledEvent",
+      "discriminator": [
+        194,
+        166,
+        144,
+        147,
+        39,
+        125,
+        215,
+        139
+      ]
+    },
+    {
+      "name": "withdrawQueueProcessedEvent",
+      "discriminator": [
+        167,
+        34,
+        124,
+        242,
+        149,
+        97,
+        242,
+        17
+      ]
+    },
+    {
+      "name": "withdrawalQueuedEvent",
+      "discriminator": [
+        189,
+        125,
+        98,
+        229,
+        140,
+        253,
+        16,
+        86
       ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "mathOverflow",
+      "msg": "Math operation overflow"
+    },
+    {
+      "code": 6001,
+      "name": "insufficientCollateral",
+      "msg": "Insufficient collateral for position"
+    },
+    {
+      "code": 6002,
+      "name": "positionNotLiquidatable",
+      "msg": "Position is not liquidatable"
+    },
+    {
+      "code": 6003,
+      "name": "positionAlreadyClosed",
+      "msg": "Position is already closed"
+    },
+    {
+      "code": 6004,
+      "name": "insufficientLiquidity",
+      "msg": "Insufficient liquidity in pool"
+    },
+    {
+      "code": 6005,
+      "name": "invalidBasktConfig",
+      "msg": "Invalid baskt configuration"
+    },
+    {
+      "code": 6006,
+      "name": "invalidPositionSize",
+      "msg": "Invalid position size"
+    },
+    {
+      "code": 6007,
+      "name": "unauthorized",
+      "msg": "Unauthorized access"
+    },
+    {
+      "code": 6008,
+      "name": "unauthorizedRole",
+      "msg": "Unauthorized: Missing required role for this operation"
+    },
+    {
+      "code": 6009,
+      "name": "unauthorizedTokenOwner",
+      "msg": "Unauthorized: Token account owner mismatch"
+    },
+    {
+      "code": 6010,
+      "name": "invalidLpTokenAmount",
+      "msg": "Invalid LP token amount"
+    },
+    {
+      "code": 6011,
+      "name": "invalidOraclePrice",
+      "msg": "Invalid oracle price"
+    },
+    {
+      "code": 6012,
+      "name": "priceOutOfBounds",
+      "msg": "Submitted price is outside acceptable deviation bounds from oracle price"
+    },
+    {
+      "code": 6013,
+      "name": "insufficientFunds",
+      "msg": "Insufficient funds for operation"
+    },
+    {
+      "code": 6014,
+      "name": "basktNotActive",
+      "msg": "Baskt is not active for trading"
+    },
+    {
+      "code": 6015,
+      "name": "roleNotFound",
+      "msg": "Role not found for the account"
     },
     {
-      "name": "protocol",
-      "discriminator": [
-        45,
-        39,
-        101,
-        43,
-        115,
-        72,
-        131,
-        40
-      ]
+      "code": 6016,
+      "name": "invalidRoleType",
+      "msg": "Invalid role type"
     },
     {
-      "name": "syntheticAsset",
-      "discriminator": [
-        106,
-        240,
-        239,
-        114,
-        174,
-        40,
-        189,
-        218
-      ]
+      "code": 6017,
+      "name": "invalidAssetAccount",
+      "msg": "Invalid asset account"
     },
     {
-      "name": "withdrawRequest",
-      "discriminator": [
-        186,
-        239,
-        174,
-        191,
-        189,
-        13,
-        47,
-        196
-      ]
-    }
-  ],
-  "events": [
+      "code": 6018,
+      "name": "longPositionsDisabled",
+      "msg": "Long positions are disabled for this asset"
+    },
+    {
+      "code": 6019,
+      "name": "shortPositionsDisabled",
+      "msg": "Short positions are disabled for this asset"
+    },
+    {
+      "code": 6020,
+      "name": "assetNotInBaskt",
+      "msg": "Asset not in baskt"
+    },
+    {
+      "code": 6021,
+      "name": "invalidAssetConfig",
+      "msg": "Invalid asset config"
+    },
+    {
+      "code": 6022,
+      "name": "tradingDisabled",
+      "msg": "Trading operations are currently disabled"
+    },
+    {
+      "code": 6023,
+      "name": "liquidityOperationsDisabled",
+      "msg": "Liquidity operations are currently disabled"
+    },
+    {
+      "code": 6024,
+      "name": "positionOperationsDisabled",
+      "msg": "Position operations are currently disabled"
+    },
+    {
+      "code": 6025,
+      "name": "basktOperationsDisabled",
+      "msg": "Baskt management operations are currently disabled"
+    },
+    {
+      "code": 6026,
+      "name": "inactiveAsset",
+      "msg": "Asset Not Active"
+    },
+    {
+      "code": 6027,
+      "name": "basktAlreadyActive",
+      "msg": "Baskt Already Active"
+    },
+    {
+      "code": 6028,
+      "name": "invalidAssetWeights",
+      "msg": "Invalid asset weights"
+    },
+    {
+      "code": 6029,
+      "name": "orderAlreadyProcessed",
+      "msg": "Order already processed"
+    },
+    {
+      "code": 6030,
+      "name": "invalidEscrowAccount",
+      "msg": "Invalid escrow account"
+    },
+    {
+      "code": 6031,
+      "name": "invalidProgramAuthority",
+      "msg": "Invalid program authority"
+    },
+    {
+      "code": 6032,
+      "name": "tokenHasDelegate",
+      "msg": "Token has delegate"
+    },
+    {
+      "code": 6033,
+      "name": "tokenHasCloseAuthority",
+      "msg": "Token has close authority"
+    },
+    {
+      "code": 6034,
+      "name": "invalidMint",
+      "msg": "Invalid mint"
+    },
+    {
+      "code": 6035,
+      "name": "zeroSizedPosition",
+      "msg": "Zero sized position"
+    },
+    {
+      "code": 6036,
+      "name": "invalidTargetPosition",
+      "msg": "Invalid target position"
+    },
+    {
+      "code": 6037,
+      "name": "invalidBaskt",
+      "msg": "Invalid baskt"
+    },
+    {
+      "code": 6038,
+      "name": "invalidOrderAction",
+      "msg": "Invalid order action"
+    },
+    {
+      "code": 6039,
+      "name": "fundingNotUpToDate",
+      "msg": "Funding not up to date"
+    },
+    {
+      "code": 6040,
+      "name": "positionStillOpen",
+      "msg": "Position still open"
+    },
+    {
+      "code": 6041,
+      "name": "invalidTreasuryAccount",
+      "msg": "Invalid treasury account"
+    },
+    {
+      "code": 6042,
+      "name": "collateralOverflow",
+      "msg": "Collateral amount would overflow maximum value"
+    },
+    {
+      "code": 6043,
+      "name": "invalidDepositAmount",
+      "msg": "Invalid deposit amount"
+    },
+    {
+      "code": 6044,
+      "name": "invalidUsdcVault",
+      "msg": "Invalid usdc vault account"
+    },
+    {
+      "code": 6045,
+      "name": "invalidFeeBps",
+      "msg": "Invalid fee basis points"
+    },
+    {
+      "code": 6046,
+      "name": "invalidCollateralRatio",
+      "msg": "Invalid collateral ratio"
+    },
+    {
+      "code": 6047,
+      "name": "fundingRateExceedsMaximum",
+      "msg": "Funding rate exceeds maximum allowed"
+    },
+    {
+      "code": 6048,
+      "name": "invalidOwner",
+      "msg": "Invalid owner"
+    },
+    {
+      "code": 6049,
+      "name": "invalidFundingIndex",
+      "msg": "Invalid funding index account"
+    },
+    {
+      "code": 6050,
+      "name": "invalidBasktState",
+      "msg": "Invalid baskt state for this operation"
+    },
+    {
+      "code": 6051,
+      "name": "gracePeriodNotOver",
+      "msg": "Grace period has not ended"
+    },
+    {
+      "code": 6052,
+      "name": "positionsStillOpen",
+      "msg": "Positions are still open"
+    },
+    {
+      "code": 6053,
+      "name": "invalidGracePeriod",
+      "msg": "Invalid grace period - must be between 1 hour and 7 days"
+    },
+    {
+      "code": 6054,
+      "name": "priceDeviationTooHigh",
+      "msg": "Price deviation too high"
+    },
+    {
+      "code": 6055,
+      "name": "leverageExceeded",
+      "msg": "Realised leverage exceeds declared leverage amount"
+    },
+    {
+      "code": 6056,
+      "name": "invalidInput",
+      "msg": "Invalid input provided"
+    },
+    {
+      "code": 6057,
+      "name": "transferFailed",
+      "msg": "SOL transfer failed"
+    },
+    {
+      "code": 6058,
+      "name": "invalidLpTokenEscrow",
+      "msg": "Invalid LP token escrow"
+    },
+    {
+      "code": 6059,
+      "name": "borrowRateExceedsMaximum",
+      "msg": "Borrow rate exceeds maximum allowed"
+    },
+    {
+      "code": 6060,
+      "name": "invalidBorrowState",
+      "msg": "Invalid borrow state - borrow accumulated should be negative"
+    },
     {
-      "name": "basktActivatedEvent",
-      "discriminator": [
-        88,
-        137,
-        206,
-        103,
-        196,
-        4,
-        65,
-        8
-      ]
+      "code": 6061,
+      "name": "invalidOracleAccount",
+      "msg": "Invalid oracle account"
     },
     {
-      "name": "basktClosed",
-      "discriminator": [
-        30,
-        129,
-        31,
-        215,
-        46,
-        231,
-        241,
-        7
-      ]
+      "code": 6062,
+      "name": "oracleFeedMismatch",
+      "msg": "Oracle price feed does not match asset"
     },
     {
-      "name": "basktConfigUpdatedEvent",
-      "discriminator": [
-        161,
-        255,
-        126,
-        226,
-        10,
-        130,
-        31,
-        137
-      ]
+      "code": 6063,
+      "name": "staleOraclePrice",
+      "msg": "Oracle price is stale"
     },
     {
-      "name": "basktCreatedEvent",
-      "discriminator": [
-        230,
-        10,
-        18,
-        30,
-        165,
-        178,
-        209,
-        132
-      ]
+      "code": 6064,
+      "name": "oracleConfidenceTooLow",
+      "msg": "Oracle price confidence interval is too wide"
     },
     {
-      "name": "basktDecommissioningInitiated",
-      "discriminator": [
-        41,
-        181,
-        24,
-        72,
-        93,
-        88,
-        141,
-        100
-      ]
+      "code": 6065,
+      "name": "invalidOracleConfig",
+      "msg": "Invalid oracle config"
     },
     {
-      "name": "basktRebalancedEvent",
-      "discriminator": [
-        201,
-        140,
-        15,
-        4,
-        88,
-        198,
-        74,
-        170
-      ]
+      "code": 6066,
+      "name": "priceUpdateOutOfOrder",
+      "msg": "Price update is not newer than the stored price"
     },
     {
-      "name": "collateralAddedEvent",
-      "discriminator": [
-        90,
-        251,
-        19,
-        246,
-        134,
-        164,
-        243,
-        177
-      ]
+      "code": 6067,
+      "name": "invalidPriceSignature",
+      "msg": "Price message signature is missing or invalid"
     },
     {
-      "name": "fundingIndexUpdatedEvent",
-      "discriminator": [
-        142,
-        186,
-        249,
-        164,
-        193,
-        209,
-        84,
-        145
-      ]
+      "code": 6068,
+      "name": "insufficientOracleSources",
+      "msg": "Not enough usable oracle sources"
     },
     {
-      "name": "liquidityAddedEvent",
-      "discriminator": [
-        220,
-        104,
-        7,
-        39,
-        147,
-        1,
-        194,
-        142
-      ]
+      "code": 6069,
+      "name": "oracleSourcesDisagree",
+      "msg": "Oracle sources disagree beyond the configured spread"
     },
     {
-      "name": "liquidityPoolInitializedEvent",
-      "discriminator": [
-        206,
-        100,
-        66,
-        15,
-        113,
-        180,
-        118,
-        59
-      ]
+      "code": 6070,
+      "name": "navRecordedTooSoon",
+      "msg": "NAV observation recorded too soon after the previous one"
     },
     {
-      "name": "marketIndexUpdatedEvent",
-      "discriminator": [
-        93,
-        35,
-        103,
-        143,
-        225,
-        46,
-        86,
-        224
-      ]
+      "code": 6071,
+      "name": "insufficientNavHistory",
+      "msg": "NAV history does not cover the requested window"
     },
     {
-      "name": "orderCancelledEvent",
-      "discriminator": [
-        200,
-        73,
-        179,
-        145,
-        247,
-        176,
-        10,
-        101
-      ]
+      "code": 6072,
+      "name": "navHistoryRequired",
+      "msg": "NAV history account is required for this baskt"
     },
     {
-      "name": "orderCreatedEvent",
-      "discriminator": [
-        75,
-        88,
-        56,
-        153,
-        87,
-        22,
-        170,
-        46
-      ]
+      "code": 6073,
+      "name": "unsupportedPriceAccountVersion",
+      "msg": "Unsupported price account version"
     },
     {
-      "name": "positionClosedEvent",
-      "discriminator": [
-        76,
-        129,
-        10,
-        225,
-        238,
-        51,
-        158,
-        126
-      ]
+      "code": 6074,
+      "name": "triggerNotReached",
+      "msg": "Execution price has not crossed the order trigger price"
     },
     {
-      "name": "positionForceClosed",
-      "discriminator": [
-        169,
-        152,
-        228,
-        17,
-        218,
-        207,
-        1,
-        119
-      ]
+      "code": 6075,
+      "name": "orderExpired",
+      "msg": "Order has expired"
     },
     {
-      "name": "positionLiquidatedEvent",
-      "discriminator": [
-        70,
-        153,
-        226,
-        254,
-        176,
-        139,
-        225,
-        72
-      ]
+      "code": 6076,
+      "name": "orderNotExpired",
+      "msg": "Order has not expired"
     },
     {
-      "name": "positionOpenedEvent",
-      "discriminator": [
-        163,
-        1,
-        92,
-        149,
-        138,
-        188,
-        177,
-        23
-      ]
+      "code": 6077,
+      "name": "orderSizeExceedsPosition",
+      "msg": "Close order size exceeds the target position size"
     },
     {
-      "name": "protocolStateUpdatedEvent",
-      "discriminator": [
-        191,
-        17,
-        185,
-        143,
-        32,
-        206,
-        61,
-        29
-      ]
+      "code": 6078,
+      "name": "invalidOrderGroup",
+      "msg": "Invalid order group or group members"
     },
     {
-      "name": "rebalanceRequestEvent",
-      "discriminator": [
-        14,
-        9,
-        248,
-        15,
-        162,
-        243,
-        186,
-        161
-      ]
+      "code": 6079,
+      "name": "orderGroupRequired",
+      "msg": "Order group account is required for grouped orders"
     },
     {
-      "name": "withdrawQueueProcessedEvent",
-      "discriminator": [
-        167,
-        34,
-        124,
-        242,
-        149,
-        97,
-        242,
-        17
-      ]
+      "code": 6080,
+      "name": "trancheIntervalNotElapsed",
+      "msg": "TWAP tranche interval has not elapsed"
     },
     {
-      "name": "withdrawalQueuedEvent",
-      "discriminator": [
-        189,
-        125,
-        98,
-        229,
-        140,
-        253,
-        16,
-        86
-      ]
-    }
-  ],
-  "errors": [
+      "code": 6081,
+      "name": "tradingDelegateExpired",
+      "msg": "Trading delegate has expired"
+    },
     {
-      "code": 6000,
-      "name": "mathOverflow",
-      "msg": "Math operation overflow"
+      "code": 6082,
+      "name": "tradingDelegateScopeViolation",
+      "msg": "Trading delegate is not authorized for this baskt"
     },
     {
-      "code": 6001,
-      "name": "insufficientCollateral",
-      "msg": "Insufficient collateral for position"
+      "code": 6083,
+      "name": "tradingDelegateNotionalExceeded",
+      "msg": "Order notional exceeds the trading delegate cap"
     },
     {
-      "code": 6002,
-      "name": "positionNotLiquidatable",
-      "msg": "Position is not liquidatable"
+      "code": 6084,
+      "name": "positionIsCrossMargined",
+      "msg": "Position collateral is held by a margin account"
     },
     {
-      "code": 6003,
-      "name": "positionAlreadyClosed",
-      "msg": "Position is already closed"
+      "code": 6085,
+      "name": "invalidMarginPositions",
+      "msg": "Margin account position set is invalid or incomplete"
     },
     {
-      "code": 6004,
-      "name": "insufficientLiquidity",
-      "msg": "Insufficient liquidity in pool"
+      "code": 6086,
+      "name": "marginAccountFull",
+      "msg": "Margin account has reached its position limit"
     },
     {
-      "code": 6005,
-      "name": "invalidBasktConfig",
-      "msg": "Invalid baskt configuration"
+      "code": 6087,
+      "name": "insufficientMarginEquity",
+      "msg": "Margin account equity is below the required margin"
     },
     {
-      "code": 6006,
-      "name": "invalidPositionSize",
-      "msg": "Invalid position size"
+      "code": 6088,
+      "name": "marginAccountNotLiquidatable",
+      "msg": "Margin account is not liquidatable"
     },
     {
-      "code": 6007,
-      "name": "unauthorized",
-      "msg": "Unauthorized access"
+      "code": 6089,
+      "name": "staleBasktPrice",
+      "msg": "Baskt mark price is too old for margin valuation"
     },
     {
-      "code": 6008,
-      "name": "unauthorizedRole",
-      "msg": "Unauthorized: Missing required role for this operation"
+      "code": 6090,
+      "name": "partialLiquidationTooLarge",
+      "msg": "Liquidation size exceeds what is needed to restore the target margin"
     },
     {
-      "code": 6009,
-      "name": "unauthorizedTokenOwner",
-      "msg": "Unauthorized: Token account owner mismatch"
+      "code": 6091,
+      "name": "invalidInsuranceVault",
+      "msg": "Insurance vault does not match the insurance fund"
     },
     {
-      "code": 6010,
-      "name": "invalidLpTokenAmount",
-      "msg": "Invalid LP token amount"
+      "code": 6092,
+      "name": "adlNotTriggered",
+      "msg": "Auto-deleveraging is not triggered for the current pool state"
     },
     {
-      "code": 6011,
-      "name": "invalidOraclePrice",
-      "msg": "Invalid oracle price"
+      "code": 6093,
+      "name": "adlNotTopRanked",
+      "msg": "Position is not the top-ranked auto-deleveraging candidate"
     },
     {
-      "code": 6012,
-      "name": "priceOutOfBounds",
-      "msg": "Submitted price is outside acceptable deviation bounds from oracle price"
+      "code": 6094,
+      "name": "staleAdlRanking",
+      "msg": "Auto-deleveraging ranking is too old"
     },
     {
-      "code": 6013,
-      "name": "insufficientFunds",
-      "msg": "Insufficient funds for operation"
+      "code": 6095,
+      "name": "circuitBreakerTripped",
+      "msg": "NAV moved beyond the baskt circuit breaker threshold"
+    }
+  ],
+  "types": [
+    {
+      "name": "accessControl",
+      "docs": [
+        "Access control system for the protocol"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entries",
+            "docs": [
+              "Map of accounts to their roles"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "accessControlEntry"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "basktNotActive",
-      "msg": "Baskt is not active for trading"
+      "name": "accessControlEntry",
+      "docs": [
+        "Access control entry for a specific account"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "account",
+            "docs": [
+              "The account that has this role"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "role",
+            "docs": [
+              "The role assigned to this account"
+            ],
+            "type": {
+              "defined": {
+                "name": "role"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "roleNotFound",
-      "msg": "Role not found for the account"
+      "name": "actionParams",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "open",
+            "fields": [
+              {
+                "defined": {
+                  "name": "openOrderParams"
+                }
+              }
+            ]
+          },
+          {
+            "name": "close",
+            "fields": [
+              {
+                "defined": {
+                  "name": "closeOrderParams"
+                }
+              }
+            ]
+          },
+          {
+            "name": "increase",
+            "fields": [
+              {
+                "defined": {
+                  "name": "increaseOrderParams"
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "invalidRoleType",
-      "msg": "Invalid role type"
+      "name": "activateBasktParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prices",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "invalidAssetAccount",
-      "msg": "Invalid asset account"
+      "name": "addAssetParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "permissions",
+            "type": {
+              "defined": {
+                "name": "assetPermissions"
+              }
+            }
+          },
+          {
+            "name": "ticker",
+            "type": "string"
+          },
+          {
+            "name": "oracleSources",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "oracleSource"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "longPositionsDisabled",
-      "msg": "Long positions are disabled for this asset"
+      "name": "addCollateralParams",
+      "docs": [
+        "Parameters for adding collateral to a position"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "additionalCollateral",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "shortPositionsDisabled",
-      "msg": "Short positions are disabled for this asset"
+      "name": "adlEntry",
+      "docs": [
+        "One ranked auto-deleveraging candidate"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "position",
+            "type": "pubkey"
+          },
+          {
+            "name": "score",
+            "docs": [
+              "`Position::adl_score` when last scored"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "unrealizedPnl",
+            "docs": [
+              "Unrealized profit when last scored"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "assetNotInBaskt",
-      "msg": "Asset not in baskt"
+      "name": "adlRanking",
+      "docs": [
+        "Per-baskt ranking of the most profitable, most leveraged open positions, kept by",
+        "keepers and consumed highest score first when the pool cannot cover their profits."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baskt",
+            "type": "pubkey"
+          },
+          {
+            "name": "entries",
+            "docs": [
+              "Sorted by descending score; ties broken by position key for a deterministic order"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "adlEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "lastUpdated",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adlRankingUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baskt",
+            "type": "pubkey"
+          },
+          {
+            "name": "keeper",
+            "type": "pubkey"
+          },
+          {
+            "name": "positionsScored",
+            "type": "u16"
+          },
+          {
+            "name": "rankedPositions",
+            "type": "u16"
+          },
+          {
+            "name": "rankedUnrealizedPnl",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "invalidAssetConfig",
-      "msg": "Invalid asset config"
+      "name": "amendOrderParams",
+      "docs": [
+        "Fields left as None keep their current value"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limitPrice",
+            "docs": [
+              "Limit price for limit orders; slippage guard reference price for market orders"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxSlippageBps",
+            "docs": [
+              "Market order slippage guard"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "notionalValue",
+            "docs": [
+              "Open and increase orders only"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "collateral",
+            "docs": [
+              "Open and increase orders only; the difference moves between owner and escrow"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "tradingDisabled",
-      "msg": "Trading operations are currently disabled"
+      "name": "assetConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetId",
+            "type": "pubkey"
+          },
+          {
+            "name": "direction",
+            "type": "bool"
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "name": "baselinePrice",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "liquidityOperationsDisabled",
-      "msg": "Liquidity operations are currently disabled"
+      "name": "assetPermissions",
+      "docs": [
+        "Permissions for the asset - optimized to use bitfield"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "flags",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "positionOperationsDisabled",
-      "msg": "Position operations are currently disabled"
+      "name": "autoDeleverageParams",
+      "docs": [
+        "Parameters for auto-deleveraging a position"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "exitPrice",
+            "type": "u64"
+          },
+          {
+            "name": "sizeToReduce",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "basktOperationsDisabled",
-      "msg": "Baskt management operations are currently disabled"
+      "name": "baskt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "uid",
+            "type": "u32"
+          },
+          {
+            "name": "currentAssetConfigs",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "assetConfig"
+                }
+              }
+            }
+          },
+          {
+            "name": "isPublic",
+            "type": "bool"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "basktStatus"
+              }
+            }
+          },
+          {
+            "name": "openPositions",
+            "type": "u32"
+          },
+          {
+            "name": "lastRebalanceTime",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rebalancePeriod",
+            "type": "u32"
+          },
+          {
+            "name": "baselineNav",
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "basktConfig"
+              }
+            }
+          },
+          {
+            "name": "marketIndices",
+            "type": {
+              "defined": {
+                "name": "marketIndices"
+              }
+            }
+          },
+          {
+            "name": "rebalanceFeeIndex",
+            "type": {
+              "defined": {
+                "name": "rebalanceFeeIndex"
+              }
+            }
+          },
+          {
+            "name": "longOpenInterest",
+            "type": "u64"
+          },
+          {
+            "name": "shortOpenInterest",
+            "type": "u64"
+          },
+          {
+            "name": "indexPrice",
+            "type": "u64"
+          },
+          {
+            "name": "markPrice",
+            "type": "u64"
+          },
+          {
+            "name": "priceUpdatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "riskConfig",
+            "type": {
+              "defined": {
+                "name": "basktRiskConfig"
+              }
+            }
+          },
+          {
+            "name": "extraSpace",
+            "type": {
+              "array": [
+                "u8",
+                47
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "inactiveAsset",
-      "msg": "Asset Not Active"
+      "name": "basktActivatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basktId",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6027,
-      "name": "basktAlreadyActive",
-      "msg": "Baskt Already Active"
+      "name": "basktClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baskt",
+            "type": "pubkey"
+          },
+          {
+            "name": "closedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "invalidAssetWeights",
-      "msg": "Invalid asset weights"
+      "name": "basktConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "flags",
+            "type": "u8"
+          },
+          {
+            "name": "openingFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "closingFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "liquidationFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "minCollateralRatioBps",
+            "type": "u64"
+          },
+          {
+            "name": "liquidationThresholdBps",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6029,
-      "name": "orderAlreadyProcessed",
-      "msg": "Order already processed"
+      "name": "basktConfigUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baskt",
+            "type": "pubkey"
+          },
+          {
+            "name": "updatedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6030,
-      "name": "invalidEscrowAccount",
-      "msg": "Invalid escrow account"
+      "name": "basktCreatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basktCreationFee",
+            "type": "u64"
+          },
+          {
+            "name": "uid",
+            "type": "u32"
+          },
+          {
+            "name": "basktId",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "isPublic",
+            "type": "bool"
+          },
+          {
+            "name": "assetCount",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "basktRebalancePeriod",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6031,
-      "name": "invalidProgramAuthority",
-      "msg": "Invalid program authority"
+      "name": "basktDecommissioningInitiated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baskt",
+            "type": "pubkey"
+          },
+          {
+            "name": "initiatedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6032,
-      "name": "tokenHasDelegate",
-      "msg": "Token has delegate"
+      "name": "basktHaltedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basktId",
+            "type": "pubkey"
+          },
+          {
+            "name": "nav",
+            "type": "u64"
+          },
+          {
+            "name": "moveBps",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6033,
-      "name": "tokenHasCloseAuthority",
-      "msg": "Token has close authority"
+      "name": "basktPriceAccount",
+      "docs": [
+        "Baskt NAV published for other programs to read (see `crate::reader`).",
+        "",
+        "The layout is stable for a given `version`; fields are only ever appended by taking",
+        "bytes from `reserved`, and any other change bumps `version`. Borsh layout, version 1:",
+        "",
+        "| offset | size | field        |",
+        "|--------|------|--------------|",
+        "| 0      | 8    | discriminator|",
+        "| 8      | 1    | version      |",
+        "| 9      | 32   | baskt        |",
+        "| 41     | 8    | nav (u64)    |",
+        "| 49     | 8    | conf (u64)   |",
+        "| 57     | 4    | exponent (i32, always PRICE_EXPONENT) |",
+        "| 61     | 8    | slot (u64)   |",
+        "| 69     | 8    | publish_time (i64) |",
+        "| 77     | 1    | status (BasktPriceStatus) |",
+        "| 78     | 1    | bump         |",
+        "| 79     | 64   | reserved     |"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "baskt",
+            "type": "pubkey"
+          },
+          {
+            "name": "nav",
+            "type": "u64"
+          },
+          {
+            "name": "conf",
+            "type": "u64"
+          },
+          {
+            "name": "exponent",
+            "type": "i32"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "publishTime",
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "basktPriceStatus"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6034,
-      "name": "invalidMint",
-      "msg": "Invalid mint"
+      "name": "basktPriceStatus",
+      "docs": [
+        "Status of the published price, mirroring the baskt lifecycle"
+      ],
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "unknown"
+          },
+          {
+            "name": "trading"
+          },
+          {
+            "name": "decommissioning"
+          },
+          {
+            "name": "halted"
+          }
+        ]
+      }
     },
     {
-      "code": 6035,
-      "name": "zeroSizedPosition",
-      "msg": "Zero sized position"
+      "name": "basktRebalancedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basktId",
+            "type": "pubkey"
+          },
+          {
+            "name": "rebalanceIndex",
+            "type": "u64"
+          },
+          {
+            "name": "newNav",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6036,
-      "name": "invalidTargetPosition",
-      "msg": "Invalid target position"
+      "name": "basktResumedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basktId",
+            "type": "pubkey"
+          },
+          {
+            "name": "resumedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "nav",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6037,
-      "name": "invalidBaskt",
-      "msg": "Invalid baskt"
+      "name": "basktRiskConfig",
+      "docs": [
+        "Baskt overrides added after launch. Kept apart from `BasktConfig`, whose flag bits are",
+        "used up and which sits mid-account, so existing baskt accounts keep their layout."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "flags",
+            "type": "u8"
+          },
+          {
+            "name": "maxPriceDeviationBps",
+            "type": "u64"
+          },
+          {
+            "name": "liquidationTwapWindowSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "circuitBreaker",
+            "type": {
+              "defined": {
+                "name": "circuitBreakerConfig"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6038,
-      "name": "invalidOrderAction",
-      "msg": "Invalid order action"
+      "name": "basktStatus",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pending"
+          },
+          {
+            "name": "active"
+          },
+          {
+            "name": "decommissioning"
+          },
+          {
+            "name": "halted"
+          }
+        ]
+      }
     },
     {
-      "code": 6039,
-      "name": "fundingNotUpToDate",
-      "msg": "Funding not up to date"
+      "name": "circuitBreakerConfig",
+      "docs": [
+        "Halt the baskt when the oracle NAV moves more than `move_bps` within `window_seconds`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "moveBps",
+            "type": "u64"
+          },
+          {
+            "name": "windowSeconds",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6040,
-      "name": "positionStillOpen",
-      "msg": "Position still open"
+      "name": "closeOrderParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sizeAsContracts",
+            "type": "u64"
+          },
+          {
+            "name": "targetPosition",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6041,
-      "name": "invalidTreasuryAccount",
-      "msg": "Invalid treasury account"
+      "name": "closePositionParams",
+      "docs": [
+        "Parameters for closing a position"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "exitPrice",
+            "type": "u64"
+          },
+          {
+            "name": "sizeToClose",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6042,
-      "name": "collateralOverflow",
-      "msg": "Collateral amount would overflow maximum value"
+      "name": "collateralAddedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "positionId",
+            "type": "u64"
+          },
+          {
+            "name": "basktId",
+            "type": "pubkey"
+          },
+          {
+            "name": "additionalCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "newTotalCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6043,
-      "name": "invalidDepositAmount",
-      "msg": "Invalid deposit amount"
+      "name": "collateralRemovedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "positionId",
+            "type": "u64"
+          },
+          {
+            "name": "basktId",
+            "type": "pubkey"
+          },
+          {
+            "name": "removedCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "newTotalCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "currentPrice",
+            "type": "u64"
+          },
+          {
+            "name": "rebalanceFee",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6044,
-      "name": "invalidUsdcVault",
-      "msg": "Invalid usdc vault account"
+      "name": "createBasktAssetParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "name": "direction",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6045,
-      "name": "invalidFeeBps",
-      "msg": "Invalid fee basis points"
+      "name": "createBasktParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "uid",
+            "type": "u32"
+          },
+          {
+            "name": "assetParams",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "createBasktAssetParams"
+                }
+              }
+            }
+          },
+          {
+            "name": "isPublic",
+            "type": "bool"
+          },
+          {
+            "name": "basktRebalancePeriod",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6046,
-      "name": "invalidCollateralRatio",
-      "msg": "Invalid collateral ratio"
+      "name": "createOrderParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "orderId",
+            "type": "u32"
+          },
+          {
+            "name": "notionalValue",
+            "type": "u64"
+          },
+          {
+            "name": "collateral",
+            "type": "u64"
+          },
+          {
+            "name": "isLong",
+            "type": "bool"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "orderAction"
+              }
+            }
+          },
+          {
+            "name": "targetPosition",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "sizeAsContracts",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "limitPrice",
+            "docs": [
+              "Limit price for limit orders; optional reference price for market orders (0 = none)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxSlippageBps",
+            "docs": [
+              "Market order slippage guard around `limit_price`; ignored by other order types"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "leverageBps",
+            "type": "u64"
+          },
+          {
+            "name": "orderType",
+            "type": {
+              "defined": {
+                "name": "orderType"
+              }
+            }
+          },
+          {
+            "name": "triggerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "triggerDirection",
+            "type": {
+              "defined": {
+                "name": "triggerDirection"
+              }
+            }
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Unix timestamp after which the order can no longer be filled (None = good-til-cancelled)"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "reduceOnly",
+            "docs": [
+              "Close orders only: clamp fills to the remaining position size"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "trailBps",
+            "docs": [
+              "Trailing stops only: retracement from the watermark, which starts at `trigger_price`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "trancheCount",
+            "docs": [
+              "TWAP orders only: number of tranches the notional and collateral are split into"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "trancheIntervalSeconds",
+            "docs": [
+              "TWAP orders only: minimum seconds between tranche fills"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6047,
-      "name": "fundingRateExceedsMaximum",
-      "msg": "Funding rate exceeds maximum allowed"
+      "name": "exitInfo",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "closed",
+            "fields": [
+              {
+                "name": "price",
+                "type": "u64"
+              },
+              {
+                "name": "timestamp",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "liquidated",
+            "fields": [
+              {
+                "name": "price",
+                "type": "u64"
+              },
+              {
+                "name": "timestamp",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "forceClosed",
+            "fields": [
+              {
+                "name": "price",
+                "type": "u64"
+              },
+              {
+                "name": "timestamp",
+                "type": "u32"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6048,
-      "name": "invalidOwner",
-      "msg": "Invalid owner"
+      "name": "featureFlags",
+      "docs": [
+        "Feature flags to enable or disable specific protocol features"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowAddLiquidity",
+            "docs": [
+              "Allow adding liquidity to the protocol"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowRemoveLiquidity",
+            "docs": [
+              "Allow removing liquidity from the protocol"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowOpenPosition",
+            "docs": [
+              "Allow opening new positions"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowClosePosition",
+            "docs": [
+              "Allow closing existing positions"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowPnlWithdrawal",
+            "docs": [
+              "Allow withdrawal of PnL"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowCollateralWithdrawal",
+            "docs": [
+              "Allow withdrawal of collateral"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowAddCollateral",
+            "docs": [
+              "Allow adding collateral to existing positions"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowBasktCreation",
+            "docs": [
+              "Allow creation of new baskts"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowBasktUpdate",
+            "docs": [
+              "Allow updating existing baskts"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowTrading",
+            "docs": [
+              "Allow trading on the protocol"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowLiquidations",
+            "docs": [
+              "Allow liquidations to occur"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6049,
-      "name": "invalidFundingIndex",
-      "msg": "Invalid funding index account"
+      "name": "forceClosePositionParams",
+      "docs": [
+        "Parameters for force closing a position"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "closePrice",
+            "docs": [
+              "Price to use for closing the position"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sizeToClose",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6050,
-      "name": "invalidBasktState",
-      "msg": "Invalid baskt state for this operation"
+      "name": "fundingIndexUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basktId",
+            "type": "pubkey"
+          },
+          {
+            "name": "cumulativeIndex",
+            "type": "i128"
+          },
+          {
+            "name": "currentRate",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6051,
-      "name": "gracePeriodNotOver",
-      "msg": "Grace period has not ended"
+      "name": "increaseOrderParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "notionalValue",
+            "type": "u64"
+          },
+          {
+            "name": "leverageBps",
+            "type": "u64"
+          },
+          {
+            "name": "collateral",
+            "type": "u64"
+          },
+          {
+            "name": "targetPosition",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6052,
-      "name": "positionsStillOpen",
-      "msg": "Positions are still open"
+      "name": "increasePositionParams",
+      "docs": [
+        "Parameters for increasing a position"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "increasePrice",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6053,
-      "name": "invalidGracePeriod",
-      "msg": "Invalid grace period - must be between 1 hour and 7 days"
+      "name": "insuranceFund",
+      "docs": [
+        "Backstop for bad debt, funded by a cut of closing and liquidation fees.",
+        "Settlements with bad debt draw on the vault first; only the remainder is",
+        "socialized to LPs."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "docs": [
+              "Token account holding the fund's collateral"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalFeesReceived",
+            "docs": [
+              "Cumulative fee cuts received"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalBadDebtCovered",
+            "docs": [
+              "Cumulative bad debt paid from the fund into the pool"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalBadDebtSocialized",
+            "docs": [
+              "Cumulative bad debt the fund could not cover and LPs absorbed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6054,
-      "name": "priceDeviationTooHigh",
-      "msg": "Price deviation too high"
+      "name": "insuranceFundInitializedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "insuranceFund",
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "initializer",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6055,
-      "name": "leverageExceeded",
-      "msg": "Realised leverage exceeds declared leverage amount"
+      "name": "limitOrderParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limitPrice",
+            "type": "u64"
+          },
+          {
+            "name": "deprecatedMaxSlippageBps",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6056,
-      "name": "invalidInput",
-      "msg": "Invalid input provided"
+      "name": "liquidatePositionParams",
+      "docs": [
+        "Parameters for liquidating a position"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "exitPrice",
+            "type": "u64"
+          },
+          {
+            "name": "sizeToClose",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6057,
-      "name": "transferFailed",
-      "msg": "SOL transfer failed"
+      "name": "liquidityAddedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "provider",
+            "type": "pubkey"
+          },
+          {
+            "name": "liquidityPool",
+            "type": "pubkey"
+          },
+          {
+            "name": "depositAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "sharesMinted",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6058,
-      "name": "invalidLpTokenEscrow",
-      "msg": "Invalid LP token escrow"
+      "name": "liquidityPool",
+      "docs": [
+        "LiquidityPool represents the shared liquidity pool for the entire protocol",
+        "This is a single pool that acts as the counterparty to all user positions"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalLiquidity",
+            "docs": [
+              "The total amount of liquidity in the pool"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lpMint",
+            "docs": [
+              "The token mint for the LP tokens"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "usdcVault",
+            "docs": [
+              "The token account where USDC is stored"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "lpTokenEscrow",
+            "docs": [
+              "The token account where LP tokens are held during withdrawal queue processing"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalShares",
+            "docs": [
+              "Total supply of LP tokens"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastUpdateTimestamp",
+            "docs": [
+              "The timestamp of the last pool update"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "depositFeeBps",
+            "docs": [
+              "Fee percentage charged on deposits in basis points (e.g. 10 = 0.1%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "withdrawalFeeBps",
+            "docs": [
+              "Fee percentage charged on withdrawals in basis points (e.g. 30 = 0.3%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump for this PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "poolAuthorityBump",
+            "docs": [
+              "Bump for pool authority PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pendingLpTokens",
+            "docs": [
+              "Sum of LP tokens in the withdrawal queue but not yet burned"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawQueueHead",
+            "docs": [
+              "Monotonically increasing identifier for the next withdrawal request to append"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawQueueTail",
+            "docs": [
+              "Identifier of the next withdrawal request expected to be processed"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6059,
-      "name": "borrowRateExceedsMaximum",
-      "msg": "Borrow rate exceeds maximum allowed"
+      "name": "liquidityPoolInitializedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "liquidityPool",
+            "type": "pubkey"
+          },
+          {
+            "name": "lpMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "usdcVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "depositFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "withdrawalFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "initializer",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6060,
-      "name": "invalidBorrowState",
-      "msg": "Invalid borrow state - borrow accumulated should be negative"
-    }
-  ],
-  "types": [
-    {
-      "name": "accessControl",
+      "name": "manualPrice",
       "docs": [
-        "Access control system for the protocol"
+        "Admin-set price used by `OracleSource::Manual`"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "entries",
-            "docs": [
-              "Map of accounts to their roles"
-            ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "accessControlEntry"
-                }
-              }
-            }
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "publishTime",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "accessControlEntry",
+      "name": "marginAccount",
       "docs": [
-        "Access control entry for a specific account"
+        "Cross-margin account: collateral held once and shared by member positions",
+        "across baskts. Member positions keep no collateral of their own."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "account",
-            "docs": [
-              "The account that has this role"
-            ],
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "role",
-            "docs": [
-              "The role assigned to this account"
-            ],
+            "name": "collateral",
+            "type": "u64"
+          },
+          {
+            "name": "positions",
             "type": {
-              "defined": {
-                "name": "role"
-              }
+              "vec": "pubkey"
             }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "actionParams",
+      "name": "marginAccountLiquidatedEvent",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "open",
-            "fields": [
-              {
-                "defined": {
-                  "name": "openOrderParams"
-                }
-              }
-            ]
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "close",
-            "fields": [
-              {
-                "defined": {
-                  "name": "closeOrderParams"
-                }
-              }
-            ]
+            "name": "marginAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "liquidator",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalEquity",
+            "type": "i128"
+          },
+          {
+            "name": "requiredMargin",
+            "type": "u64"
+          },
+          {
+            "name": "positionsClosed",
+            "type": "u8"
+          },
+          {
+            "name": "collateralSeized",
+            "type": "u64"
+          },
+          {
+            "name": "feeToTreasury",
+            "type": "u64"
+          },
+          {
+            "name": "feeToInsurance",
+            "type": "u64"
+          },
+          {
+            "name": "feeToBlp",
+            "type": "u64"
+          },
+          {
+            "name": "escrowToPool",
+            "type": "u64"
+          },
+          {
+            "name": "poolShortfall",
+            "type": "u64"
+          },
+          {
+            "name": "badDebtCovered",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "activateBasktParams",
+      "name": "marginCollateralDepositedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "prices",
-            "type": {
-              "vec": "u64"
-            }
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "marginAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "newTotalCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "addAssetParams",
+      "name": "marginCollateralWithdrawnEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "permissions",
-            "type": {
-              "defined": {
-                "name": "assetPermissions"
-              }
-            }
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "ticker",
-            "type": "string"
+            "name": "marginAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "newTotalCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "marginPositionAttachedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "marginAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "position",
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralMoved",
+            "type": "u64"
+          },
+          {
+            "name": "newTotalCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "marginPositionDetachedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "marginAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "position",
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralMoved",
+            "type": "u64"
+          },
+          {
+            "name": "newTotalCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "addCollateralParams",
-      "docs": [
-        "Parameters for adding collateral to a position"
-      ],
+      "name": "marketIndexUpdatedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "additionalCollateral",
-            "type": "u64"
+            "name": "basktId",
+            "type": "pubkey"
+          },
+          {
+            "name": "cumulativeFundingIndex",
+            "type": "i128"
+          },
+          {
+            "name": "cumulativeBorrowIndex",
+            "type": "i128"
+          },
+          {
+            "name": "currentFundingRate",
+            "type": "i64"
+          },
+          {
+            "name": "currentBorrowRate",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "assetConfig",
+      "name": "marketIndices",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "assetId",
-            "type": "pubkey"
+            "name": "cumulativeFundingIndex",
+            "type": "i128"
           },
           {
-            "name": "direction",
-            "type": "bool"
+            "name": "currentFundingRate",
+            "type": "i64"
           },
           {
-            "name": "weight",
-            "type": "u64"
+            "name": "cumulativeBorrowIndex",
+            "type": "i128"
           },
           {
-            "name": "baselinePrice",
-            "type": "u64"
+            "name": "currentBorrowRate",
+            "type": "i64"
+          },
+          {
+            "name": "lastUpdateTimestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "assetPermissions",
-      "docs": [
-        "Permissions for the asset - optimized to use bitfield"
-      ],
+      "name": "marketOrderParams",
       "type": {
         "kind": "struct",
-        "fields": [
-          {
-            "name": "flags",
-            "type": "u8"
-          }
-        ]
+        "fields": []
       }
     },
     {
-      "name": "baskt",
+      "name": "migrateAssetParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "uid",
-            "type": "u32"
+            "name": "ticker",
+            "type": "string"
           },
           {
-            "name": "currentAssetConfigs",
+            "name": "oracleConfig",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "assetConfig"
-                }
+              "defined": {
+                "name": "oracleConfig"
               }
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "navHistory",
+      "docs": [
+        "Ring buffer of oracle NAV observations for a baskt, written by the `record_nav` crank"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "isPublic",
-            "type": "bool"
+            "name": "baskt",
+            "type": "pubkey"
           },
           {
-            "name": "creator",
-            "type": "pubkey"
+            "name": "head",
+            "type": "u16"
           },
           {
-            "name": "status",
+            "name": "observations",
             "type": {
-              "defined": {
-                "name": "basktStatus"
+              "vec": {
+                "defined": {
+                  "name": "navObservation"
+                }
               }
             }
           },
-          {
-            "name": "openPositions",
-            "type": "u32"
-          },
-          {
-            "name": "lastRebalanceTime",
-            "type": "u32"
-          },
           {
             "name": "bump",
             "type": "u8"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "navObservation",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "rebalancePeriod",
-            "type": "u32"
+            "name": "timestamp",
+            "type": "i64"
           },
           {
-            "name": "baselineNav",
+            "name": "nav",
             "type": "u64"
-          },
-          {
-            "name": "config",
-            "type": {
-              "defined": {
-                "name": "basktConfig"
-              }
-            }
-          },
-          {
-            "name": "marketIndices",
-            "type": {
-              "defined": {
-                "name": "marketIndices"
-              }
-            }
-          },
-          {
-            "name": "rebalanceFeeIndex",
-            "type": {
-              "defined": {
-                "name": "rebalanceFeeIndex"
-              }
-            }
-          },
-          {
-            "name": "extraSpace",
-            "type": {
-              "array": [
-                "u8",
-                120
-              ]
-            }
           }
         ]
       }
     },
     {
-      "name": "basktActivatedEvent",
+      "name": "navRecordedEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -5034,6 +11722,10 @@ export type Baskt = {
             "name": "basktId",
             "type": "pubkey"
           },
+          {
+            "name": "nav",
+            "type": "u64"
+          },
           {
             "name": "timestamp",
             "type": "i64"
@@ -5042,84 +11734,127 @@ export type Baskt = {
       }
     },
     {
-      "name": "basktClosed",
+      "name": "openOrderParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "baskt",
-            "type": "pubkey"
+            "name": "notionalValue",
+            "type": "u64"
           },
           {
-            "name": "closedAt",
-            "type": "i64"
+            "name": "leverageBps",
+            "type": "u64"
+          },
+          {
+            "name": "collateral",
+            "type": "u64"
+          },
+          {
+            "name": "isLong",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "basktConfig",
+      "name": "openPositionParams",
+      "docs": [
+        "Parameters for opening a position"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "flags",
-            "type": "u8"
+            "name": "positionId",
+            "type": "u32"
           },
           {
-            "name": "openingFeeBps",
+            "name": "entryPrice",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleConfig",
+      "docs": [
+        "Per-asset oracle policy applied whenever the asset's price is consumed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sources",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "oracleSource"
+                }
+              }
+            }
           },
           {
-            "name": "closingFeeBps",
-            "type": "u64"
+            "name": "minSources",
+            "type": "u8"
           },
           {
-            "name": "liquidationFeeBps",
+            "name": "maxPriceAgeSeconds",
             "type": "u64"
           },
           {
-            "name": "minCollateralRatioBps",
+            "name": "maxConfBps",
             "type": "u64"
           },
           {
-            "name": "liquidationThresholdBps",
+            "name": "maxSpreadBps",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "basktConfigUpdatedEvent",
+      "name": "oracleSource",
+      "docs": [
+        "A price source an asset can be priced from"
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "baskt",
-            "type": "pubkey"
+            "name": "pyth",
+            "fields": [
+              {
+                "name": "feedId",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
           },
           {
-            "name": "updatedBy",
-            "type": "pubkey"
+            "name": "priceFeed"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "manual"
           }
         ]
       }
     },
     {
-      "name": "basktCreatedEvent",
+      "name": "order",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "basktCreationFee",
-            "type": "u64"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "uid",
+            "name": "orderId",
             "type": "u32"
           },
           {
@@ -5127,70 +11862,91 @@ export type Baskt = {
             "type": "pubkey"
           },
           {
-            "name": "creator",
-            "type": "pubkey"
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "orderAction"
+              }
+            }
+          },
+          {
+            "name": "orderType",
+            "type": {
+              "defined": {
+                "name": "orderType"
+              }
+            }
+          },
+          {
+            "name": "actionParams",
+            "type": {
+              "defined": {
+                "name": "actionParams"
+              }
+            }
           },
           {
-            "name": "isPublic",
-            "type": "bool"
+            "name": "orderTypeParams",
+            "type": {
+              "defined": {
+                "name": "orderTypeParams"
+              }
+            }
           },
           {
-            "name": "assetCount",
-            "type": "u8"
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "orderStatus"
+              }
+            }
           },
           {
             "name": "timestamp",
-            "type": "i64"
+            "type": "u32"
           },
           {
-            "name": "basktRebalancePeriod",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "basktDecommissioningInitiated",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "baskt",
-            "type": "pubkey"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "initiatedAt",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "basktRebalancedEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          },
           {
-            "name": "basktId",
-            "type": "pubkey"
+            "name": "orderGroup",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "rebalanceIndex",
-            "type": "u64"
+            "name": "reduceOnly",
+            "type": "bool"
           },
           {
-            "name": "newNav",
-            "type": "u64"
+            "name": "slippageGuard",
+            "type": {
+              "defined": {
+                "name": "slippageGuard"
+              }
+            }
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "extraSpace",
+            "type": {
+              "array": [
+                "u8",
+                59
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "basktStatus",
+      "name": "orderAction",
       "repr": {
         "kind": "rust"
       },
@@ -5198,56 +11954,67 @@ export type Baskt = {
         "kind": "enum",
         "variants": [
           {
-            "name": "pending"
+            "name": "open"
           },
           {
-            "name": "active"
+            "name": "close"
           },
           {
-            "name": "decommissioning"
+            "name": "increase"
           }
         ]
       }
     },
     {
-      "name": "closeOrderParams",
+      "name": "orderAmendedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "sizeAsContracts",
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "orderId",
             "type": "u64"
           },
           {
-            "name": "targetPosition",
+            "name": "basktId",
             "type": "pubkey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "closePositionParams",
-      "docs": [
-        "Parameters for closing a position"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "exitPrice",
-            "type": "u64"
+            "name": "limitPrice",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "sizeToClose",
+            "name": "maxSlippageBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "notionalValue",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "collateral",
             "type": {
               "option": "u64"
             }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "collateralAddedEvent",
+      "name": "orderCancelledEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -5256,21 +12023,13 @@ export type Baskt = {
             "type": "pubkey"
           },
           {
-            "name": "positionId",
+            "name": "orderId",
             "type": "u64"
           },
           {
             "name": "basktId",
             "type": "pubkey"
           },
-          {
-            "name": "additionalCollateral",
-            "type": "u64"
-          },
-          {
-            "name": "newTotalCollateral",
-            "type": "u64"
-          },
           {
             "name": "timestamp",
             "type": "i64"
@@ -5279,59 +12038,21 @@ export type Baskt = {
       }
     },
     {
-      "name": "createBasktAssetParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "weight",
-            "type": "u64"
-          },
-          {
-            "name": "direction",
-            "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "createBasktParams",
+      "name": "orderCreatedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "uid",
-            "type": "u32"
-          },
-          {
-            "name": "assetParams",
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "createBasktAssetParams"
-                }
-              }
-            }
-          },
-          {
-            "name": "isPublic",
-            "type": "bool"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "basktRebalancePeriod",
+            "name": "orderId",
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "createOrderParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "orderId",
-            "type": "u32"
+            "name": "basktId",
+            "type": "pubkey"
           },
           {
             "name": "notionalValue",
@@ -5356,216 +12077,132 @@ export type Baskt = {
           {
             "name": "targetPosition",
             "type": {
-              "option": "pubkey"
-            }
-          },
-          {
-            "name": "sizeAsContracts",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "limitPrice",
-            "type": "u64"
-          },
-          {
-            "name": "maxSlippageBps",
-            "type": "u64"
-          },
-          {
-            "name": "leverageBps",
-            "type": "u64"
-          },
-          {
-            "name": "orderType",
-            "type": {
-              "defined": {
-                "name": "orderType"
-              }
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "exitInfo",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "none"
-          },
-          {
-            "name": "closed",
-            "fields": [
-              {
-                "name": "price",
-                "type": "u64"
-              },
-              {
-                "name": "timestamp",
-                "type": "u32"
-              }
-            ]
-          },
-          {
-            "name": "liquidated",
-            "fields": [
-              {
-                "name": "price",
-                "type": "u64"
-              },
-              {
-                "name": "timestamp",
-                "type": "u32"
-              }
-            ]
+              "option": "pubkey"
+            }
           },
           {
-            "name": "forceClosed",
-            "fields": [
-              {
-                "name": "price",
-                "type": "u64"
-              },
-              {
-                "name": "timestamp",
-                "type": "u32"
-              }
-            ]
-          }
-        ]
-      }
-    },
-    {
-      "name": "featureFlags",
-      "docs": [
-        "Feature flags to enable or disable specific protocol features"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "limitPrice",
+            "type": "u64"
+          },
           {
-            "name": "allowAddLiquidity",
-            "docs": [
-              "Allow adding liquidity to the protocol"
-            ],
-            "type": "bool"
+            "name": "maxSlippageBps",
+            "type": "u64"
           },
           {
-            "name": "allowRemoveLiquidity",
-            "docs": [
-              "Allow removing liquidity from the protocol"
-            ],
-            "type": "bool"
+            "name": "orderType",
+            "type": {
+              "defined": {
+                "name": "orderType"
+              }
+            }
           },
           {
-            "name": "allowOpenPosition",
-            "docs": [
-              "Allow opening new positions"
-            ],
-            "type": "bool"
+            "name": "triggerPrice",
+            "type": "u64"
           },
           {
-            "name": "allowClosePosition",
-            "docs": [
-              "Allow closing existing positions"
-            ],
-            "type": "bool"
+            "name": "triggerDirection",
+            "type": {
+              "defined": {
+                "name": "triggerDirection"
+              }
+            }
           },
           {
-            "name": "allowPnlWithdrawal",
-            "docs": [
-              "Allow withdrawal of PnL"
-            ],
-            "type": "bool"
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
           },
           {
-            "name": "allowCollateralWithdrawal",
-            "docs": [
-              "Allow withdrawal of collateral"
-            ],
-            "type": "bool"
+            "name": "leverageBps",
+            "type": "u64"
           },
           {
-            "name": "allowAddCollateral",
-            "docs": [
-              "Allow adding collateral to existing positions"
-            ],
-            "type": "bool"
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderExpiredEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "allowBasktCreation",
-            "docs": [
-              "Allow creation of new baskts"
-            ],
-            "type": "bool"
+            "name": "orderId",
+            "type": "u64"
           },
           {
-            "name": "allowBasktUpdate",
-            "docs": [
-              "Allow updating existing baskts"
-            ],
-            "type": "bool"
+            "name": "basktId",
+            "type": "pubkey"
           },
           {
-            "name": "allowTrading",
-            "docs": [
-              "Allow trading on the protocol"
-            ],
-            "type": "bool"
+            "name": "expiredBy",
+            "type": "pubkey"
           },
           {
-            "name": "allowLiquidations",
-            "docs": [
-              "Allow liquidations to occur"
-            ],
-            "type": "bool"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "forceClosePositionParams",
+      "name": "orderGroup",
       "docs": [
-        "Parameters for force closing a position"
+        "One-cancels-other link between pending close orders of the same owner.",
+        "Filling any member cancels the others and closes the group."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "closePrice",
-            "docs": [
-              "Price to use for closing the position"
-            ],
-            "type": "u64"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "sizeToClose",
+            "name": "groupId",
+            "type": "u32"
+          },
+          {
+            "name": "orders",
             "type": {
-              "option": "u64"
+              "vec": "pubkey"
             }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "fundingIndexUpdatedEvent",
+      "name": "orderGroupCreatedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "basktId",
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "cumulativeIndex",
-            "type": "i128"
+            "name": "orderGroup",
+            "type": "pubkey"
           },
           {
-            "name": "currentRate",
-            "type": "i64"
+            "name": "groupId",
+            "type": "u32"
+          },
+          {
+            "name": "memberCount",
+            "type": "u8"
           },
           {
             "name": "timestamp",
@@ -5575,674 +12212,581 @@ export type Baskt = {
       }
     },
     {
-      "name": "limitOrderParams",
+      "name": "orderStatus",
+      "repr": {
+        "kind": "rust"
+      },
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "limitPrice",
-            "type": "u64"
+            "name": "pending"
           },
           {
-            "name": "maxSlippageBps",
-            "type": "u64"
+            "name": "filled"
+          },
+          {
+            "name": "cancelled"
           }
         ]
       }
     },
     {
-      "name": "liquidatePositionParams",
-      "docs": [
-        "Parameters for liquidating a position"
-      ],
+      "name": "orderType",
+      "repr": {
+        "kind": "rust"
+      },
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "exitPrice",
-            "type": "u64"
+            "name": "market"
           },
           {
-            "name": "sizeToClose",
-            "type": {
-              "option": "u64"
-            }
+            "name": "limit"
+          },
+          {
+            "name": "stopLoss"
+          },
+          {
+            "name": "takeProfit"
+          },
+          {
+            "name": "trailingStop"
+          },
+          {
+            "name": "twap"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderTypeParams",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "market",
+            "fields": [
+              {
+                "defined": {
+                  "name": "marketOrderParams"
+                }
+              }
+            ]
+          },
+          {
+            "name": "limit",
+            "fields": [
+              {
+                "defined": {
+                  "name": "limitOrderParams"
+                }
+              }
+            ]
+          },
+          {
+            "name": "stopLoss",
+            "fields": [
+              {
+                "defined": {
+                  "name": "triggerOrderParams"
+                }
+              }
+            ]
+          },
+          {
+            "name": "takeProfit",
+            "fields": [
+              {
+                "defined": {
+                  "name": "triggerOrderParams"
+                }
+              }
+            ]
+          },
+          {
+            "name": "trailingStop",
+            "fields": [
+              {
+                "defined": {
+                  "name": "trailingStopParams"
+                }
+              }
+            ]
+          },
+          {
+            "name": "twap",
+            "fields": [
+              {
+                "defined": {
+                  "name": "twapOrderParams"
+                }
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "liquidityAddedEvent",
+      "name": "position",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "provider",
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "liquidityPool",
-            "type": "pubkey"
+            "name": "positionId",
+            "type": "u32"
           },
           {
-            "name": "depositAmount",
-            "type": "u64"
+            "name": "basktId",
+            "type": "pubkey"
           },
           {
-            "name": "feeAmount",
+            "name": "size",
             "type": "u64"
           },
           {
-            "name": "sharesMinted",
+            "name": "collateral",
             "type": "u64"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "liquidityPool",
-      "docs": [
-        "LiquidityPool represents the shared liquidity pool for the entire protocol",
-        "This is a single pool that acts as the counterparty to all user positions"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "isLong",
+            "type": "bool"
+          },
           {
-            "name": "totalLiquidity",
-            "docs": [
-              "The total amount of liquidity in the pool"
-            ],
+            "name": "entryPrice",
             "type": "u64"
           },
           {
-            "name": "lpMint",
-            "docs": [
-              "The token mint for the LP tokens"
-            ],
-            "type": "pubkey"
+            "name": "exitInfo",
+            "type": {
+              "defined": {
+                "name": "exitInfo"
+              }
+            }
           },
           {
-            "name": "usdcVault",
-            "docs": [
-              "The token account where USDC is stored"
-            ],
-            "type": "pubkey"
+            "name": "lastFundingIndex",
+            "type": "i128"
           },
           {
-            "name": "lpTokenEscrow",
-            "docs": [
-              "The token account where LP tokens are held during withdrawal queue processing"
-            ],
-            "type": "pubkey"
+            "name": "fundingAccumulated",
+            "type": "i128"
           },
           {
-            "name": "totalShares",
-            "docs": [
-              "Total supply of LP tokens"
-            ],
-            "type": "u64"
+            "name": "lastBorrowIndex",
+            "type": "i128"
           },
           {
-            "name": "lastUpdateTimestamp",
-            "docs": [
-              "The timestamp of the last pool update"
-            ],
-            "type": "i64"
+            "name": "borrowAccumulated",
+            "type": "i128"
           },
           {
-            "name": "depositFeeBps",
-            "docs": [
-              "Fee percentage charged on deposits in basis points (e.g. 10 = 0.1%)"
-            ],
-            "type": "u16"
+            "name": "lastRebalanceFeeIndex",
+            "type": "u64"
           },
           {
-            "name": "withdrawalFeeBps",
-            "docs": [
-              "Fee percentage charged on withdrawals in basis points (e.g. 30 = 0.3%)"
-            ],
-            "type": "u16"
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "positionStatus"
+              }
+            }
           },
           {
-            "name": "bump",
-            "docs": [
-              "Bump for this PDA"
-            ],
-            "type": "u8"
+            "name": "timestampOpen",
+            "type": "u32"
           },
           {
-            "name": "poolAuthorityBump",
-            "docs": [
-              "Bump for pool authority PDA"
-            ],
+            "name": "bump",
             "type": "u8"
           },
           {
-            "name": "pendingLpTokens",
-            "docs": [
-              "Sum of LP tokens in the withdrawal queue but not yet burned"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "withdrawQueueHead",
-            "docs": [
-              "Monotonically increasing identifier for the next withdrawal request to append"
-            ],
-            "type": "u64"
+            "name": "marginAccount",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "withdrawQueueTail",
-            "docs": [
-              "Identifier of the next withdrawal request expected to be processed"
-            ],
-            "type": "u64"
+            "name": "extraSpace",
+            "type": {
+              "array": [
+                "u8",
+                87
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "liquidityPoolInitializedEvent",
+      "name": "positionAutoDeleveragedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "liquidityPool",
+            "name": "baskt",
             "type": "pubkey"
           },
           {
-            "name": "lpMint",
+            "name": "position",
             "type": "pubkey"
           },
           {
-            "name": "usdcVault",
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "depositFeeBps",
-            "type": "u16"
-          },
-          {
-            "name": "withdrawalFeeBps",
-            "type": "u16"
-          },
-          {
-            "name": "initializer",
+            "name": "keeper",
             "type": "pubkey"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "marketIndexUpdatedEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "basktId",
-            "type": "pubkey"
+            "name": "exitPrice",
+            "type": "u64"
           },
           {
-            "name": "cumulativeFundingIndex",
-            "type": "i128"
+            "name": "sizeReduced",
+            "type": "u64"
           },
           {
-            "name": "cumulativeBorrowIndex",
-            "type": "i128"
+            "name": "sizeRemaining",
+            "type": "u64"
           },
           {
-            "name": "currentFundingRate",
-            "type": "i64"
+            "name": "adlScore",
+            "type": "u128"
           },
           {
-            "name": "currentBorrowRate",
-            "type": "i64"
+            "name": "rankedUnrealizedPnl",
+            "type": "u64"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "marketIndices",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "effectiveLiquidity",
+            "type": "u64"
+          },
           {
-            "name": "cumulativeFundingIndex",
+            "name": "pnl",
             "type": "i128"
           },
           {
-            "name": "currentFundingRate",
-            "type": "i64"
+            "name": "escrowToUser",
+            "type": "u64"
           },
           {
-            "name": "cumulativeBorrowIndex",
-            "type": "i128"
+            "name": "poolToUser",
+            "type": "u64"
           },
           {
-            "name": "currentBorrowRate",
-            "type": "i64"
+            "name": "userTotalPayout",
+            "type": "u64"
           },
           {
-            "name": "lastUpdateTimestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "marketOrderParams",
-      "type": {
-        "kind": "struct",
-        "fields": []
-      }
-    },
-    {
-      "name": "openOrderParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "notionalValue",
+            "name": "pnlHaircut",
+            "docs": [
+              "Profit forfeited because the pool could not pay it out"
+            ],
             "type": "u64"
           },
           {
-            "name": "leverageBps",
+            "name": "rebalanceFee",
             "type": "u64"
           },
           {
-            "name": "collateral",
+            "name": "collateralRemaining",
             "type": "u64"
           },
           {
-            "name": "isLong",
-            "type": "bool"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "openPositionParams",
-      "docs": [
-        "Parameters for opening a position"
-      ],
+      "name": "positionClosedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "positionId",
-            "type": "u32"
-          },
-          {
-            "name": "entryPrice",
+            "name": "orderId",
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "order",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
             "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "orderId",
-            "type": "u32"
+            "name": "positionId",
+            "type": "u64"
           },
           {
             "name": "basktId",
             "type": "pubkey"
           },
           {
-            "name": "action",
-            "type": {
-              "defined": {
-                "name": "orderAction"
-              }
-            }
+            "name": "sizeClosed",
+            "type": "u64"
           },
           {
-            "name": "orderType",
-            "type": {
-              "defined": {
-                "name": "orderType"
-              }
-            }
+            "name": "sizeRemaining",
+            "type": "u64"
           },
           {
-            "name": "actionParams",
-            "type": {
-              "defined": {
-                "name": "actionParams"
-              }
-            }
+            "name": "exitPrice",
+            "type": "u64"
           },
           {
-            "name": "orderTypeParams",
-            "type": {
-              "defined": {
-                "name": "orderTypeParams"
-              }
-            }
+            "name": "timestamp",
+            "type": "i64"
           },
           {
-            "name": "status",
-            "type": {
-              "defined": {
-                "name": "orderStatus"
-              }
-            }
+            "name": "collateralRemaining",
+            "type": "u64"
           },
           {
-            "name": "timestamp",
-            "type": "u32"
+            "name": "feeToTreasury",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "feeToBlp",
+            "type": "u64"
           },
           {
-            "name": "extraSpace",
-            "type": {
-              "array": [
-                "u8",
-                150
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "orderAction",
-      "repr": {
-        "kind": "rust"
-      },
-      "type": {
-        "kind": "enum",
-        "variants": [
+            "name": "pnl",
+            "type": "i128"
+          },
           {
-            "name": "open"
+            "name": "fundingAccumulated",
+            "type": "i128"
           },
           {
-            "name": "close"
-          }
-        ]
-      }
-    },
-    {
-      "name": "orderCancelledEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "borrowAccumulated",
+            "type": "i128"
+          },
           {
-            "name": "owner",
-            "type": "pubkey"
+            "name": "escrowToTreasury",
+            "type": "u64"
           },
           {
-            "name": "orderId",
+            "name": "escrowToPool",
             "type": "u64"
           },
           {
-            "name": "basktId",
-            "type": "pubkey"
+            "name": "escrowToUser",
+            "type": "u64"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "poolToUser",
+            "type": "u64"
+          },
+          {
+            "name": "userTotalPayout",
+            "type": "u64"
+          },
+          {
+            "name": "baseFee",
+            "type": "u64"
+          },
+          {
+            "name": "rebalanceFee",
+            "type": "u64"
+          },
+          {
+            "name": "badDebtAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeToInsurance",
+            "type": "u64"
+          },
+          {
+            "name": "badDebtCovered",
+            "type": "u64"
+          },
+          {
+            "name": "collateralReleased",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "orderCreatedEvent",
+      "name": "positionForceClosed",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "baskt",
             "type": "pubkey"
           },
           {
-            "name": "orderId",
-            "type": "u64"
+            "name": "position",
+            "type": "pubkey"
           },
           {
-            "name": "basktId",
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "notionalValue",
+            "name": "closePrice",
             "type": "u64"
           },
           {
-            "name": "collateral",
+            "name": "sizeClosed",
             "type": "u64"
           },
           {
-            "name": "isLong",
-            "type": "bool"
+            "name": "sizeRemaining",
+            "type": "u64"
           },
           {
-            "name": "action",
-            "type": {
-              "defined": {
-                "name": "orderAction"
-              }
-            }
+            "name": "timestamp",
+            "type": "i64"
           },
           {
-            "name": "targetPosition",
-            "type": {
-              "option": "pubkey"
-            }
+            "name": "feeToTreasury",
+            "type": "u64"
           },
           {
-            "name": "limitPrice",
+            "name": "feeToBlp",
             "type": "u64"
           },
           {
-            "name": "maxSlippageBps",
+            "name": "collateralRemaining",
             "type": "u64"
           },
           {
-            "name": "orderType",
-            "type": {
-              "defined": {
-                "name": "orderType"
-              }
-            }
+            "name": "pnl",
+            "type": "i128"
           },
           {
-            "name": "leverageBps",
+            "name": "fundingAccumulated",
+            "type": "i128"
+          },
+          {
+            "name": "borrowAccumulated",
+            "type": "i128"
+          },
+          {
+            "name": "escrowToTreasury",
             "type": "u64"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "orderStatus",
-      "repr": {
-        "kind": "rust"
-      },
-      "type": {
-        "kind": "enum",
-        "variants": [
+            "name": "escrowToPool",
+            "type": "u64"
+          },
           {
-            "name": "pending"
+            "name": "escrowToUser",
+            "type": "u64"
           },
           {
-            "name": "filled"
+            "name": "poolToUser",
+            "type": "u64"
           },
           {
-            "name": "cancelled"
-          }
-        ]
-      }
-    },
-    {
-      "name": "orderType",
-      "repr": {
-        "kind": "rust"
-      },
-      "type": {
-        "kind": "enum",
-        "variants": [
+            "name": "userTotalPayout",
+            "type": "u64"
+          },
           {
-            "name": "market"
+            "name": "baseFee",
+            "type": "u64"
           },
           {
-            "name": "limit"
-          }
-        ]
-      }
-    },
-    {
-      "name": "orderTypeParams",
-      "type": {
-        "kind": "enum",
-        "variants": [
+            "name": "rebalanceFee",
+            "type": "u64"
+          },
           {
-            "name": "market",
-            "fields": [
-              {
-                "defined": {
-                  "name": "marketOrderParams"
-                }
-              }
-            ]
+            "name": "badDebtAmount",
+            "type": "u64"
           },
           {
-            "name": "limit",
-            "fields": [
-              {
-                "defined": {
-                  "name": "limitOrderParams"
-                }
-              }
-            ]
+            "name": "feeToInsurance",
+            "type": "u64"
+          },
+          {
+            "name": "badDebtCovered",
+            "type": "u64"
+          },
+          {
+            "name": "collateralReleased",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "position",
+      "name": "positionIncreasedEvent",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "orderId",
+            "type": "u64"
+          },
           {
             "name": "owner",
             "type": "pubkey"
           },
           {
             "name": "positionId",
-            "type": "u32"
+            "type": "u64"
           },
           {
             "name": "basktId",
             "type": "pubkey"
           },
           {
-            "name": "size",
+            "name": "sizeAdded",
             "type": "u64"
           },
           {
-            "name": "collateral",
+            "name": "newSize",
             "type": "u64"
           },
           {
-            "name": "isLong",
-            "type": "bool"
-          },
-          {
-            "name": "entryPrice",
+            "name": "collateralAdded",
             "type": "u64"
           },
           {
-            "name": "exitInfo",
-            "type": {
-              "defined": {
-                "name": "exitInfo"
-              }
-            }
-          },
-          {
-            "name": "lastFundingIndex",
-            "type": "i128"
-          },
-          {
-            "name": "fundingAccumulated",
-            "type": "i128"
-          },
-          {
-            "name": "lastBorrowIndex",
-            "type": "i128"
+            "name": "newCollateral",
+            "type": "u64"
           },
           {
-            "name": "borrowAccumulated",
-            "type": "i128"
+            "name": "increasePrice",
+            "type": "u64"
           },
           {
-            "name": "lastRebalanceFeeIndex",
+            "name": "newEntryPrice",
             "type": "u64"
           },
           {
-            "name": "status",
-            "type": {
-              "defined": {
-                "name": "positionStatus"
-              }
-            }
+            "name": "rebalanceFee",
+            "type": "u64"
           },
           {
-            "name": "timestampOpen",
-            "type": "u32"
+            "name": "feeToTreasury",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "feeToBlp",
+            "type": "u64"
           },
           {
-            "name": "extraSpace",
-            "type": {
-              "array": [
-                "u8",
-                120
-              ]
-            }
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "positionClosedEvent",
+      "name": "positionLiquidatedEvent",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "orderId",
-            "type": "u64"
-          },
           {
             "name": "owner",
             "type": "pubkey"
@@ -6256,7 +12800,7 @@ export type Baskt = {
             "type": "pubkey"
           },
           {
-            "name": "sizeClosed",
+            "name": "sizeLiquidated",
             "type": "u64"
           },
           {
@@ -6272,15 +12816,15 @@ export type Baskt = {
             "type": "i64"
           },
           {
-            "name": "collateralRemaining",
+            "name": "feeToTreasury",
             "type": "u64"
           },
           {
-            "name": "feeToTreasury",
+            "name": "feeToBlp",
             "type": "u64"
           },
           {
-            "name": "feeToBlp",
+            "name": "collateralRemaining",
             "type": "u64"
           },
           {
@@ -6327,6 +12871,14 @@ export type Baskt = {
             "name": "badDebtAmount",
             "type": "u64"
           },
+          {
+            "name": "feeToInsurance",
+            "type": "u64"
+          },
+          {
+            "name": "badDebtCovered",
+            "type": "u64"
+          },
           {
             "name": "collateralReleased",
             "type": "u64"
@@ -6335,37 +12887,41 @@ export type Baskt = {
       }
     },
     {
-      "name": "positionForceClosed",
+      "name": "positionOpenedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "baskt",
-            "type": "pubkey"
+            "name": "orderId",
+            "type": "u64"
           },
           {
-            "name": "position",
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "owner",
+            "name": "positionId",
+            "type": "u64"
+          },
+          {
+            "name": "basktId",
             "type": "pubkey"
           },
           {
-            "name": "closePrice",
+            "name": "size",
             "type": "u64"
           },
           {
-            "name": "sizeClosed",
+            "name": "collateral",
             "type": "u64"
           },
           {
-            "name": "sizeRemaining",
-            "type": "u64"
+            "name": "isLong",
+            "type": "bool"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "entryPrice",
+            "type": "u64"
           },
           {
             "name": "feeToTreasury",
@@ -6376,289 +12932,506 @@ export type Baskt = {
             "type": "u64"
           },
           {
-            "name": "collateralRemaining",
-            "type": "u64"
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionStatus",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "open"
           },
           {
-            "name": "pnl",
-            "type": "i128"
+            "name": "closed"
           },
           {
-            "name": "fundingAccumulated",
-            "type": "i128"
+            "name": "liquidated"
           },
           {
-            "name": "borrowAccumulated",
-            "type": "i128"
+            "name": "forceClosed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceFeed",
+      "docs": [
+        "Protocol-owned price for an asset without a Pyth feed.",
+        "Written by `Role::Oracle` holders, either directly or via an ed25519-signed message."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "asset",
+            "type": "pubkey"
           },
           {
-            "name": "escrowToTreasury",
+            "name": "price",
             "type": "u64"
           },
           {
-            "name": "escrowToPool",
+            "name": "conf",
             "type": "u64"
           },
           {
-            "name": "escrowToUser",
-            "type": "u64"
+            "name": "publishTime",
+            "type": "i64"
           },
           {
-            "name": "poolToUser",
-            "type": "u64"
+            "name": "lastSigner",
+            "type": "pubkey"
           },
           {
-            "name": "userTotalPayout",
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceFeedUpdateParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
             "type": "u64"
           },
           {
-            "name": "baseFee",
+            "name": "conf",
             "type": "u64"
           },
           {
-            "name": "rebalanceFee",
-            "type": "u64"
+            "name": "publishTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceFeedUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "priceFeed",
+            "type": "pubkey"
           },
           {
-            "name": "badDebtAmount",
+            "name": "asset",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
             "type": "u64"
           },
           {
-            "name": "collateralReleased",
+            "name": "conf",
             "type": "u64"
+          },
+          {
+            "name": "publishTime",
+            "type": "i64"
+          },
+          {
+            "name": "signer",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "positionLiquidatedEvent",
+      "name": "programAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "protocol",
+      "docs": [
+        "* It should track all the assets that we have in the system.\n * it should keep track of all the fees we are charging and what not\n * it should keep track of min liqudiation margins\n * it should keep track of fee splits\n * it should keep track of owner and be able to transfer ownership\n * it should keep track of protocol level stats maybe? fees, trading, volume, total baskts, total assets"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "isInitialized",
+            "type": "bool"
+          },
           {
             "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "positionId",
-            "type": "u64"
+            "name": "accessControl",
+            "type": {
+              "defined": {
+                "name": "accessControl"
+              }
+            }
           },
           {
-            "name": "basktId",
+            "name": "featureFlags",
+            "type": {
+              "defined": {
+                "name": "featureFlags"
+              }
+            }
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralMint",
+            "docs": [
+              "Collateral mint (USDC)"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "sizeLiquidated",
+            "name": "config",
+            "docs": [
+              "Protocol configuration parameters"
+            ],
+            "type": {
+              "defined": {
+                "name": "protocolConfig"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "openingFeeBps",
+            "docs": [
+              "Fee parameters (in basis points)"
+            ],
             "type": "u64"
           },
           {
-            "name": "sizeRemaining",
+            "name": "closingFeeBps",
             "type": "u64"
           },
           {
-            "name": "exitPrice",
+            "name": "liquidationFeeBps",
             "type": "u64"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "treasuryCutBps",
+            "docs": [
+              "Fee split parameters (in basis points)"
+            ],
+            "type": "u64"
           },
           {
-            "name": "feeToTreasury",
+            "name": "fundingCutBps",
             "type": "u64"
           },
           {
-            "name": "feeToBlp",
+            "name": "insuranceCutBps",
+            "docs": [
+              "Share of closing and liquidation fees routed to the insurance fund"
+            ],
             "type": "u64"
           },
           {
-            "name": "collateralRemaining",
+            "name": "maxFundingRateBps",
+            "docs": [
+              "Funding parameters"
+            ],
             "type": "u64"
           },
           {
-            "name": "pnl",
-            "type": "i128"
+            "name": "fundingIntervalSeconds",
+            "type": "i64"
           },
           {
-            "name": "fundingAccumulated",
-            "type": "i128"
+            "name": "minCollateralRatioBps",
+            "docs": [
+              "Risk parameters"
+            ],
+            "type": "u64"
           },
           {
-            "name": "borrowAccumulated",
-            "type": "i128"
+            "name": "liquidationThresholdBps",
+            "type": "u64"
           },
           {
-            "name": "escrowToTreasury",
+            "name": "minLiquidity",
+            "docs": [
+              "Liquidity parameters"
+            ],
             "type": "u64"
           },
           {
-            "name": "escrowToPool",
+            "name": "maxPriceDeviationBps",
+            "docs": [
+              "Max deviation of a submitted fill price from the oracle NAV (in basis points)"
+            ],
             "type": "u64"
           },
           {
-            "name": "escrowToUser",
+            "name": "partialLiquidationTargetBps",
+            "docs": [
+              "Partial liquidation parameters (in basis points): margin ratio a partial liquidation",
+              "restores (0 = disabled) and the ratio below which a full liquidation is allowed"
+            ],
             "type": "u64"
           },
           {
-            "name": "poolToUser",
+            "name": "fullLiquidationThresholdBps",
             "type": "u64"
           },
           {
-            "name": "userTotalPayout",
+            "name": "adlPnlToPoolBps",
+            "docs": [
+              "Ranked unrealized profit of a baskt, as a share of effective pool liquidity, above",
+              "which its top-ranked positions may be auto-deleveraged (in basis points, 0 = disabled)"
+            ],
             "type": "u64"
           },
           {
-            "name": "baseFee",
+            "name": "rebalanceRequestFeeLamports",
+            "docs": [
+              "Rebalance request fee in lamports (SOL)"
+            ],
             "type": "u64"
           },
           {
-            "name": "rebalanceFee",
+            "name": "basktCreationFeeLamports",
+            "docs": [
+              "Baskt creation fee in lamports (SOL)"
+            ],
             "type": "u64"
           },
           {
-            "name": "badDebtAmount",
-            "type": "u64"
+            "name": "lastUpdated",
+            "docs": [
+              "Metadata"
+            ],
+            "type": "i64"
           },
           {
-            "name": "collateralReleased",
-            "type": "u64"
+            "name": "lastUpdatedBy",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "positionOpenedEvent",
+      "name": "protocolStateUpdatedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "orderId",
-            "type": "u64"
+            "name": "protocol",
+            "type": "pubkey"
           },
           {
-            "name": "owner",
+            "name": "updatedBy",
             "type": "pubkey"
           },
           {
-            "name": "positionId",
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebalanceFeeIndex",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cumulativeIndex",
+            "docs": [
+              "Cumulative rebalance fee index (monotonically increasing)",
+              "Represents the total cumulative rebalance fee amount per unit position size",
+              "Scaled by PRICE_PRECISION for precision"
+            ],
             "type": "u64"
           },
           {
-            "name": "basktId",
-            "type": "pubkey"
-          },
+            "name": "lastUpdateTimestamp",
+            "docs": [
+              "Timestamp of the last rebalance that updated this index"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebalanceRequestEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "size",
+            "name": "rebalanceRequestFee",
             "type": "u64"
           },
           {
-            "name": "collateral",
-            "type": "u64"
+            "name": "basktId",
+            "type": "pubkey"
           },
           {
-            "name": "isLong",
-            "type": "bool"
+            "name": "creator",
+            "type": "pubkey"
           },
           {
-            "name": "entryPrice",
-            "type": "u64"
-          },
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "removeCollateralParams",
+      "docs": [
+        "Parameters for removing collateral from a position"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "feeToTreasury",
+            "name": "amount",
             "type": "u64"
           },
           {
-            "name": "feeToBlp",
+            "name": "currentPrice",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "requestWithdrawParams",
+      "docs": [
+        "Parameters for withdrawal request creation"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "lpAmount",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "positionStatus",
-      "repr": {
-        "kind": "rust"
-      },
+      "name": "role",
+      "docs": [
+        "Roles that can be assigned to accounts for access control"
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "open"
+            "name": "owner"
           },
           {
-            "name": "closed"
+            "name": "assetManager"
           },
           {
-            "name": "liquidated"
+            "name": "basktManager"
           },
           {
-            "name": "forceClosed"
+            "name": "rebalancer"
+          },
+          {
+            "name": "matcher"
+          },
+          {
+            "name": "liquidator"
+          },
+          {
+            "name": "fundingManager"
+          },
+          {
+            "name": "configManager"
+          },
+          {
+            "name": "keeper"
+          },
+          {
+            "name": "oracle"
           }
         ]
       }
     },
     {
-      "name": "programAuthority",
+      "name": "slippageGuard",
       "type": {
         "kind": "struct",
-        "fields": []
+        "fields": [
+          {
+            "name": "referencePrice",
+            "type": "u64"
+          },
+          {
+            "name": "maxSlippageBps",
+            "type": "u64"
+          }
+        ]
       }
     },
     {
-      "name": "protocol",
-      "docs": [
-        "* It should track all the assets that we have in the system.\n * it should keep track of all the fees we are charging and what not\n * it should keep track of min liqudiation margins\n * it should keep track of fee splits\n * it should keep track of owner and be able to transfer ownership\n * it should keep track of protocol level stats maybe? fees, trading, volume, total baskts, total assets"
-      ],
+      "name": "syntheticAsset",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "isInitialized",
-            "type": "bool"
-          },
-          {
-            "name": "owner",
-            "type": "pubkey"
-          },
-          {
-            "name": "accessControl",
-            "type": {
-              "defined": {
-                "name": "accessControl"
-              }
-            }
+            "name": "ticker",
+            "type": "string"
           },
           {
-            "name": "featureFlags",
+            "name": "permissions",
             "type": {
               "defined": {
-                "name": "featureFlags"
+                "name": "assetPermissions"
               }
             }
           },
           {
-            "name": "treasury",
-            "type": "pubkey"
+            "name": "isActive",
+            "type": "bool"
           },
           {
-            "name": "collateralMint",
-            "docs": [
-              "Collateral mint (USDC)"
-            ],
-            "type": "pubkey"
+            "name": "listingTime",
+            "type": "u32"
           },
           {
-            "name": "config",
-            "docs": [
-              "Protocol configuration parameters"
-            ],
+            "name": "oracleConfig",
+            "type": {
+              "defined": {
+                "name": "oracleConfig"
+              }
+            }
+          },
+          {
+            "name": "manualPrice",
             "type": {
               "defined": {
-                "name": "protocolConfig"
+                "name": "manualPrice"
               }
             }
           }
@@ -6666,106 +13439,119 @@ export type Baskt = {
       }
     },
     {
-      "name": "protocolConfig",
+      "name": "tradingDelegate",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "openingFeeBps",
-            "docs": [
-              "Fee parameters (in basis points)"
-            ],
-            "type": "u64"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "closingFeeBps",
-            "type": "u64"
+            "name": "delegate",
+            "type": "pubkey"
           },
           {
-            "name": "liquidationFeeBps",
-            "type": "u64"
+            "name": "expiresAt",
+            "type": "i64"
           },
           {
-            "name": "treasuryCutBps",
-            "docs": [
-              "Fee split parameters (in basis points)"
-            ],
-            "type": "u64"
+            "name": "baskt",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "fundingCutBps",
-            "type": "u64"
+            "name": "maxNotional",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "maxFundingRateBps",
-            "docs": [
-              "Funding parameters"
-            ],
-            "type": "u64"
-          },
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tradingDelegateConfig",
+      "docs": [
+        "Lets `delegate` create and cancel orders and add collateral on behalf of `owner`.",
+        "Funds leaving the protocol always go to the owner's accounts.",
+        "Terms of a delegation, set by the owner"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "fundingIntervalSeconds",
+            "name": "expiresAt",
             "type": "i64"
           },
           {
-            "name": "minCollateralRatioBps",
-            "docs": [
-              "Risk parameters"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "liquidationThresholdBps",
-            "type": "u64"
+            "name": "baskt",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "minLiquidity",
-            "docs": [
-              "Liquidity parameters"
-            ],
-            "type": "u64"
-          },
+            "name": "maxNotional",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "tradingDelegateRevokedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "rebalanceRequestFeeLamports",
-            "docs": [
-              "Rebalance request fee in lamports (SOL)"
-            ],
-            "type": "u64"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "basktCreationFeeLamports",
-            "docs": [
-              "Baskt creation fee in lamports (SOL)"
-            ],
-            "type": "u64"
+            "name": "delegate",
+            "type": "pubkey"
           },
           {
-            "name": "lastUpdated",
-            "docs": [
-              "Metadata"
-            ],
+            "name": "timestamp",
             "type": "i64"
-          },
-          {
-            "name": "lastUpdatedBy",
-            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "protocolStateUpdatedEvent",
+      "name": "tradingDelegateSetEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "protocol",
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "updatedBy",
+            "name": "delegate",
             "type": "pubkey"
           },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "baskt",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "maxNotional",
+            "type": {
+              "option": "u64"
+            }
+          },
           {
             "name": "timestamp",
             "type": "i64"
@@ -6774,36 +13560,40 @@ export type Baskt = {
       }
     },
     {
-      "name": "rebalanceFeeIndex",
+      "name": "trailingStopParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "cumulativeIndex",
-            "docs": [
-              "Cumulative rebalance fee index (monotonically increasing)",
-              "Represents the total cumulative rebalance fee amount per unit position size",
-              "Scaled by PRICE_PRECISION for precision"
-            ],
+            "name": "trailBps",
             "type": "u64"
           },
           {
-            "name": "lastUpdateTimestamp",
-            "docs": [
-              "Timestamp of the last rebalance that updated this index"
-            ],
-            "type": "i64"
+            "name": "watermark",
+            "type": "u64"
+          },
+          {
+            "name": "triggerDirection",
+            "type": {
+              "defined": {
+                "name": "triggerDirection"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "rebalanceRequestEvent",
+      "name": "trailingStopUpdatedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "rebalanceRequestFee",
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "orderId",
             "type": "u64"
           },
           {
@@ -6811,8 +13601,12 @@ export type Baskt = {
             "type": "pubkey"
           },
           {
-            "name": "creator",
-            "type": "pubkey"
+            "name": "watermark",
+            "type": "u64"
+          },
+          {
+            "name": "stopPrice",
+            "type": "u64"
           },
           {
             "name": "timestamp",
@@ -6822,82 +13616,110 @@ export type Baskt = {
       }
     },
     {
-      "name": "requestWithdrawParams",
-      "docs": [
-        "Parameters for withdrawal request creation"
-      ],
+      "name": "triggerDirection",
+      "repr": {
+        "kind": "rust"
+      },
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "lpAmount",
-            "type": "u64"
+            "name": "above"
+          },
+          {
+            "name": "below"
           }
         ]
       }
     },
     {
-      "name": "role",
-      "docs": [
-        "Roles that can be assigned to accounts for access control"
-      ],
+      "name": "triggerOrderParams",
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "owner"
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "assetManager"
+            "name": "triggerPrice",
+            "type": "u64"
           },
           {
-            "name": "basktManager"
-          },
+            "name": "triggerDirection",
+            "type": {
+              "defined": {
+                "name": "triggerDirection"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "twapOrderParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "rebalancer"
+            "name": "trancheCount",
+            "type": "u16"
           },
           {
-            "name": "matcher"
+            "name": "minIntervalSeconds",
+            "type": "u32"
           },
           {
-            "name": "liquidator"
+            "name": "tranchesFilled",
+            "type": "u16"
           },
           {
-            "name": "fundingManager"
+            "name": "lastFillTime",
+            "type": "i64"
           },
           {
-            "name": "configManager"
+            "name": "notionalFilled",
+            "type": "u64"
           },
           {
-            "name": "keeper"
+            "name": "collateralUsed",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "syntheticAsset",
+      "name": "twapTrancheFilledEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "ticker",
-            "type": "string"
+            "name": "orderId",
+            "type": "u64"
           },
           {
-            "name": "permissions",
-            "type": {
-              "defined": {
-                "name": "assetPermissions"
-              }
-            }
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "isActive",
-            "type": "bool"
+            "name": "positionId",
+            "type": "u64"
           },
           {
-            "name": "listingTime",
-            "type": "u32"
+            "name": "tranchesFilled",
+            "type": "u16"
+          },
+          {
+            "name": "trancheCount",
+            "type": "u16"
+          },
+          {
+            "name": "notionalFilled",
+            "type": "u64"
+          },
+          {
+            "name": "collateralUsed",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
@@ -6936,6 +13758,28 @@ export type Baskt = {
             "type": {
               "option": "u64"
             }
+          },
+          {
+            "name": "maxPriceDeviationBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "liquidationTwapWindowSeconds",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "circuitBreaker",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "circuitBreakerConfig"
+                }
+              }
+            }
           }
         ]
       }
@@ -20,6 +20,8 @@ export function toRoleString(role: AccessControlRole): string {
       return 'ConfigManager';
     case AccessControlRole.Keeper:
       return 'Keeper';
+    case AccessControlRole.Oracle:
+      return 'Oracle';
     default:
       return 'Unknown';
   }
@@ -42,6 +44,8 @@ export function stringToRole(roleStr: string): AccessControlRole {
       return AccessControlRole.ConfigManager;
     case 'keeper':
       return AccessControlRole.Keeper;
+    case 'oracle':
+      return AccessControlRole.Oracle;
     case 'owner':
       return AccessControlRole.Owner;
     default:
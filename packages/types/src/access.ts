@@ -11,4 +11,5 @@ export enum AccessControlRole {
   FundingManager = 6,
   ConfigManager = 7,
   Keeper = 8,
+  Oracle = 9,
 }
@@ -37,6 +37,9 @@ export interface OrderCreatedEvent {
   limitPrice: BN;
   maxSlippageBps: BN;
   orderType: OrderType;
+  triggerPrice: BN;
+  triggerDirection: { above: {} } | { below: {} };
+  expiresAt: BN | null;
   leverageBps: BN;
   timestamp: BN;
 }
@@ -90,6 +93,8 @@ export interface PositionClosedEvent {
   baseFee: BN;
   rebalanceFee: BN;
   badDebtAmount: BN;
+  feeToInsurance: BN;
+  badDebtCovered: BN;
   collateralReleased: BN;
 }
 
@@ -116,6 +121,8 @@ export interface PositionLiquidatedEvent {
   baseFee: BN;
   rebalanceFee: BN;
   badDebtAmount: BN;
+  feeToInsurance: BN;
+  badDebtCovered: BN;
   collateralReleased: BN;
 }
 
@@ -142,6 +149,8 @@ export interface PositionForceClosed {
   baseFee: BN;
   rebalanceFee: BN;
   badDebtAmount: BN;
+  feeToInsurance: BN;
+  badDebtCovered: BN;
   collateralReleased: BN;
 }
 
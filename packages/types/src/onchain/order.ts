@@ -31,15 +31,15 @@ export interface CloseOrderParams {
   targetPosition: PublicKey; // Position to close
 }
 
-// Market order parameters - no additional fields needed
+// Market order parameters - optional slippage guard around a reference price
 export interface MarketOrderParams {
-  // No additional fields for market orders
+  referencePrice: BN;   // Reference price for the slippage guard (0 = no guard)
+  maxSlippageBps: BN;   // Maximum acceptable slippage around referencePrice in basis points
 }
 
 // Limit order parameters - required for limit orders
 export interface LimitOrderParams {
-  limitPrice: BN;       // User-specified limit price
-  maxSlippageBps: BN;   // Maximum acceptable slippage in basis points
+  limitPrice: BN;       // Worst acceptable fill price in the order's direction
 }
 
 export interface OnchainOrder {